@@ -59,6 +59,13 @@ export function configPDA(): [PublicKey, number] {
   );
 }
 
+export function treasuryPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    PROGRAM_ID
+  );
+}
+
 export function auctionPDA(auctionId: bigint): [PublicKey, number] {
   const buf = Buffer.alloc(8);
   buf.writeBigUInt64LE(auctionId);
@@ -77,6 +84,15 @@ export function bidVaultPDA(auctionId: bigint): [PublicKey, number] {
   );
 }
 
+export function bidLogPDA(auctionId: bigint): [PublicKey, number] {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(auctionId);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("bid-log"), buf],
+    PROGRAM_ID
+  );
+}
+
 export function slotPDA(
   nftMint: PublicKey,
   scheduledDate: bigint
@@ -88,3 +104,103 @@ export function slotPDA(
     PROGRAM_ID
   );
 }
+
+export function datePDA(scheduledDate: bigint): [PublicKey, number] {
+  const dateBuf = Buffer.alloc(8);
+  dateBuf.writeBigInt64LE(scheduledDate);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("date"), dateBuf],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * The DateReservation of a slot's date and the wallet that paid its rent —
+ * the `dateReservation` and `dateReservationPayer` accounts of every
+ * instruction that uses the date up. A slot listed before reservations
+ * existed has none; `fallback` then stands in as the unused payer.
+ */
+export async function dateReservationAccounts(
+  connection: Connection,
+  scheduledDate: bigint,
+  fallback: PublicKey
+): Promise<{ dateReservation: PublicKey; dateReservationPayer: PublicKey }> {
+  const [dateReservation] = datePDA(scheduledDate);
+  const info = await connection.getAccountInfo(dateReservation);
+  // discriminator, scheduled_date, nft_mint, payer, bump
+  const dateReservationPayer = info ? new PublicKey(info.data.subarray(48, 80)) : fallback;
+  return { dateReservation, dateReservationPayer };
+}
+
+/** Mirrors SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS in the program. */
+export const MAX_RELIST_LOOKAHEAD_DAYS = 10;
+
+/**
+ * First date from the midnight UTC after `now` without a DateReservation,
+ * plus the reservations of the booked dates before it — the remaining
+ * accounts settle_auction expects when it auto-relists without a date.
+ * Null when every date within the program's lookahead is booked.
+ */
+export async function firstFreeDate(
+  connection: Connection,
+  now: number
+): Promise<{ date: bigint; booked: PublicKey[] } | null> {
+  let date = BigInt((Math.floor(now / 86_400) + 1) * 86_400);
+  const booked: PublicKey[] = [];
+  while (booked.length <= MAX_RELIST_LOOKAHEAD_DAYS) {
+    const [reservation] = datePDA(date);
+    if (!(await connection.getAccountInfo(reservation))) return { date, booked };
+    booked.push(reservation);
+    date += 86_400n;
+  }
+  return null;
+}
+
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
+export function metadataPDA(nftMint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), nftMint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+}
+
+/**
+ * Verified creators of an NFT, in metadata order — the accounts
+ * settle_auction expects after the fee recipients when royalties are on.
+ * Returns [] when the mint has no Metaplex metadata.
+ */
+export async function verifiedCreators(
+  connection: Connection,
+  nftMint: PublicKey
+): Promise<PublicKey[]> {
+  const info = await connection.getAccountInfo(metadataPDA(nftMint)[0]);
+  if (!info) return [];
+  const data = info.data;
+  // key, update_authority, mint, then three borsh strings
+  let offset = 1 + 32 + 32;
+  for (let i = 0; i < 3; i++) offset += 4 + data.readUInt32LE(offset);
+  offset += 2; // seller_fee_basis_points
+  if (data[offset++] === 0) return [];
+  const count = data.readUInt32LE(offset);
+  offset += 4;
+  const creators: PublicKey[] = [];
+  for (let i = 0; i < count; i++) {
+    const address = new PublicKey(data.subarray(offset, offset + 32));
+    if (data[offset + 32] === 1) creators.push(address);
+    offset += 34;
+  }
+  return creators;
+}
+
+/**
+ * Used entries of the fee distribution table, in table order — the first
+ * remaining accounts settle_auction expects.
+ */
+export function feeRecipientAccounts(config: {
+  feeRecipients: { recipient: PublicKey; bps: number }[];
+}): PublicKey[] {
+  return config.feeRecipients.filter((r) => r.bps > 0).map((r) => r.recipient);
+}
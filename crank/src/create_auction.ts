@@ -10,7 +10,7 @@
  */
 import { PublicKey } from "@solana/web3.js";
 import BN from "bn.js";
-import { buildClient, configPDA, auctionPDA, PROGRAM_ID } from "./client";
+import { buildClient, configPDA, auctionPDA, bidLogPDA, PROGRAM_ID } from "./client";
 
 async function main() {
   const { program, adminKeypair } = buildClient();
@@ -40,11 +40,12 @@ async function main() {
     .createAuction(auctionIdBN)
     // eslint-disable-next-line @typescript-eslint/no-explicit-any
     .accounts({
-      admin: adminKeypair.publicKey,
+      crankAuthority: adminKeypair.publicKey,
       config: configAddress,
       nftMint,
       slot: slotAddress,
       auction: auctionAddress,
+      bidLog: bidLogPDA(auctionId)[0],
       systemProgram: SystemProgram.programId,
     } as any)
     .signers([adminKeypair])
@@ -29,9 +29,18 @@ import idlJson from "../idl.json";
 import {
   buildClient,
   configPDA,
+  treasuryPDA,
   auctionPDA,
+  bidLogPDA,
   bidVaultPDA,
   slotPDA,
+  datePDA,
+  dateReservationAccounts,
+  firstFreeDate,
+  MAX_RELIST_LOOKAHEAD_DAYS,
+  metadataPDA,
+  verifiedCreators,
+  feeRecipientAccounts,
 } from "./client";
 
 // ── helpers ──────────────────────────────────────────────────────────────────
@@ -112,15 +121,16 @@ async function main() {
 
   console.log("\n[settle] Scanning for ended auctions…");
 
-  const AUCTION_STATE_SIZE = 150;
+  // Match on the account discriminator rather than the size, which grows
+  // whenever fields are appended.
   const allAuctions = await (program.provider as any).connection.getProgramAccounts(
     program.programId,
-    { filters: [{ dataSize: AUCTION_STATE_SIZE }] }
+    { filters: [{ memcmp: coder.memcmp("AuctionState") }] }
   );
 
   let settledCount = 0;
 
-  // Fetch config once (needed for treasury address)
+  // Fetch config once (needed for royalties and fee recipients)
   const config = await program.account.programConfig.fetch(configAddress);
 
   for (const { pubkey: auctionAddress, account } of allAuctions) {
@@ -128,6 +138,7 @@ async function main() {
     try {
       decoded = coder.decode("AuctionState", account.data);
     } catch {
+      console.log(`\n  ${auctionAddress.toBase58()}: old layout — run migrate_auction first`);
       continue;
     }
 
@@ -150,26 +161,87 @@ async function main() {
     const winnerTokenAccount = await getAssociatedTokenAddress(nftMint, winner);
     const sellerTokenAccount = await getAssociatedTokenAddress(nftMint, seller);
 
+    const reserveMet =
+      decoded.current_bidder !== null &&
+      (decoded.current_bid as BN).gte(decoded.reserve_price as BN);
+
+    // Auto-relist slots need the next slot's accounts in case the reserve
+    // was not met. The program relists to the first free date from tomorrow
+    // on and checks the booked dates before it, passed as remaining accounts.
+    let relistSlot: PublicKey | null = null;
+    let relistEscrowTokenAccount: PublicKey | null = null;
+    let relistDateReservation: PublicKey | null = null;
+    let bookedDates: PublicKey[] = [];
+    let reservation: Awaited<ReturnType<typeof dateReservationAccounts>>;
+    try {
+      const slot = coder.decode(
+        "SlotRegistration",
+        (await (program.provider as any).connection.getAccountInfo(slotAddress)).data
+      );
+      reservation = await dateReservationAccounts(
+        (program.provider as any).connection,
+        BigInt(slot.scheduled_date.toString()),
+        seller
+      );
+      if (slot.auto_relist && !reserveMet) {
+        const free = await firstFreeDate((program.provider as any).connection, nowTs);
+        if (!free) {
+          console.log(
+            `  Auction #${auctionId}: no free relist date within ${MAX_RELIST_LOOKAHEAD_DAYS} days — the seller must settle with a relist date`
+          );
+          continue;
+        }
+        [relistSlot] = slotPDA(nftMint, free.date);
+        [relistDateReservation] = datePDA(free.date);
+        relistEscrowTokenAccount = await getAssociatedTokenAddress(nftMint, relistSlot, true);
+        bookedDates = free.booked;
+      }
+    } catch {
+      console.log(`  Slot ${slotAddress.toBase58()}: old layout — run migrate_slot first`);
+      continue;
+    }
+
+    // Royalties are paid to the verified creators, passed in metadata order.
+    const creators = config.royaltiesEnabled
+      ? await verifiedCreators((program.provider as any).connection, nftMint)
+      : [];
+
     try {
       const builder = program.methods
-        .settleAuction()
+        .settleAuction(null)
         .accounts({
           payer: admin,
           config: configAddress,
           auction: auctionAddress,
           slot: slotAddress,
           nftMint,
+          nftMetadata: metadataPDA(nftMint)[0],
+          ...reservation,
           escrowTokenAccount,
           winnerTokenAccount,
           sellerTokenAccount,
           bidVault: bidVaultAddress,
           seller,
           winner,
-          treasury: config.treasury as PublicKey,
+          treasury: treasuryPDA()[0],
+          referrer: (decoded.current_referrer as PublicKey | null) ?? null,
+          relistSlot,
+          relistEscrowTokenAccount,
+          relistDateReservation,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         } as any)
+        .remainingAccounts(
+          (reserveMet
+            ? [...feeRecipientAccounts(config as any), ...creators]
+            : bookedDates
+          ).map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: reserveMet,
+          }))
+        )
         .signers([adminKeypair]);
 
       const tx = await send(builder);
@@ -206,10 +278,9 @@ async function main() {
   let createLabel = todayStr;
 
   console.log(`\n[create] Scanning on-chain slots for ${todayStr}…`);
-  const SLOT_SIZE = 91;
   const slotAccounts = await (program.provider as any).connection.getProgramAccounts(
     program.programId,
-    { filters: [{ dataSize: SLOT_SIZE }] }
+    { filters: [{ memcmp: coder.memcmp("SlotRegistration") }] }
   );
 
   for (const { account } of slotAccounts) {
@@ -266,11 +337,12 @@ async function main() {
     const builder = program.methods
       .createAuction(new BN(auctionId.toString()), new BN(endTime))
       .accounts({
-        admin,
+        crankAuthority: admin,
         config: configAddress,
         nftMint,
         slot: slotAddress,
         auction: auctionAddress,
+        bidLog: bidLogPDA(auctionId)[0],
         systemProgram: SystemProgram.programId,
       } as any)
       .signers([adminKeypair]);
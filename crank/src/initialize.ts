@@ -1,11 +1,12 @@
 /**
  * One-time initialization of the ProgramConfig account.
  *
- * Run once after deploying the program:
+ * Run once after deploying the program, with the upgrade authority keypair
+ * (the program rejects any other signer):
  *   npx tsx crank/src/initialize.ts
  *
  * Optional env vars:
- *   TREASURY_ADDRESS   — defaults to the admin wallet (swap to Squads before mainnet)
+ *   PARAM_UPDATE_DELAY_SECS — param timelock, at least 1 second; defaults to 48 hours
  *   SOLANA_RPC_URL     — defaults to devnet
  *   ADMIN_KEYPAIR_PATH — defaults to ~/.config/solana/id.json
  */
@@ -15,19 +16,23 @@ import { buildClient, configPDA, PROGRAM_ID } from "./client";
 async function main() {
   const { program, adminKeypair, connection } = buildClient();
 
-  // Treasury defaults to admin wallet for devnet.
-  // Swap to your Squads multisig address before mainnet.
-  const treasury = new PublicKey(
-    process.env.TREASURY_ADDRESS ?? adminKeypair.publicKey.toBase58()
+  // Protocol fees go to the program-owned treasury PDA.
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    PROGRAM_ID
   );
 
   const [configAddress] = configPDA();
+  const [programData] = PublicKey.findProgramAddressSync(
+    [PROGRAM_ID.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
 
   // Check if already initialized
   const existing = await connection.getAccountInfo(configAddress);
   if (existing !== null) {
     console.log("ProgramConfig already initialized at", configAddress.toBase58());
-    console.log("Use update_params to change settings.");
+    console.log("Use queue_param_update / execute_param_update to change settings.");
     process.exit(0);
   }
 
@@ -42,16 +47,19 @@ async function main() {
 
   const tx = await (program.methods
     .initializeProgram(
-      treasury,
       900,          // default_fee_bps     — 9%
       500,          // bid_increment_bps   — 5% min raise
       new BN(600),           // time_buffer_secs    — 10-min anti-snipe
-      new BN(420_000_000)    // min_reserve_lamports — 0.42 SOL
+      new BN(420_000_000),   // min_reserve_lamports — 0.42 SOL
+      null,                  // required_collection — set before mainnet
+      new BN(Number(process.env.PARAM_UPDATE_DELAY_SECS ?? 172_800)) // param timelock — 48h
     )
     // eslint-disable-next-line @typescript-eslint/no-explicit-any
     .accounts({
       admin: adminKeypair.publicKey,
       config: configAddress,
+      program: PROGRAM_ID,
+      programData,
       systemProgram: SystemProgram.programId,
     } as any)
     .signers([adminKeypair])
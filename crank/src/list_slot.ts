@@ -17,6 +17,8 @@
  *   NFT_MINT            Mint address you hold (required unless NEW_MINT=1)
  *   NEW_MINT            Set to "1" to create + mint a fresh test token
  *   RESERVE_PRICE_SOL   Reserve price in SOL (default: 0.42)
+ *   AUTO_RELIST         Set to "1" to relist automatically if unsold
+ *   RESERVE_DECAY_BPS   Reserve reduction per relist in bps (default: 0)
  *   SCHEDULE_PATH       Path to auction-schedule.json (optional)
  */
 import fs from "fs";
@@ -31,7 +33,7 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import BN from "bn.js";
-import { buildClient, configPDA, slotPDA } from "./client";
+import { buildClient, configPDA, slotPDA, datePDA, metadataPDA } from "./client";
 
 // ── metadata fetch ────────────────────────────────────────────────────────────
 
@@ -135,6 +137,9 @@ async function main() {
     )
   );
 
+  const autoRelist = process.env.AUTO_RELIST === "1";
+  const reserveDecayBps = parseInt(process.env.RESERVE_DECAY_BPS ?? "0", 10);
+
   let nftMint: PublicKey;
 
   if (process.env.NEW_MINT === "1") {
@@ -173,17 +178,20 @@ async function main() {
   console.log(`\nRegistering slot for ${slotDateStr}...`);
   console.log("  scheduledDate :", scheduledDate.toString());
   console.log("  Reserve price :", reservePrice.toNumber() / LAMPORTS_PER_SOL, "SOL");
+  console.log("  Auto-relist   :", autoRelist, `(decay ${reserveDecayBps} bps)`);
   console.log("  Slot PDA      :", slotAddress.toBase58());
 
   const tx = await (program.methods
-    .listSlot(new BN(scheduledDate.toString()), reservePrice)
+    .listSlot(new BN(scheduledDate.toString()), reservePrice, autoRelist, reserveDecayBps)
     .accounts({
       holder: admin,
       config: configAddress,
       nftMint,
+      nftMetadata: metadataPDA(nftMint)[0],
       holderTokenAccount: holderATA,
       escrowTokenAccount: escrowATA,
       slot: slotAddress,
+      dateReservation: datePDA(scheduledDate)[0],
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
@@ -1,5 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Connection, Keypair, PublicKey } from "@solana/web3.js";
+import { Connection, Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import BN from "bn.js";
 import idl from "../idl.json";
 import fs from "fs";
 
@@ -24,6 +25,53 @@ async function main() {
     .accounts({ admin: admin.publicKey, config: configPda })
     .rpc();
   console.log("Migration tx:", tx);
+
+  // Slots listed before auto-relist are 3 bytes short of the current layout.
+  const coder = new anchor.BorshAccountsCoder(idl as anchor.Idl);
+  const slots = await conn.getProgramAccounts(program.programId, {
+    filters: [{ memcmp: coder.memcmp("SlotRegistration") }],
+  });
+  const slotSize = coder.size("SlotRegistration");
+  for (const { pubkey, account } of slots) {
+    if (account.data.length >= slotSize) continue;
+    const nftMint = new PublicKey(account.data.subarray(8, 40));
+    const scheduledDate = new BN(account.data.readBigInt64LE(72).toString());
+    console.log("Calling migrate_slot on PDA:", pubkey.toBase58());
+    const slotTx = await (program.methods as any).migrateSlot(nftMint, scheduledDate)
+      .accounts({ payer: admin.publicKey, slot: pubkey, systemProgram: SystemProgram.programId })
+      .rpc();
+    console.log("Migration tx:", slotTx);
+  }
+
+  // Auctions created before the referrer and the status were appended.
+  const auctions = await conn.getProgramAccounts(program.programId, {
+    filters: [{ memcmp: coder.memcmp("AuctionState") }],
+  });
+  const auctionSize = coder.size("AuctionState");
+  for (const { pubkey, account } of auctions) {
+    if (account.data.length >= auctionSize) continue;
+    // auction_id follows current_bidder (borsh Option), fee_bps, settled, reserve_met.
+    const hasBidder = account.data[104] === 1;
+    const bidderEnd = 104 + (hasBidder ? 33 : 1);
+    const auctionId = new BN(account.data.readBigUInt64LE(bidderEnd + 4).toString());
+    // A stale-closed auction's standing bid is refunded to its bidder.
+    const currentBidder = hasBidder ? new PublicKey(account.data.subarray(105, 137)) : admin.publicKey;
+    const [bidVault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bid-vault"), auctionId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    console.log("Calling migrate_auction on PDA:", pubkey.toBase58());
+    const auctionTx = await (program.methods as any).migrateAuction(auctionId)
+      .accounts({
+        payer: admin.publicKey,
+        auction: pubkey,
+        bidVault,
+        currentBidder,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    console.log("Migration tx:", auctionTx);
+  }
   console.log("Done.");
 }
 
@@ -2,7 +2,8 @@
  * Settlement crank — called after an auction's end_time has passed.
  *
  * If reserve was met:  NFT → winner, SOL → seller (minus fee) + treasury
- * If reserve not met:  NFT → seller, no SOL moves
+ * If reserve not met:  NFT → seller, no SOL moves — or, when the slot opted
+ *                      into auto-relist, NFT → a new slot at the first free date
  *
  * Usage:
  *   AUCTION_ID=1 npx tsx crank/src/settle_auction.ts
@@ -17,7 +18,22 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import BN from "bn.js";
-import { buildClient, configPDA, auctionPDA, bidVaultPDA, PROGRAM_ID } from "./client";
+import {
+  buildClient,
+  configPDA,
+  treasuryPDA,
+  auctionPDA,
+  bidVaultPDA,
+  slotPDA,
+  datePDA,
+  dateReservationAccounts,
+  firstFreeDate,
+  MAX_RELIST_LOOKAHEAD_DAYS,
+  metadataPDA,
+  verifiedCreators,
+  feeRecipientAccounts,
+  PROGRAM_ID,
+} from "./client";
 
 async function main() {
   const { program, adminKeypair, connection } = buildClient();
@@ -46,9 +62,9 @@ async function main() {
   const seller: PublicKey = auction.seller;
   const winner: PublicKey = auction.currentBidder ?? seller; // fallback to seller if no bids
 
-  // Fetch config for treasury address
+  // Fetch config for the royalty switch
   const config = await program.account.programConfig.fetch(configAddress);
-  const treasury: PublicKey = config.treasury;
+  const [treasury] = treasuryPDA();
 
   // Derive slot PDA — we need it for the NFT authority on no-reserve path
   // The slot is stored by (nft_mint, scheduled_date). We fetch via the auction's seller.
@@ -66,7 +82,33 @@ async function main() {
   const winnerTokenAccount = await getAssociatedTokenAddress(nftMint, winner);
   const sellerTokenAccount = await getAssociatedTokenAddress(nftMint, seller);
 
-  const reserveMet = auction.currentBidder !== null;
+  const reserveMet =
+    auction.currentBidder !== null && auction.currentBid.gte(auction.reservePrice);
+
+  // Royalties are paid to the verified creators, passed in metadata order.
+  const creators = config.royaltiesEnabled
+    ? await verifiedCreators(connection, nftMint)
+    : [];
+
+  // Auto-relist: the program moves an unsold NFT to the slot for the first
+  // free date from tomorrow on, so derive that slot, its escrow and date
+  // reservation up front. The booked dates before it go in remaining accounts.
+  let relistSlot: PublicKey | null = null;
+  let relistEscrowTokenAccount: PublicKey | null = null;
+  let relistDateReservation: PublicKey | null = null;
+  let bookedDates: PublicKey[] = [];
+  if (slot.autoRelist && !reserveMet) {
+    const free = await firstFreeDate(connection, now);
+    if (!free) {
+      throw new Error(
+        `No free relist date within ${MAX_RELIST_LOOKAHEAD_DAYS} days — the seller must settle with a relist date`
+      );
+    }
+    [relistSlot] = slotPDA(nftMint, free.date);
+    [relistDateReservation] = datePDA(free.date);
+    relistEscrowTokenAccount = await getAssociatedTokenAddress(nftMint, relistSlot, true);
+    bookedDates = free.booked;
+  }
 
   console.log("Settling auction...");
   console.log("  Auction ID   :", auctionId.toString());
@@ -76,14 +118,20 @@ async function main() {
   console.log("  Reserve met  :", reserveMet);
 
   const tx = await (program.methods
-    .settleAuction()
+    .settleAuction(null)
     // eslint-disable-next-line @typescript-eslint/no-explicit-any
     .accounts({
-      admin: adminKeypair.publicKey,
+      payer: adminKeypair.publicKey,
       config: configAddress,
       auction: auctionAddress,
       slot: slotAddress,
       nftMint,
+      nftMetadata: metadataPDA(nftMint)[0],
+      ...(await dateReservationAccounts(
+        connection,
+        BigInt(slot.scheduledDate.toString()),
+        seller
+      )),
       escrowTokenAccount,
       winnerTokenAccount,
       sellerTokenAccount,
@@ -91,10 +139,24 @@ async function main() {
       seller,
       winner,
       treasury,
+      referrer: auction.currentReferrer ?? null,
+      relistSlot,
+      relistEscrowTokenAccount,
+      relistDateReservation,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
     } as any)
+    .remainingAccounts(
+      (reserveMet
+        ? [...feeRecipientAccounts(config as any), ...creators]
+        : bookedDates
+      ).map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: reserveMet,
+      }))
+    )
     .signers([adminKeypair])
     .rpc());
 
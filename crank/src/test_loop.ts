@@ -6,7 +6,9 @@
  *   2. Mint 1 token to admin (seller)
  *   3. list_slot  — admin locks NFT into escrow, reserves a slot
  *   4. create_auction — admin crank opens the auction
- *   5. update_params — shrink time_buffer to 3s so anti-snipe doesn't interfere
+ *   5. queue + execute a param update — shrink time_buffer to 3s so
+ *      anti-snipe doesn't interfere, waiting out the config's param
+ *      timelock in between (keep the devnet delay shorter than the auction)
  *   6. place_bid (bidder2, 0.5 SOL)
  *   7. place_bid (admin,   0.55 SOL) — bidder2 gets refunded
  *   8. Wait for auction end (~32s)
@@ -36,9 +38,13 @@ import BN from "bn.js";
 import {
   buildClient,
   configPDA,
+  treasuryPDA,
   auctionPDA,
+  bidLogPDA,
   bidVaultPDA,
   slotPDA,
+  datePDA,
+  metadataPDA,
   PROGRAM_ID,
 } from "./client";
 
@@ -119,14 +125,16 @@ async function main() {
   const scheduledDateBN = new BN(scheduledDate.toString());
 
   await (program.methods
-    .listSlot(scheduledDateBN, reservePrice)
+    .listSlot(scheduledDateBN, reservePrice, false, 0)
     .accounts({
       holder: admin,
       config: configAddress,
       nftMint,
+      nftMetadata: metadataPDA(nftMint)[0],
       holderTokenAccount: adminATA,
       escrowTokenAccount: escrowATA,
       slot: slotAddress,
+      dateReservation: datePDA(scheduledDate)[0],
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
@@ -151,11 +159,12 @@ async function main() {
   await (program.methods
     .createAuction(auctionIdBN)
     .accounts({
-      admin,
+      crankAuthority: admin,
       config: configAddress,
       nftMint,
       slot: slotAddress,
       auction: auctionAddress,
+      bidLog: bidLogPDA(auctionId)[0],
       systemProgram: SystemProgram.programId,
     } as any)
     .signers([adminKeypair])
@@ -168,23 +177,56 @@ async function main() {
   console.log("  Reserve price:", auctionState.reservePrice.toNumber() / LAMPORTS_PER_SOL, "SOL");
   console.log("  Fee:", auctionState.feeBps, "bps");
 
-  // ── Step 6: update_params — shrink time_buffer to 3s ─────────────────
+  // ── Step 6: queue + execute param update — shrink time_buffer to 3s ───
   log(6, "Setting time_buffer_secs = 3 so bidding doesn't trigger anti-snipe...");
+  const [pendingParams] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-params")],
+    program.programId
+  );
   await (program.methods
-    .updateParams(
-      null,          // fee_bps unchanged
-      null,          // bid_increment_bps unchanged
-      new BN(3),     // time_buffer_secs = 3s
-      null,          // min_reserve unchanged
-      null,          // common_token_mint unchanged
-      null           // discount_tiers unchanged
-    )
+    .queueParamUpdate({
+      feeBps: null,
+      bidIncrementBps: null,
+      timeBufferSecs: new BN(3),
+      minReserveLamports: null,
+      commonTokenMint: null,
+      discountTiers: null,
+      referralBps: null,
+      settlerRewardLamports: null,
+      keeperRewardLamports: null,
+      requiredCollection: null,
+      paramUpdateDelaySecs: null,
+      commonerCollection: null,
+      quorumBps: null,
+      approvalThresholdBps: null,
+      executionDelaySecs: null,
+      proposalDepositLamports: null,
+      proposalApprovalRequired: null,
+      maxVoteWeight: null,
+      feeRecipients: null,
+      royaltiesEnabled: null,
+      royaltyCapBps: null,
+    })
     .accounts({
       admin,
       config: configAddress,
+      pending: pendingParams,
+      systemProgram: SystemProgram.programId,
     } as any)
     .signers([adminKeypair])
     .rpc());
+  const { eta } = await program.account.pendingParamUpdate.fetch(pendingParams);
+  const timelockSecs = Math.max(0, eta.toNumber() - Math.floor(Date.now() / 1000)) + 1;
+  console.log(`  Waiting ${timelockSecs}s for the param timelock...`);
+  await sleep(timelockSecs * 1000);
+  await (program.methods
+    .executeParamUpdate()
+    .accounts({
+      config: configAddress,
+      pending: pendingParams,
+      proposer: admin,
+    } as any)
+    .rpc());
   console.log("  time_buffer_secs set to 3 ✓");
 
   // ── Step 7: place_bid — bidder2 bids 0.5 SOL ─────────────────────────
@@ -193,11 +235,12 @@ async function main() {
   const [bidVault] = bidVaultPDA(auctionId);
 
   await (program.methods
-    .placeBid(bid1Amount)
+    .placeBid(bid1Amount, null)
     .accounts({
       bidder: bidder2.publicKey,
       config: configAddress,
       auction: auctionAddress,
+      bidLog: bidLogPDA(auctionId)[0],
       bidVault,
       prevBidder: bidder2.publicKey, // no prev bidder — constraint: map_or(true, ...) passes
       systemProgram: SystemProgram.programId,
@@ -215,11 +258,12 @@ async function main() {
   const bid2Amount = new BN(550_000_000); // 0.55 SOL (>= 0.5 * 1.05 = 0.525)
 
   await (program.methods
-    .placeBid(bid2Amount)
+    .placeBid(bid2Amount, null)
     .accounts({
       bidder: admin,
       config: configAddress,
       auction: auctionAddress,
+      bidLog: bidLogPDA(auctionId)[0],
       bidVault,
       prevBidder: bidder2.publicKey, // must match auction.current_bidder
       systemProgram: SystemProgram.programId,
@@ -253,20 +297,27 @@ async function main() {
   // (admin is both winner and seller — the program handles this fine)
 
   await (program.methods
-    .settleAuction()
+    .settleAuction(null)
     .accounts({
-      admin,
+      payer: admin,
       config: configAddress,
       auction: auctionAddress,
       slot: slotAddress,
       nftMint,
+      nftMetadata: metadataPDA(nftMint)[0], // test mint — no royalties to pay
+      dateReservation: datePDA(scheduledDate)[0],
+      dateReservationPayer: admin, // booked the date at list_slot
       escrowTokenAccount: escrowATA,
       winnerTokenAccount: adminNFTAta,
       sellerTokenAccount: adminNFTAta,
       bidVault,
       seller: admin,
       winner: admin,
-      treasury: admin,
+      treasury: treasuryPDA()[0],
+      referrer: null, // neither bid carried a referrer
+      relistSlot: null,
+      relistEscrowTokenAccount: null,
+      relistDateReservation: null,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
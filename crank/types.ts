@@ -14,35 +14,31 @@ export type CommonersAuction = {
   },
   "instructions": [
     {
-      "name": "createAuction",
+      "name": "acceptAdmin",
       "docs": [
-        "Called by the backend crank at the start of each auction day",
-        "to open bidding for the scheduled NFT."
+        "Nominated admin accepts the handoff and becomes admin."
       ],
       "discriminator": [
-        234,
-        6,
-        201,
-        246,
-        47,
-        219,
-        176,
-        107
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "newAdmin",
           "docs": [
-            "Backend crank wallet — must be the program admin."
+            "The wallet nominated by propose_admin."
           ],
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "config"
-          ]
+          "signer": true
         },
         {
           "name": "config",
+          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -66,101 +62,38 @@ export type CommonersAuction = {
               }
             ]
           }
-        },
-        {
-          "name": "nftMint"
-        },
-        {
-          "name": "slot",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  115,
-                  108,
-                  111,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "nftMint"
-              },
-              {
-                "kind": "account",
-                "path": "slot.scheduled_date",
-                "account": "slotRegistration"
-              }
-            ]
-          }
-        },
-        {
-          "name": "auction",
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  97,
-                  117,
-                  99,
-                  116,
-                  105,
-                  111,
-                  110
-                ]
-              },
-              {
-                "kind": "arg",
-                "path": "auctionId"
-              }
-            ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "auctionId",
-          "type": "u64"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "initializeProgram",
+      "name": "acceptOffer",
       "docs": [
-        "One-time setup. Called once by the admin after deployment.",
-        "Default values:",
-        "fee_bps          = 900  (9%)",
-        "bid_increment_bps = 500  (5%)",
-        "time_buffer_secs = 600  (10 minutes anti-snipe window)",
-        "min_reserve      = 420_000_000 lamports (0.42 SOL)"
+        "Holder accepts an offer: NFT → buyer, SOL → holder (minus fee), fee → treasury.",
+        "`expected_amount` guards against the offer being replaced with a lower one.",
+        "Remaining accounts: the fee distribution recipients, if configured."
       ],
       "discriminator": [
-        176,
-        107,
-        205,
-        168,
-        24,
-        157,
-        175,
-        103
+        227,
+        82,
+        234,
+        131,
+        1,
+        18,
+        48,
+        2
       ],
       "accounts": [
         {
-          "name": "admin",
+          "name": "holder",
+          "docs": [
+            "Current NFT holder. Pays tx fee + buyer ATA rent if needed."
+          ],
           "writable": true,
           "signer": true
         },
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -186,57 +119,30 @@ export type CommonersAuction = {
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "treasury",
-          "type": "pubkey"
-        },
-        {
-          "name": "defaultFeeBps",
-          "type": "u16"
-        },
-        {
-          "name": "bidIncrementBps",
-          "type": "u16"
-        },
-        {
-          "name": "timeBufferSecs",
-          "type": "i64"
-        },
-        {
-          "name": "minReserveLamports",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "listSlot",
-      "docs": [
-        "Called by an NFT holder to lock their MidEvil into escrow",
-        "and reserve a future auction date."
-      ],
-      "discriminator": [
-        206,
-        123,
-        215,
-        156,
-        84,
-        78,
-        37,
-        118
-      ],
-      "accounts": [
-        {
-          "name": "holder",
+          "name": "offer",
           "writable": true,
-          "signer": true
-        },
-        {
-          "name": "config"
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
         },
         {
           "name": "nftMint"
@@ -335,16 +241,16 @@ export type CommonersAuction = {
           }
         },
         {
-          "name": "escrowTokenAccount",
+          "name": "buyerTokenAccount",
           "docs": [
-            "Program-owned escrow token account for this NFT."
+            "Buyer's token account — receives the NFT."
           ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "slot"
+                "path": "buyer"
               },
               {
                 "kind": "const",
@@ -428,9 +334,16 @@ export type CommonersAuction = {
           }
         },
         {
-          "name": "slot",
+          "name": "buyer",
+          "writable": true,
+          "relations": [
+            "offer"
+          ]
+        },
+        {
+          "name": "treasury",
           "docs": [
-            "Slot registration PDA — seeds ensure one slot per mint per date."
+            "Treasury PDA — receives the fee when no fee recipients are set."
           ],
           "writable": true,
           "pda": {
@@ -438,19 +351,15 @@ export type CommonersAuction = {
               {
                 "kind": "const",
                 "value": [
+                  116,
+                  114,
+                  101,
+                  97,
                   115,
-                  108,
-                  111,
-                  116
+                  117,
+                  114,
+                  121
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "nftMint"
-              },
-              {
-                "kind": "arg",
-                "path": "scheduledDate"
               }
             ]
           }
@@ -470,37 +379,36 @@ export type CommonersAuction = {
       ],
       "args": [
         {
-          "name": "scheduledDate",
-          "type": "i64"
-        },
-        {
-          "name": "reservePrice",
+          "name": "expectedAmount",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "placeBid",
+      "name": "approveProposal",
       "docs": [
-        "Called by any wallet to place a bid.",
-        "Automatically refunds the previous bidder and extends",
-        "the auction if within the anti-sniping window."
+        "Proposal manager opens voting on a submitted proposal."
       ],
       "discriminator": [
-        238,
-        77,
-        148,
-        91,
-        200,
-        151,
-        92,
-        146
+        136,
+        108,
+        102,
+        85,
+        98,
+        114,
+        7,
+        147
       ],
       "accounts": [
         {
-          "name": "bidder",
-          "writable": true,
-          "signer": true
+          "name": "proposalManager",
+          "docs": [
+            "Must be config.proposal_manager."
+          ],
+          "signer": true,
+          "relations": [
+            "config"
+          ]
         },
         {
           "name": "config",
@@ -529,103 +437,59 @@ export type CommonersAuction = {
           }
         },
         {
-          "name": "auction",
+          "name": "proposal",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  117,
-                  99,
-                  116,
-                  105,
+                  112,
+                  114,
                   111,
-                  110
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
                 ]
               },
               {
-                "kind": "account",
-                "path": "auction.auction_id",
-                "account": "auctionState"
+                "kind": "arg",
+                "path": "proposalId"
               }
             ]
           }
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "bidVault",
-          "docs": [
-            "SOL vault PDA that holds the current winning bid in escrow.",
-            "Seeds tie it to a specific auction so vaults don't collide."
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  105,
-                  100,
-                  45,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "auction.auction_id",
-                "account": "auctionState"
-              }
-            ]
-          }
-        },
-        {
-          "name": "prevBidder",
-          "docs": [
-            "Previous bidder account — required for the refund transfer.",
-            "Validated at runtime: must match auction.current_bidder if set."
-          ],
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "bidAmount",
+          "name": "proposalId",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "settleAuction",
+      "name": "cancelAuction",
       "docs": [
-        "Called by the backend crank after auction end_time.",
-        "Sends NFT to winner + SOL to seller if reserve met,",
-        "or returns NFT to seller if reserve not met."
+        "Admin cancels an auction that has no bids yet.",
+        "Returns the escrowed NFT to the original seller."
       ],
       "discriminator": [
-        246,
-        196,
-        183,
-        98,
-        222,
-        139,
-        46,
-        133
+        156,
+        43,
+        197,
+        110,
+        218,
+        105,
+        143,
+        182
       ],
       "accounts": [
         {
           "name": "admin",
           "docs": [
-            "Backend crank — must be admin."
+            "Pays ATA rent for the seller if needed."
           ],
           "writable": true,
           "signer": true,
@@ -710,112 +574,49 @@ export type CommonersAuction = {
           }
         },
         {
-          "name": "nftMint"
-        },
-        {
-          "name": "escrowTokenAccount",
+          "name": "dateReservation",
           "docs": [
-            "Escrow token account holding the NFT (owned by slot PDA)."
+            "The slot's date reservation, if it has one — slots listed before",
+            "reservations existed don't."
           ],
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "slot"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  100,
+                  97,
+                  116,
+                  101
                 ]
               },
               {
                 "kind": "account",
-                "path": "nftMint"
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "winnerTokenAccount",
+          "name": "dateReservationPayer",
+          "writable": true
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "escrowTokenAccount",
           "docs": [
-            "Winner's token account — receives NFT if reserve met."
+            "Escrow token account holding the NFT (owned by slot PDA)."
           ],
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "account",
-                "path": "winner"
+                "path": "slot"
               },
               {
                 "kind": "const",
@@ -901,7 +702,7 @@ export type CommonersAuction = {
         {
           "name": "sellerTokenAccount",
           "docs": [
-            "Seller's token account — receives NFT back if reserve not met."
+            "Seller's token account — receives the NFT back."
           ],
           "writable": true,
           "pda": {
@@ -991,36 +792,6 @@ export type CommonersAuction = {
             }
           }
         },
-        {
-          "name": "bidVault",
-          "docs": [
-            "The auction vault holding the winning bid in escrow."
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  98,
-                  105,
-                  100,
-                  45,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "auction.auction_id",
-                "account": "auctionState"
-              }
-            ]
-          }
-        },
         {
           "name": "seller",
           "writable": true,
@@ -1028,20 +799,6 @@ export type CommonersAuction = {
             "auction"
           ]
         },
-        {
-          "name": "winner",
-          "writable": true
-        },
-        {
-          "name": "treasury",
-          "docs": [
-            "Treasury wallet — validated by config has_one constraint."
-          ],
-          "writable": true,
-          "relations": [
-            "config"
-          ]
-        },
         {
           "name": "tokenProgram",
           "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
@@ -1058,20 +815,73 @@ export type CommonersAuction = {
       "args": []
     },
     {
-      "name": "updateParams",
+      "name": "cancelOffer",
       "docs": [
-        "Update global config parameters.",
-        "Only the admin (Squads multisig) can call this."
+        "Buyer withdraws their offer; escrowed lamports and rent are returned."
       ],
       "discriminator": [
-        108,
-        178,
-        190,
-        95,
-        94,
+        92,
         203,
-        116,
-        20
+        223,
+        40,
+        92,
+        89,
+        53,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "offer"
+          ]
+        },
+        {
+          "name": "offer",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.nft_mint",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelParamUpdate",
+      "docs": [
+        "Admin cancels the queued config change."
+      ],
+      "discriminator": [
+        48,
+        233,
+        88,
+        184,
+        201,
+        198,
+        45,
+        199
       ],
       "accounts": [
         {
@@ -1083,7 +893,6 @@ export type CommonersAuction = {
         },
         {
           "name": "config",
-          "writable": true,
           "pda": {
             "seeds": [
               {
@@ -1107,43 +916,6615 @@ export type CommonersAuction = {
               }
             ]
           }
-        }
-      ],
-      "args": [
-        {
-          "name": "newFeeBps",
-          "type": {
-            "option": "u16"
-          }
-        },
-        {
-          "name": "newBidIncrementBps",
-          "type": {
-            "option": "u16"
-          }
-        },
-        {
-          "name": "newTimeBufferSecs",
-          "type": {
-            "option": "i64"
-          }
         },
         {
-          "name": "newMinReserveLamports",
-          "type": {
-            "option": "u64"
+          "name": "pending",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  45,
+                  112,
+                  97,
+                  114,
+                  97,
+                  109,
+                  115
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "newCommonTokenMint",
-          "type": {
-            "option": "pubkey"
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "pending"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "castVote",
+      "docs": [
+        "Commoner NFT holder casts a split vote on an active proposal.",
+        "Weight is the number of Commoners proven on-chain.",
+        "Remaining accounts: `delegation_count` Delegation PDAs naming the voter,",
+        "then (token account, metadata, vote marker) triples, one per Commoner",
+        "held by the voter or a delegating holder. Each NFT votes at most once",
+        "per proposal. Between the two, `overridden_count` VoteRecords of",
+        "delegates whose earlier vote counted the voter's own NFTs — the",
+        "holder's vote takes those NFTs over. Calling again adds more NFTs",
+        "and allocation to the same vote."
+      ],
+      "discriminator": [
+        20,
+        212,
+        15,
+        189,
+        69,
+        180,
+        69,
+        151
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "docs": [
+            "The Commoner NFT holder casting the vote. Signs and pays rent."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
           }
         },
         {
-          "name": "newDiscountTiers",
-          "type": {
-            "option": {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "docs": [
+            "VoteRecord PDA — created on the voter's first call, added to by",
+            "later ones. The NFT markers are what prevent double-voting."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "yes",
+          "type": "u64"
+        },
+        {
+          "name": "no",
+          "type": "u64"
+        },
+        {
+          "name": "abstain",
+          "type": "u64"
+        },
+        {
+          "name": "delegationCount",
+          "type": "u8"
+        },
+        {
+          "name": "overriddenCount",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeAuction",
+      "docs": [
+        "Crank closes a settled, stale-closed or cancelled auction together",
+        "with its bid log, recovering the auction's rent; the log's goes back",
+        "to whoever paid it. A standing bid an unsold auction",
+        "never refunded goes back to its bidder; lamports sent to the bid vault",
+        "after the auction finished are swept to the crank."
+      ],
+      "discriminator": [
+        225,
+        129,
+        91,
+        48,
+        215,
+        73,
+        203,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "crankAuthority",
+          "docs": [
+            "Must be config.crank_authority. Receives the auction's rent."
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidLog",
+          "docs": [
+            "It is only missing for an auction created before bid logs that never",
+            "took a bid — place_bid creates the log otherwise."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidLogPayer",
+          "docs": [
+            "Receives the bid log's rent. Any account when there is no log."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bidVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "currentBidder",
+          "docs": [
+            "Standing bidder — refunded if the auction didn't sell and still holds",
+            "their bid. Any account when there is none."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStaleAuction",
+      "docs": [
+        "Permissionless — closes a stale auction (ended 3+ days ago, still",
+        "unsettled). Returns the escrowed NFT to the original seller and",
+        "refunds the standing bid; the keeper reward comes out of the escrow's",
+        "rent."
+      ],
+      "discriminator": [
+        248,
+        40,
+        163,
+        200,
+        10,
+        21,
+        138,
+        18
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can trigger stale cleanup. Pays tx fee + ATA rent if needed,",
+            "and receives the keeper reward."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "slot",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservation",
+          "docs": [
+            "The slot's date reservation, if it has one — slots listed before",
+            "reservations existed don't."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservationPayer",
+          "writable": true
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow token account holding the NFT (owned by slot PDA)."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "slot"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sellerTokenAccount",
+          "docs": [
+            "Seller's token account — receives the NFT back."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "bidVault",
+          "docs": [
+            "The auction vault holding the standing bid, if any."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "currentBidder",
+          "docs": [
+            "Standing bidder — receives the refund. Any account when there is none."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createAuction",
+      "docs": [
+        "Called by the backend crank (crank_authority) at the start of each",
+        "auction day to open bidding for the scheduled NFT.",
+        "`end_time` is the explicit Unix timestamp for auction close —",
+        "pass the next midnight UTC so the auction ends deterministically",
+        "regardless of when the crank actually runs."
+      ],
+      "discriminator": [
+        234,
+        6,
+        201,
+        246,
+        47,
+        219,
+        176,
+        107
+      ],
+      "accounts": [
+        {
+          "name": "crankAuthority",
+          "docs": [
+            "Backend crank wallet — must be config.crank_authority."
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "slot",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "auctionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidLog",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "auctionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionId",
+          "type": "u64"
+        },
+        {
+          "name": "endTime",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "createProposal",
+      "docs": [
+        "Proposal manager creates an on-chain governance proposal after off-chain review.",
+        "Opens the voting window immediately for `duration_secs`, numbered from",
+        "config.next_proposal_id like submitted proposals.",
+        "`content` is an inline description or a hosted document's URI and SHA-256.",
+        "`param_update` makes it a parameter proposal, applied on execution —",
+        "limited to the fee, bid increment, time buffer, min reserve, discount",
+        "tiers, required collection and max vote weight."
+      ],
+      "discriminator": [
+        132,
+        116,
+        68,
+        174,
+        216,
+        160,
+        198,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "proposalManager",
+          "docs": [
+            "Must be config.proposal_manager."
+          ],
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.next_proposal_id",
+                "account": "programConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposer",
+          "type": "pubkey"
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "content",
+          "type": {
+            "defined": {
+              "name": "proposalContent"
+            }
+          }
+        },
+        {
+          "name": "proposalType",
+          "type": "string"
+        },
+        {
+          "name": "treasurySol",
+          "type": "u64"
+        },
+        {
+          "name": "recipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "paramUpdate",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "proposalParamUpdate"
+              }
+            }
+          }
+        },
+        {
+          "name": "durationSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "delegateVotes",
+      "docs": [
+        "Holder delegates their Commoners' votes to `delegate` until revoked."
+      ],
+      "discriminator": [
+        134,
+        214,
+        180,
+        254,
+        134,
+        143,
+        185,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "executeParamUpdate",
+      "docs": [
+        "Permissionless — applies the queued config change after its eta."
+      ],
+      "discriminator": [
+        202,
+        100,
+        72,
+        53,
+        170,
+        243,
+        222,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pending",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  45,
+                  112,
+                  97,
+                  114,
+                  97,
+                  109,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "pending"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeProposal",
+      "docs": [
+        "Permissionless — pays a passed proposal's treasury_sol from the treasury",
+        "PDA to its recipient and applies its param_update, once",
+        "config.execution_delay_secs has passed since voting ended.",
+        "Runs at most once per proposal."
+      ],
+      "discriminator": [
+        186,
+        60,
+        116,
+        133,
+        108,
+        128,
+        111,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Program-owned treasury PDA that collects protocol fees."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "recipient",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "finalizeProposal",
+      "docs": [
+        "Permissionless — finalizes a proposal after the voting window closes,",
+        "marking it passed or failed by the configured quorum and approval threshold,",
+        "and settles any proposal deposit."
+      ],
+      "discriminator": [
+        23,
+        68,
+        51,
+        167,
+        109,
+        173,
+        187,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Program-owned treasury PDA; receives a forfeited deposit."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeProgram",
+      "docs": [
+        "One-time setup. Called once by the admin after deployment.",
+        "The signer must be the program's upgrade authority.",
+        "Default values:",
+        "fee_bps          = 900  (9%)",
+        "bid_increment_bps = 500  (5%)",
+        "time_buffer_secs = 600  (10 minutes anti-snipe window)",
+        "min_reserve      = 420_000_000 lamports (0.42 SOL)",
+        "param_update_delay_secs = 172_800 (48 hours)",
+        "Protocol fees go to the program-owned treasury PDA until a fee",
+        "distribution table is configured, then to its recipients."
+      ],
+      "discriminator": [
+        176,
+        107,
+        205,
+        168,
+        24,
+        157,
+        175,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Must be the program's upgrade authority. Becomes the config admin."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "docs": [
+            "This program — used to locate its ProgramData account."
+          ],
+          "address": "EWXiRHrYNtMy6wXQsy2oZhops6Dsw5M4GT59Bqb3xPjC"
+        },
+        {
+          "name": "programData",
+          "docs": [
+            "Upgradeable loader ProgramData account holding the upgrade authority."
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "defaultFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "bidIncrementBps",
+          "type": "u16"
+        },
+        {
+          "name": "timeBufferSecs",
+          "type": "i64"
+        },
+        {
+          "name": "minReserveLamports",
+          "type": "u64"
+        },
+        {
+          "name": "requiredCollection",
+          "type": {
+            "option": "pubkey"
+          }
+        },
+        {
+          "name": "paramUpdateDelaySecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "listSlot",
+      "docs": [
+        "Called by an NFT holder to lock their MidEvil into escrow",
+        "and reserve a future auction date.",
+        "`auto_relist` keeps an unsold NFT in escrow for another date,",
+        "lowering the reserve by `reserve_decay_bps` each time."
+      ],
+      "discriminator": [
+        206,
+        123,
+        215,
+        156,
+        84,
+        78,
+        37,
+        118
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "nftMetadata",
+          "docs": [
+            "Metaplex metadata account for collection verification."
+          ]
+        },
+        {
+          "name": "holderTokenAccount",
+          "docs": [
+            "Holder's token account for this NFT."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "holder"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Program-owned escrow token account for this NFT."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "slot"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "slot",
+          "docs": [
+            "Slot registration PDA — seeds ensure one slot per mint per date."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "arg",
+                "path": "scheduledDate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservation",
+          "docs": [
+            "Date reservation PDA — seeds ensure one slot per date across all mints."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "scheduledDate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "scheduledDate",
+          "type": "i64"
+        },
+        {
+          "name": "reservePrice",
+          "type": "u64"
+        },
+        {
+          "name": "autoRelist",
+          "type": "bool"
+        },
+        {
+          "name": "reserveDecayBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "makeOffer",
+      "docs": [
+        "Buyer escrows a standing SOL offer on any NFT from the required",
+        "collection, scheduled for auction or not."
+      ],
+      "discriminator": [
+        214,
+        98,
+        97,
+        35,
+        59,
+        12,
+        44,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "buyer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "nftMetadata",
+          "docs": [
+            "Metaplex metadata account for collection verification."
+          ]
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "Offer PDA — seeds allow one open offer per buyer per mint."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "buyer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateAuction",
+      "docs": [
+        "Permissionless — appends an empty referrer and the lifecycle status,",
+        "derived from the legacy flags and dates, to an AuctionState in the",
+        "original layout."
+      ],
+      "discriminator": [
+        177,
+        229,
+        202,
+        103,
+        229,
+        236,
+        201,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "auction",
+          "docs": [
+            "layout. Owner, discriminator and size are verified in the handler;",
+            "the PDA seeds constraint guarantees the address."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "auctionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidVault",
+          "docs": [
+            "The auction's bid vault — refunds the standing bid of a stale-closed auction."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "auctionId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "currentBidder",
+          "docs": [
+            "Standing bidder of a stale-closed auction — receives the refund.",
+            "Any account otherwise."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "auctionId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateConfig",
+      "docs": [
+        "Admin upgrades ProgramConfig from any earlier layout version to the",
+        "current one, one registered upgrade at a time, giving new fields their",
+        "defaults.",
+        "Safe to call multiple times — a current account is left unchanged."
+      ],
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Must match the admin stored in the config, parsed from raw bytes."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "docs": [
+            "Owner, discriminator and admin are verified from the raw bytes in the",
+            "handler; the PDA seeds constraint guarantees the address."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateProposal",
+      "docs": [
+        "Permissionless — grows a GovernanceProposal created before the content",
+        "URI and hash were appended, so it decodes as an inline proposal."
+      ],
+      "discriminator": [
+        240,
+        117,
+        96,
+        251,
+        253,
+        64,
+        32,
+        112
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "proposal",
+          "docs": [
+            "layout. Owner and discriminator are verified in the handler; the PDA",
+            "seeds constraint guarantees the address."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrateSlot",
+      "docs": [
+        "Permissionless — appends the auto-relist fields, switched off, to a",
+        "SlotRegistration created before they existed."
+      ],
+      "discriminator": [
+        16,
+        44,
+        133,
+        226,
+        191,
+        205,
+        230,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "slot",
+          "docs": [
+            "layout. Owner, discriminator and size are verified in the handler;",
+            "the PDA seeds constraint guarantees the address."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "nftMint"
+              },
+              {
+                "kind": "arg",
+                "path": "scheduledDate"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "nftMint",
+          "type": "pubkey"
+        },
+        {
+          "name": "scheduledDate",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "placeBid",
+      "docs": [
+        "Called by any wallet to place a bid.",
+        "Automatically refunds the previous bidder and extends",
+        "the auction if within the anti-sniping window.",
+        "`referrer` is the partner frontend credited if this bid wins."
+      ],
+      "discriminator": [
+        238,
+        77,
+        148,
+        91,
+        200,
+        151,
+        92,
+        146
+      ],
+      "accounts": [
+        {
+          "name": "bidder",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidVault",
+          "docs": [
+            "SOL vault PDA that holds the current winning bid in escrow.",
+            "Seeds tie it to a specific auction so vaults don't collide."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "bidLog",
+          "docs": [
+            "Created here for auctions opened before bid logs existed."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  108,
+                  111,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prevBidder",
+          "docs": [
+            "Previous bidder account — required for the refund transfer.",
+            "Validated at runtime: must match auction.current_bidder if set."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "bidAmount",
+          "type": "u64"
+        },
+        {
+          "name": "referrer",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "docs": [
+        "Admin nominates a successor (None cancels). Takes effect only once",
+        "the nominee calls accept_admin."
+      ],
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "queueParamUpdate",
+      "docs": [
+        "Admin queues a config change behind the governed timelock.",
+        "Applied values stay on ProgramConfig; pending ones on PendingParamUpdate."
+      ],
+      "discriminator": [
+        53,
+        218,
+        121,
+        96,
+        202,
+        181,
+        22,
+        167
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "pending",
+          "docs": [
+            "Fails to init while another update is pending — cancel it first."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  101,
+                  110,
+                  100,
+                  105,
+                  110,
+                  103,
+                  45,
+                  112,
+                  97,
+                  114,
+                  97,
+                  109,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "update",
+          "type": {
+            "defined": {
+              "name": "paramUpdate"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "reclaimExpiredSlot",
+      "docs": [
+        "Permissionless — returns the NFT from a slot whose date passed 3+ days",
+        "ago without an auction being created, closing the slot. The keeper",
+        "reward comes out of the escrow's rent."
+      ],
+      "discriminator": [
+        59,
+        223,
+        138,
+        119,
+        58,
+        148,
+        44,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can trigger reclaim. Pays tx fee + ATA rent if needed, and",
+            "receives the keeper reward."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "slot",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservation",
+          "docs": [
+            "The slot's booking of its date. Slots listed before date reservations",
+            "existed have none."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservationPayer",
+          "writable": true
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow token account holding the NFT (owned by slot PDA)."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "slot"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "ownerTokenAccount",
+          "docs": [
+            "Owner's token account — receives the NFT back."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "owner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "closed slot and escrow."
+          ],
+          "writable": true,
+          "relations": [
+            "slot"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectProposal",
+      "docs": [
+        "Proposal manager turns down a submitted proposal awaiting approval —",
+        "or anyone expires it after the approval window — refunding the deposit."
+      ],
+      "discriminator": [
+        114,
+        162,
+        164,
+        82,
+        191,
+        11,
+        102,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "The proposal manager, or anyone once the approval window has passed."
+          ],
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposer",
+          "writable": true,
+          "relations": [
+            "proposal"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeDelegation",
+      "docs": [
+        "Holder revokes their delegation, recovering its rent."
+      ],
+      "discriminator": [
+        188,
+        92,
+        135,
+        67,
+        160,
+        181,
+        54,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "holder",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "delegation"
+          ]
+        },
+        {
+          "name": "delegation",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "holder"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setAutoRelist",
+      "docs": [
+        "Seller switches automatic relisting on or off for one of their slots.",
+        "Turning it off makes an unsold NFT go back to the seller at settlement."
+      ],
+      "discriminator": [
+        242,
+        206,
+        146,
+        136,
+        83,
+        26,
+        70,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "signer": true,
+          "relations": [
+            "slot"
+          ]
+        },
+        {
+          "name": "slot",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "slot.nft_mint",
+                "account": "slotRegistration"
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "autoRelist",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "docs": [
+        "Admin sets the emergency pause bitflags (ProgramConfig::PAUSE_*).",
+        "Settlement and refund paths stay available while paused."
+      ],
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setRoles",
+      "docs": [
+        "Admin assigns the crank and proposal manager roles."
+      ],
+      "discriminator": [
+        119,
+        86,
+        129,
+        161,
+        55,
+        23,
+        250,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "crankAuthority",
+          "type": "pubkey"
+        },
+        {
+          "name": "proposalManager",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "settleAuction",
+      "docs": [
+        "Permissionless — callable by anyone after auction end_time.",
+        "Sends NFT to winner + SOL to seller if reserve met,",
+        "or returns NFT to seller if reserve not met.",
+        "For auto-relist slots, an unsold NFT is instead re-registered for the",
+        "first free date within SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS of",
+        "tomorrow, or for `relist_date` when the seller settles. The payer's",
+        "rent for the new slot and escrow is refunded from the old ones.",
+        "Remaining accounts when sold: the fee distribution recipients (if",
+        "configured), then — with royalties enabled — the verified creators.",
+        "When relisting without `relist_date`: the DateReservations of the",
+        "booked dates from tomorrow up to the first free one."
+      ],
+      "discriminator": [
+        246,
+        196,
+        183,
+        98,
+        222,
+        139,
+        46,
+        133
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Anyone can trigger settlement. Pays tx fee + ATA rent if needed."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "auction",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  117,
+                  99,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "slot",
+          "docs": [
+            "Closed to the payer when the NFT is relisted."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  108,
+                  111,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "nftMint"
+        },
+        {
+          "name": "dateReservation",
+          "docs": [
+            "Reservation of the slot's date, closed here. Slots listed before",
+            "date reservations existed have none; the PDA is passed regardless."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  100,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "slot.scheduled_date",
+                "account": "slotRegistration"
+              }
+            ]
+          }
+        },
+        {
+          "name": "dateReservationPayer",
+          "writable": true
+        },
+        {
+          "name": "nftMetadata",
+          "docs": [
+            "Metaplex metadata account — read for creator royalties when enabled."
+          ]
+        },
+        {
+          "name": "escrowTokenAccount",
+          "docs": [
+            "Escrow token account holding the NFT (owned by slot PDA)."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "slot"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "winnerTokenAccount",
+          "docs": [
+            "Winner's token account — receives NFT if reserve met."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "winner"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "sellerTokenAccount",
+          "docs": [
+            "Seller's token account — receives NFT back if reserve not met."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "seller"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  6,
+                  221,
+                  246,
+                  225,
+                  215,
+                  101,
+                  161,
+                  147,
+                  217,
+                  203,
+                  225,
+                  70,
+                  206,
+                  235,
+                  121,
+                  172,
+                  28,
+                  180,
+                  133,
+                  237,
+                  95,
+                  91,
+                  55,
+                  145,
+                  58,
+                  140,
+                  245,
+                  133,
+                  126,
+                  255,
+                  0,
+                  169
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "nftMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "bidVault",
+          "docs": [
+            "The auction vault holding the winning bid in escrow."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  98,
+                  105,
+                  100,
+                  45,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "auction.auction_id",
+                "account": "auctionState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "seller",
+          "writable": true,
+          "relations": [
+            "auction"
+          ]
+        },
+        {
+          "name": "winner",
+          "writable": true
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Treasury PDA — receives the protocol fee when no fee recipients are set."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "referrer",
+          "docs": [
+            "Referrer credited with the winning bid. Required when the auction has",
+            "a referrer and referral_bps is non-zero."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "relistSlot",
+          "docs": [
+            "New slot registration for an auto-relisted NFT. Only required when the",
+            "slot opted into auto-relist and reserve was not met."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "relistEscrowTokenAccount",
+          "docs": [
+            "Escrow ATA for `relist_slot`. Only required alongside it.",
+            "which validates the address against (relist_slot, nft_mint)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "relistDateReservation",
+          "docs": [
+            "Date reservation for `relist_slot`. Only required alongside it."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "relistDate",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "submitProposal",
+      "docs": [
+        "Commoner holder submits a proposal directly, locking",
+        "config.proposal_deposit_lamports until it is finalized, rejected or",
+        "vetoed. Voting opens immediately unless config.proposal_approval_required.",
+        "The proposal takes the ID config.next_proposal_id."
+      ],
+      "discriminator": [
+        224,
+        38,
+        210,
+        52,
+        167,
+        150,
+        221,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "docs": [
+            "Commoner holder submitting the proposal. Pays rent and the deposit."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposerTokenAccount",
+          "docs": [
+            "Proposer's token account holding a Commoner."
+          ]
+        },
+        {
+          "name": "proposerMetadata"
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "config.next_proposal_id",
+                "account": "programConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "content",
+          "type": {
+            "defined": {
+              "name": "proposalContent"
+            }
+          }
+        },
+        {
+          "name": "proposalType",
+          "type": "string"
+        },
+        {
+          "name": "treasurySol",
+          "type": "u64"
+        },
+        {
+          "name": "recipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "paramUpdate",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "proposalParamUpdate"
+              }
+            }
+          }
+        },
+        {
+          "name": "durationSecs",
+          "type": "i64"
+        }
+      ]
+    },
+    {
+      "name": "updateVote",
+      "docs": [
+        "Voter changes their yes/no/abstain split before voting ends."
+      ],
+      "discriminator": [
+        95,
+        223,
+        212,
+        112,
+        234,
+        242,
+        37,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        },
+        {
+          "name": "yes",
+          "type": "u64"
+        },
+        {
+          "name": "no",
+          "type": "u64"
+        },
+        {
+          "name": "abstain",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "vetoProposal",
+      "docs": [
+        "Admin vetoes a pending, active or passed proposal, forfeiting any",
+        "deposit it still holds to the treasury."
+      ],
+      "discriminator": [
+        177,
+        197,
+        208,
+        96,
+        169,
+        68,
+        23,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "config"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "docs": [
+            "Program-owned treasury PDA; receives a forfeited deposit."
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  114,
+                  101,
+                  97,
+                  115,
+                  117,
+                  114,
+                  121
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVote",
+      "docs": [
+        "Voter withdraws their vote before voting ends, closing the record.",
+        "Remaining accounts: every NFT marker this vote holds, closed as well."
+      ],
+      "discriminator": [
+        243,
+        255,
+        70,
+        200,
+        3,
+        242,
+        103,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "voter",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "voteRecord"
+          ]
+        },
+        {
+          "name": "config",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  103,
+                  114,
+                  97,
+                  109,
+                  45,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voteRecord",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "proposalId"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "proposalId",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "auctionState",
+      "discriminator": [
+        252,
+        227,
+        205,
+        147,
+        72,
+        64,
+        250,
+        126
+      ]
+    },
+    {
+      "name": "bidLog",
+      "discriminator": [
+        87,
+        143,
+        94,
+        55,
+        214,
+        147,
+        218,
+        53
+      ]
+    },
+    {
+      "name": "dateReservation",
+      "discriminator": [
+        173,
+        70,
+        29,
+        33,
+        156,
+        2,
+        254,
+        31
+      ]
+    },
+    {
+      "name": "delegation",
+      "discriminator": [
+        237,
+        90,
+        140,
+        159,
+        124,
+        255,
+        243,
+        80
+      ]
+    },
+    {
+      "name": "governanceProposal",
+      "discriminator": [
+        53,
+        107,
+        240,
+        190,
+        43,
+        73,
+        65,
+        143
+      ]
+    },
+    {
+      "name": "offer",
+      "discriminator": [
+        215,
+        88,
+        60,
+        71,
+        170,
+        162,
+        73,
+        229
+      ]
+    },
+    {
+      "name": "pendingParamUpdate",
+      "discriminator": [
+        97,
+        142,
+        223,
+        185,
+        187,
+        92,
+        36,
+        109
+      ]
+    },
+    {
+      "name": "programConfig",
+      "discriminator": [
+        196,
+        210,
+        90,
+        231,
+        144,
+        149,
+        140,
+        63
+      ]
+    },
+    {
+      "name": "slotRegistration",
+      "discriminator": [
+        119,
+        114,
+        239,
+        196,
+        78,
+        13,
+        64,
+        243
+      ]
+    },
+    {
+      "name": "voteRecord",
+      "discriminator": [
+        112,
+        9,
+        123,
+        165,
+        234,
+        9,
+        157,
+        167
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adminAccepted",
+      "discriminator": [
+        174,
+        12,
+        76,
+        139,
+        158,
+        99,
+        110,
+        254
+      ]
+    },
+    {
+      "name": "adminProposed",
+      "discriminator": [
+        129,
+        249,
+        226,
+        227,
+        199,
+        82,
+        110,
+        243
+      ]
+    },
+    {
+      "name": "auctionCancelled",
+      "discriminator": [
+        22,
+        32,
+        51,
+        83,
+        215,
+        194,
+        171,
+        209
+      ]
+    },
+    {
+      "name": "auctionClosed",
+      "discriminator": [
+        104,
+        72,
+        168,
+        177,
+        241,
+        79,
+        231,
+        167
+      ]
+    },
+    {
+      "name": "auctionCreated",
+      "discriminator": [
+        133,
+        190,
+        194,
+        65,
+        172,
+        0,
+        70,
+        178
+      ]
+    },
+    {
+      "name": "auctionExtended",
+      "discriminator": [
+        204,
+        229,
+        238,
+        200,
+        189,
+        21,
+        50,
+        41
+      ]
+    },
+    {
+      "name": "auctionSettled",
+      "discriminator": [
+        61,
+        151,
+        131,
+        170,
+        95,
+        203,
+        219,
+        147
+      ]
+    },
+    {
+      "name": "autoRelistUpdated",
+      "discriminator": [
+        229,
+        93,
+        102,
+        52,
+        165,
+        117,
+        40,
+        142
+      ]
+    },
+    {
+      "name": "bidPlaced",
+      "discriminator": [
+        135,
+        53,
+        176,
+        83,
+        193,
+        69,
+        108,
+        61
+      ]
+    },
+    {
+      "name": "delegateVoteOverridden",
+      "discriminator": [
+        173,
+        15,
+        217,
+        36,
+        6,
+        118,
+        102,
+        27
+      ]
+    },
+    {
+      "name": "delegationRevoked",
+      "discriminator": [
+        59,
+        158,
+        142,
+        49,
+        164,
+        116,
+        220,
+        8
+      ]
+    },
+    {
+      "name": "offerAccepted",
+      "discriminator": [
+        81,
+        238,
+        238,
+        115,
+        140,
+        18,
+        8,
+        20
+      ]
+    },
+    {
+      "name": "offerCancelled",
+      "discriminator": [
+        45,
+        42,
+        175,
+        214,
+        51,
+        192,
+        154,
+        9
+      ]
+    },
+    {
+      "name": "offerMade",
+      "discriminator": [
+        11,
+        5,
+        86,
+        210,
+        3,
+        4,
+        25,
+        154
+      ]
+    },
+    {
+      "name": "paramUpdateCancelled",
+      "discriminator": [
+        160,
+        23,
+        236,
+        126,
+        203,
+        178,
+        118,
+        26
+      ]
+    },
+    {
+      "name": "paramUpdateQueued",
+      "discriminator": [
+        198,
+        155,
+        234,
+        11,
+        69,
+        210,
+        182,
+        123
+      ]
+    },
+    {
+      "name": "paramsUpdated",
+      "discriminator": [
+        2,
+        163,
+        138,
+        99,
+        135,
+        11,
+        136,
+        169
+      ]
+    },
+    {
+      "name": "pauseUpdated",
+      "discriminator": [
+        203,
+        203,
+        33,
+        225,
+        130,
+        103,
+        90,
+        105
+      ]
+    },
+    {
+      "name": "proposalApproved",
+      "discriminator": [
+        70,
+        49,
+        155,
+        228,
+        157,
+        43,
+        88,
+        49
+      ]
+    },
+    {
+      "name": "proposalCreated",
+      "discriminator": [
+        186,
+        8,
+        160,
+        108,
+        81,
+        13,
+        51,
+        206
+      ]
+    },
+    {
+      "name": "proposalDepositSettled",
+      "discriminator": [
+        137,
+        194,
+        12,
+        52,
+        141,
+        131,
+        232,
+        249
+      ]
+    },
+    {
+      "name": "proposalExecuted",
+      "discriminator": [
+        92,
+        213,
+        189,
+        201,
+        101,
+        83,
+        111,
+        83
+      ]
+    },
+    {
+      "name": "proposalFinalized",
+      "discriminator": [
+        159,
+        104,
+        210,
+        220,
+        86,
+        209,
+        61,
+        51
+      ]
+    },
+    {
+      "name": "proposalRejected",
+      "discriminator": [
+        77,
+        15,
+        161,
+        38,
+        240,
+        201,
+        24,
+        208
+      ]
+    },
+    {
+      "name": "proposalVetoed",
+      "discriminator": [
+        185,
+        29,
+        77,
+        23,
+        134,
+        251,
+        155,
+        27
+      ]
+    },
+    {
+      "name": "rolesUpdated",
+      "discriminator": [
+        81,
+        37,
+        176,
+        32,
+        30,
+        204,
+        251,
+        246
+      ]
+    },
+    {
+      "name": "slotListed",
+      "discriminator": [
+        23,
+        0,
+        4,
+        115,
+        185,
+        245,
+        226,
+        48
+      ]
+    },
+    {
+      "name": "slotReclaimed",
+      "discriminator": [
+        240,
+        76,
+        247,
+        5,
+        140,
+        181,
+        248,
+        124
+      ]
+    },
+    {
+      "name": "staleClosed",
+      "discriminator": [
+        100,
+        120,
+        229,
+        45,
+        247,
+        95,
+        219,
+        224
+      ]
+    },
+    {
+      "name": "voteCast",
+      "discriminator": [
+        39,
+        53,
+        195,
+        104,
+        188,
+        17,
+        225,
+        213
+      ]
+    },
+    {
+      "name": "voteUpdated",
+      "discriminator": [
+        139,
+        156,
+        106,
+        119,
+        137,
+        83,
+        39,
+        95
+      ]
+    },
+    {
+      "name": "voteWithdrawn",
+      "discriminator": [
+        124,
+        163,
+        22,
+        0,
+        175,
+        223,
+        243,
+        20
+      ]
+    },
+    {
+      "name": "votesDelegated",
+      "discriminator": [
+        161,
+        23,
+        128,
+        137,
+        139,
+        178,
+        108,
+        25
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "auctionNotStarted",
+      "msg": "Auction has not started yet"
+    },
+    {
+      "code": 6001,
+      "name": "auctionEnded",
+      "msg": "Auction has already ended"
+    },
+    {
+      "code": 6002,
+      "name": "auctionNotEnded",
+      "msg": "Auction has not ended yet"
+    },
+    {
+      "code": 6003,
+      "name": "alreadySettled",
+      "msg": "Auction has already been settled"
+    },
+    {
+      "code": 6004,
+      "name": "bidTooLow",
+      "msg": "Bid is below the minimum required amount"
+    },
+    {
+      "code": 6005,
+      "name": "reserveTooLow",
+      "msg": "Reserve price is below the global minimum"
+    },
+    {
+      "code": 6006,
+      "name": "slotTaken",
+      "msg": "Slot is already taken for this date"
+    },
+    {
+      "code": 6007,
+      "name": "notEscrowed",
+      "msg": "Slot has not been escrowed yet"
+    },
+    {
+      "code": 6008,
+      "name": "slotConsumed",
+      "msg": "Slot has already been consumed"
+    },
+    {
+      "code": 6009,
+      "name": "unauthorized",
+      "msg": "Unauthorized — admin only"
+    },
+    {
+      "code": 6010,
+      "name": "overflow",
+      "msg": "Arithmetic overflow"
+    },
+    {
+      "code": 6011,
+      "name": "dateInPast",
+      "msg": "Scheduled date is in the past"
+    },
+    {
+      "code": 6012,
+      "name": "mintMismatch",
+      "msg": "NFT mint does not match the registered slot"
+    },
+    {
+      "code": 6013,
+      "name": "sellerMismatch",
+      "msg": "Seller does not match the registered slot owner"
+    },
+    {
+      "code": 6014,
+      "name": "invalidEndTime",
+      "msg": "end_time must be in the future and no more than 48 hours away"
+    },
+    {
+      "code": 6015,
+      "name": "proposalNotActive",
+      "msg": "Proposal is not active"
+    },
+    {
+      "code": 6016,
+      "name": "votingEnded",
+      "msg": "Voting period has ended"
+    },
+    {
+      "code": 6017,
+      "name": "allocationExceedsWeight",
+      "msg": "Vote allocation exceeds voter's weight"
+    },
+    {
+      "code": 6018,
+      "name": "noVotesAllocated",
+      "msg": "No votes allocated"
+    },
+    {
+      "code": 6019,
+      "name": "titleTooLong",
+      "msg": "Title exceeds maximum length"
+    },
+    {
+      "code": 6020,
+      "name": "descriptionTooLong",
+      "msg": "Description exceeds maximum length"
+    },
+    {
+      "code": 6021,
+      "name": "typeTooLong",
+      "msg": "Proposal type exceeds maximum length"
+    },
+    {
+      "code": 6022,
+      "name": "collectionNotVerified",
+      "msg": "NFT collection is not verified or metadata is missing"
+    },
+    {
+      "code": 6023,
+      "name": "collectionMismatch",
+      "msg": "NFT is not from the required collection"
+    },
+    {
+      "code": 6024,
+      "name": "feeTooHigh",
+      "msg": "Fee basis points exceed 10000 (100%)"
+    },
+    {
+      "code": 6025,
+      "name": "invalidBidIncrement",
+      "msg": "Bid increment basis points must be between 0 and 5000"
+    },
+    {
+      "code": 6026,
+      "name": "invalidTimeBuffer",
+      "msg": "Time buffer seconds must be non-negative"
+    },
+    {
+      "code": 6027,
+      "name": "invalidScheduledDate",
+      "msg": "Scheduled date must be midnight UTC (Unix timestamp divisible by 86400)"
+    },
+    {
+      "code": 6028,
+      "name": "auctionIdMismatch",
+      "msg": "auction_id must equal the slot's scheduled_date"
+    },
+    {
+      "code": 6029,
+      "name": "auctionNotStale",
+      "msg": "Auction must be ended for at least 3 days before it can be closed as stale"
+    },
+    {
+      "code": 6030,
+      "name": "invalidFinalStatus",
+      "msg": "Invalid finalization status — must be 1 (passed), 2 (failed), or 3 (queued)"
+    },
+    {
+      "code": 6031,
+      "name": "votingStillActive",
+      "msg": "Voting period is still active — cannot finalize before votes close"
+    },
+    {
+      "code": 6032,
+      "name": "invalidDuration",
+      "msg": "Proposal duration must be between 1 hour and 7 days"
+    },
+    {
+      "code": 6033,
+      "name": "tallyOverflow",
+      "msg": "Vote weight or tally would exceed the configured max vote weight"
+    },
+    {
+      "code": 6034,
+      "name": "invalidReserveDecay",
+      "msg": "Reserve decay basis points exceed 10000 (100%)"
+    },
+    {
+      "code": 6035,
+      "name": "relistDateRequired",
+      "msg": "Slot is set to auto-relist — relist accounts are required"
+    },
+    {
+      "code": 6036,
+      "name": "relistSlotMismatch",
+      "msg": "Relist slot or date reservation does not match the PDA for relist_date"
+    },
+    {
+      "code": 6037,
+      "name": "relistDateNotAllowed",
+      "msg": "Only the seller can choose the relist date"
+    },
+    {
+      "code": 6038,
+      "name": "invalidSlotAccount",
+      "msg": "Account is not a SlotRegistration owned by this program"
+    },
+    {
+      "code": 6039,
+      "name": "bookedDateMismatch",
+      "msg": "Booked date accounts must be the reservations of consecutive dates from tomorrow"
+    },
+    {
+      "code": 6040,
+      "name": "noFreeRelistDate",
+      "msg": "No free date within the relist lookahead — the seller must pick relist_date"
+    },
+    {
+      "code": 6041,
+      "name": "reservationPayerMismatch",
+      "msg": "Date reservation payer does not match the one it records"
+    },
+    {
+      "code": 6042,
+      "name": "offerTooLow",
+      "msg": "Offer is below the global minimum reserve"
+    },
+    {
+      "code": 6043,
+      "name": "offerAmountMismatch",
+      "msg": "Offer amount changed since it was quoted"
+    },
+    {
+      "code": 6044,
+      "name": "invalidMetadata",
+      "msg": "NFT metadata account is missing or malformed"
+    },
+    {
+      "code": 6045,
+      "name": "creatorMismatch",
+      "msg": "Creator accounts do not match the verified creators in the NFT metadata"
+    },
+    {
+      "code": 6046,
+      "name": "royaltyCapTooHigh",
+      "msg": "Royalty cap basis points exceed 10000 (100%)"
+    },
+    {
+      "code": 6047,
+      "name": "referrerMismatch",
+      "msg": "Referrer account does not match the winning bid's referrer"
+    },
+    {
+      "code": 6048,
+      "name": "selfReferral",
+      "msg": "Bidders cannot refer their own bid"
+    },
+    {
+      "code": 6049,
+      "name": "invalidFeeSplit",
+      "msg": "Fee recipient shares must sum to 10000 basis points"
+    },
+    {
+      "code": 6050,
+      "name": "feeRecipientMismatch",
+      "msg": "Fee recipient accounts do not match the fee distribution table"
+    },
+    {
+      "code": 6051,
+      "name": "invalidFeeRecipient",
+      "msg": "Fee recipients must be distinct, non-default addresses"
+    },
+    {
+      "code": 6052,
+      "name": "slotNotExpired",
+      "msg": "Slot date must be at least 3 days past before it can be reclaimed"
+    },
+    {
+      "code": 6053,
+      "name": "settlerRewardTooHigh",
+      "msg": "Settler reward exceeds the maximum"
+    },
+    {
+      "code": 6054,
+      "name": "keeperRewardTooHigh",
+      "msg": "Keeper reward exceeds the maximum"
+    },
+    {
+      "code": 6055,
+      "name": "invalidConfigAccount",
+      "msg": "Account is not an initialized ProgramConfig"
+    },
+    {
+      "code": 6056,
+      "name": "unsupportedConfigVersion",
+      "msg": "ProgramConfig version is not one this program can migrate"
+    },
+    {
+      "code": 6057,
+      "name": "programPaused",
+      "msg": "This capability is paused by the admin"
+    },
+    {
+      "code": 6058,
+      "name": "invalidPauseFlags",
+      "msg": "Unknown pause flag bits"
+    },
+    {
+      "code": 6059,
+      "name": "commonerCollectionNotSet",
+      "msg": "Commoner collection is not configured"
+    },
+    {
+      "code": 6060,
+      "name": "invalidVoteAccounts",
+      "msg": "Vote accounts must be (token account, metadata) pairs"
+    },
+    {
+      "code": 6061,
+      "name": "invalidCommonerTokenAccount",
+      "msg": "Token account must be owned by the voter and hold exactly one NFT"
+    },
+    {
+      "code": 6062,
+      "name": "duplicateNft",
+      "msg": "The same NFT was passed more than once"
+    },
+    {
+      "code": 6063,
+      "name": "nftAlreadyVoted",
+      "msg": "This NFT has already voted on this proposal"
+    },
+    {
+      "code": 6064,
+      "name": "voteMarkerMismatch",
+      "msg": "NFT vote marker address does not match the proposal and mint"
+    },
+    {
+      "code": 6065,
+      "name": "selfDelegation",
+      "msg": "Cannot delegate votes to yourself"
+    },
+    {
+      "code": 6066,
+      "name": "invalidDelegation",
+      "msg": "Delegation account is invalid or not delegated to this voter"
+    },
+    {
+      "code": 6067,
+      "name": "overriddenVoteRecordMismatch",
+      "msg": "Vote record does not belong to the delegate being overridden"
+    },
+    {
+      "code": 6068,
+      "name": "invalidGovernanceThreshold",
+      "msg": "Quorum must be at most 10000 bps and approval threshold below 10000 bps"
+    },
+    {
+      "code": 6069,
+      "name": "proposalNotVetoable",
+      "msg": "Proposal can no longer be vetoed"
+    },
+    {
+      "code": 6070,
+      "name": "proposalNotPassed",
+      "msg": "Only passed proposals can be executed"
+    },
+    {
+      "code": 6071,
+      "name": "executionDelayNotElapsed",
+      "msg": "Execution delay has not elapsed since voting ended"
+    },
+    {
+      "code": 6072,
+      "name": "insufficientTreasury",
+      "msg": "Treasury balance is too low to pay this proposal"
+    },
+    {
+      "code": 6073,
+      "name": "invalidStatusTransition",
+      "msg": "Auction status does not allow this transition"
+    },
+    {
+      "code": 6074,
+      "name": "auctionHasBids",
+      "msg": "Auction already has bids"
+    },
+    {
+      "code": 6075,
+      "name": "bidVaultNotEmpty",
+      "msg": "Bid vault still holds lamports"
+    },
+    {
+      "code": 6076,
+      "name": "invalidAuctionAccount",
+      "msg": "Account is not an AuctionState owned by this program"
+    },
+    {
+      "code": 6077,
+      "name": "invalidParamUpdateDelay",
+      "msg": "Delay must be at most 30 days, and the param update delay at least 1 second"
+    },
+    {
+      "code": 6078,
+      "name": "paramUpdateNotReady",
+      "msg": "Queued param update has not reached its eta"
+    },
+    {
+      "code": 6079,
+      "name": "proposalNotPending",
+      "msg": "Proposal is not awaiting approval"
+    },
+    {
+      "code": 6080,
+      "name": "approvalWindowOpen",
+      "msg": "Only the proposal manager can reject a proposal before its approval window ends"
+    },
+    {
+      "code": 6081,
+      "name": "invalidMaxVoteWeight",
+      "msg": "Max vote weight must be greater than zero"
+    },
+    {
+      "code": 6082,
+      "name": "invalidProposalContent",
+      "msg": "Hosted proposal needs a URI of at most 200 chars and a non-zero content hash"
+    },
+    {
+      "code": 6083,
+      "name": "invalidProposalAccount",
+      "msg": "Account is not a GovernanceProposal"
+    },
+    {
+      "code": 6084,
+      "name": "invalidBidLogAccount",
+      "msg": "Account at the bid log address is not a BidLog"
+    },
+    {
+      "code": 6085,
+      "name": "bidLogPayerMismatch",
+      "msg": "Bid log payer does not match the one it records"
+    },
+    {
+      "code": 6086,
+      "name": "bidderMismatch",
+      "msg": "Bidder account does not match the auction's standing bidder"
+    }
+  ],
+  "types": [
+    {
+      "name": "adminAccepted",
+      "docs": [
+        "Admin handoff completed by the pending admin."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposed",
+      "docs": [
+        "Admin handoff started (or cancelled, when `pending_admin` is None).",
+        "The pending admin must call accept_admin to complete it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionCancelled",
+      "docs": [
+        "The admin cancelled an auction before any bid; its NFT was returned."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionClosed",
+      "docs": [
+        "A finished auction and its bid log were closed; `refund` is a standing bid",
+        "the unsold auction still owed its bidder, `swept` what was left in its bid",
+        "vault after that and went to the crank."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "totalBids",
+            "type": "u64"
+          },
+          {
+            "name": "refundedBidder",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          },
+          {
+            "name": "swept",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          },
+          {
+            "name": "feeBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionExtended",
+      "docs": [
+        "Anti-snipe extension triggered by a late bid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "previousEndTime",
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionSettled",
+      "docs": [
+        "An auction was settled. When sold, `price` splits exactly into",
+        "seller_proceeds + protocol_fee + referral + royalties + settler_reward.",
+        "When unsold, every amount is zero and `relist_date` is set if the NFT was",
+        "relisted rather than returned."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "winner",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "price",
+            "type": "u64"
+          },
+          {
+            "name": "sellerProceeds",
+            "type": "u64"
+          },
+          {
+            "name": "protocolFee",
+            "type": "u64"
+          },
+          {
+            "name": "referral",
+            "type": "u64"
+          },
+          {
+            "name": "royalties",
+            "type": "u64"
+          },
+          {
+            "name": "settlerReward",
+            "type": "u64"
+          },
+          {
+            "name": "relistDate",
+            "type": {
+              "option": "i64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionState",
+      "docs": [
+        "Live auction state for a single day's auction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "docs": [
+              "The NFT mint being auctioned."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "docs": [
+              "Wallet that listed the NFT (receives proceeds minus fee)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reservePrice",
+            "docs": [
+              "Minimum acceptable final bid in lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp when bidding opens."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endTime",
+            "docs": [
+              "Unix timestamp when bidding closes.",
+              "Extended by time_buffer_secs on late bids."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "currentBid",
+            "docs": [
+              "Highest bid placed so far in lamports. Zero if no bids."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "currentBidder",
+            "docs": [
+              "Wallet that placed the current highest bid.",
+              "Refunded automatically when outbid. Cleared, with the bid, once it is",
+              "refunded by an unsold close."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "Fee bps applied at settlement (resolved from seller's COMMON balance)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "settled",
+            "docs": [
+              "Whether this auction reached a terminal status.",
+              "Mirrors `status` for clients that predate it."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserveMet",
+            "docs": [
+              "Whether the auction settled as sold. Mirrors `status`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "auctionId",
+            "docs": [
+              "Auction sequence number (day index, 1-based)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "currentReferrer",
+            "docs": [
+              "Frontend/partner credited with the current highest bid.",
+              "Receives referral_bps of the protocol fee at settlement."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Lifecycle status as last written. See status_at for the current one."
+            ],
+            "type": {
+              "defined": {
+                "name": "auctionStatus"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "auctionStatus",
+      "docs": [
+        "Lifecycle of an auction.",
+        "",
+        "Scheduled → Live → Ended are time-driven: the stored value may lag the",
+        "clock, so read it through AuctionState::status_at. The remaining states",
+        "are terminal and only reached through AuctionState::transition."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "scheduled"
+          },
+          {
+            "name": "live"
+          },
+          {
+            "name": "ended"
+          },
+          {
+            "name": "settledSold"
+          },
+          {
+            "name": "settledUnsold"
+          },
+          {
+            "name": "closedStale"
+          },
+          {
+            "name": "cancelled"
+          }
+        ]
+      }
+    },
+    {
+      "name": "autoRelistUpdated",
+      "docs": [
+        "A seller switched automatic relisting on or off for a slot."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "scheduledDate",
+            "type": "i64"
+          },
+          {
+            "name": "autoRelist",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidEntry",
+      "docs": [
+        "One accepted bid."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Bid amount in lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix timestamp when the bid was placed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "causedExtension",
+            "docs": [
+              "1 if this bid triggered the anti-snipe extension."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidLog",
+      "docs": [
+        "Bid history for one auction, appended by place_bid.",
+        "A ring buffer: the last MAX_LOGGED_BIDS bids are kept, and `total_bids`",
+        "counts every bid ever placed. Closed together with the auction, refunding",
+        "its rent to `payer`.",
+        "PDA seeds: [\"bid-log\", auction_id]"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "totalBids",
+            "docs": [
+              "Bids placed so far. The next entry is written at",
+              "`total_bids % MAX_LOGGED_BIDS`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent — the crank at create_auction, or the first",
+              "bidder on an auction created before bid logs."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "bidEntry"
+                  }
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bidPlaced",
+      "docs": [
+        "A new high bid. `refunded_bidder` got `refund_amount` lamports back."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
+            "type": "u64"
+          },
+          {
+            "name": "bidder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "refundedBidder",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "refundAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "dateReservation",
+      "docs": [
+        "Booking of an auction date. create_auction opens one auction per date, so",
+        "list_slot and auto-relist both create this PDA for the slot's date and",
+        "fail if another NFT already booked it. Closed once the slot's date is used",
+        "up: at settlement, stale close, cancellation or expired slot reclaim.",
+        "PDA seeds: [\"date\", scheduled_date]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "scheduledDate",
+            "docs": [
+              "Booked auction date (start of day UTC)."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "nftMint",
+            "docs": [
+              "NFT booked on this date."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Wallet that paid the rent — the holder at list_slot, the settler on",
+              "auto-relist. Refunded when the reservation is closed."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegateVoteOverridden",
+      "docs": [
+        "A holder voted directly with Commoners their delegate had already",
+        "counted. `weight` NFTs moved from the delegate's vote to the holder's,",
+        "and the delegate's allocation was trimmed by yes/no/abstain."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "yes",
+            "type": "u64"
+          },
+          {
+            "name": "no",
+            "type": "u64"
+          },
+          {
+            "name": "abstain",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegation",
+      "docs": [
+        "A holder's standing delegation of their Commoners' votes.",
+        "The delegate can count the holder's NFTs in cast_vote; delegations are",
+        "one hop — a delegate's own delegation does not forward them.",
+        "PDA seeds: [\"delegation\", holder.as_ref()]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp when the delegation was made."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "delegationRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "discountTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minBalance",
+            "docs": [
+              "Minimum COMMON token balance (in raw units) to qualify.",
+              "Zero means this tier slot is unused."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "Fee in basis points for this tier."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "feeRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet receiving this share of the protocol fee."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "docs": [
+              "Share of the protocol fee in basis points.",
+              "Zero means this entry is unused."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "governanceProposal",
+      "docs": [
+        "On-chain governance proposal. Created by the proposal manager after",
+        "off-chain review, or submitted directly by a holder with a deposit.",
+        "PDA seeds: [\"proposal\", proposal_id.to_le_bytes()]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "docs": [
+              "Numeric ID: config.next_proposal_id when created or submitted."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "Original submitter (Commoner NFT holder who proposed it)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "Proposal manager who created or approved this record. Unset for a",
+              "submitted proposal that hasn't been approved or didn't need to be."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "title",
+            "docs": [
+              "Short title (max 100 chars)."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "description",
+            "docs": [
+              "Full description (max 800 chars). Empty for a hosted proposal,",
+              "whose document lives at `content_uri`."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "proposalType",
+            "docs": [
+              "Proposal type slug, e.g. \"community-initiative\" (max 50 chars)."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "treasurySol",
+            "docs": [
+              "Lamports paid from the treasury PDA to `recipient` when executed",
+              "(0 for non-treasury proposals)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp when this record was created on-chain."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "endsAt",
+            "docs": [
+              "Unix timestamp when voting closes, 0 while awaiting approval."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "yes",
+            "docs": [
+              "Accumulated yes votes (NFT-weighted)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "no",
+            "docs": [
+              "Accumulated no votes."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "abstain",
+            "docs": [
+              "Accumulated abstain votes."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "See GovernanceProposal::STATUS_*."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "recipient",
+            "docs": [
+              "Wallet paid `treasury_sol` by execute_proposal."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "executedAt",
+            "docs": [
+              "Unix timestamp when the proposal was executed, 0 until then."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "paramUpdate",
+            "docs": [
+              "Config change applied by execute_proposal, for parameter proposals."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "proposalParamUpdate"
+                }
+              }
+            }
+          },
+          {
+            "name": "depositLamports",
+            "docs": [
+              "Proposer's deposit, held in this account on top of its rent until",
+              "finalize_proposal or veto_proposal settles it. 0 once settled."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "votingDurationSecs",
+            "docs": [
+              "Length of the voting window, started by approve_proposal when the",
+              "proposal waits for approval."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "contentUri",
+            "docs": [
+              "Where a hosted proposal's full document lives (max 200 chars).",
+              "Empty for an inline proposal."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "docs": [
+              "SHA-256 of the document at `content_uri`, for clients to verify it.",
+              "Zero for an inline proposal."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "offer",
+      "docs": [
+        "A buyer's standing SOL offer on a specific NFT, listed or not.",
+        "The offered lamports are held in this account on top of its rent.",
+        "PDA seeds: [\"offer\", nft_mint, buyer]"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "docs": [
+              "NFT mint the offer is for."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "docs": [
+              "Wallet that made the offer (receives the NFT on acceptance)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Offered amount in lamports, escrowed in this account."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp when the offer was made."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerAccepted",
+      "docs": [
+        "`amount` splits into holder_proceeds + fee."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "holderProceeds",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offerMade",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "buyer",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paramUpdate",
+      "docs": [
+        "A set of config parameter changes. `None` leaves a parameter unchanged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "bidIncrementBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "timeBufferSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "minReserveLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "commonTokenMint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "discountTiers",
+            "type": {
+              "option": {
+                "array": [
+                  {
+                    "defined": {
+                      "name": "discountTier"
+                    }
+                  },
+                  4
+                ]
+              }
+            }
+          },
+          {
+            "name": "referralBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "settlerRewardLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "keeperRewardLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "requiredCollection",
+            "docs": [
+              "`Some(None)` clears the collection restriction."
+            ],
+            "type": {
+              "option": {
+                "option": "pubkey"
+              }
+            }
+          },
+          {
+            "name": "paramUpdateDelaySecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "commonerCollection",
+            "docs": [
+              "`Some(None)` clears the Commoner collection, closing voting."
+            ],
+            "type": {
+              "option": {
+                "option": "pubkey"
+              }
+            }
+          },
+          {
+            "name": "quorumBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "approvalThresholdBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "executionDelaySecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "proposalDepositLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "proposalApprovalRequired",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "maxVoteWeight",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "feeRecipients",
+            "docs": [
+              "Used entries must sum to 10_000; all-empty routes fees to the treasury PDA."
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  {
+                    "defined": {
+                      "name": "feeRecipient"
+                    }
+                  },
+                  4
+                ]
+              }
+            }
+          },
+          {
+            "name": "royaltiesEnabled",
+            "type": {
+              "option": "bool"
+            }
+          },
+          {
+            "name": "royaltyCapBps",
+            "docs": [
+              "`Some(None)` honours the metadata's royalty rate uncapped."
+            ],
+            "type": {
+              "option": {
+                "option": "u16"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "paramUpdateCancelled",
+      "docs": [
+        "A queued config change was cancelled before execution."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "paramUpdate"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "paramUpdateQueued",
+      "docs": [
+        "A config change was queued behind the timelock."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "paramUpdate"
+              }
+            }
+          },
+          {
+            "name": "eta",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "paramsUpdated",
+      "docs": [
+        "A config change was applied to ProgramConfig, by execute_param_update",
+        "or by executing a parameter proposal."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "type": {
+              "defined": {
+                "name": "paramUpdate"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseUpdated",
+      "docs": [
+        "Emergency pause flags changed. See ProgramConfig::PAUSE_*."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previous",
+            "type": "u8"
+          },
+          {
+            "name": "paused",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingParamUpdate",
+      "docs": [
+        "A queued config change waiting out the timelock.",
+        "The applied values stay readable on ProgramConfig until execution.",
+        "PDA seeds: [\"pending-params\"] — one pending update at a time."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "update",
+            "docs": [
+              "The changes to apply."
+            ],
+            "type": {
+              "defined": {
+                "name": "paramUpdate"
+              }
+            }
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "Admin that queued the update (receives rent back on close)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "queuedAt",
+            "docs": [
+              "Unix timestamp when the update was queued."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "eta",
+            "docs": [
+              "Earliest unix timestamp at which execute_param_update may apply it."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "programConfig",
+      "docs": [
+        "Global program configuration. One per deployment, stored at a PDA.",
+        "Controlled by admin (Squads multisig in production).",
+        "",
+        "Layout is versioned: `admin` and `version` always lead, so migrate_config",
+        "can authenticate and identify any stored layout from raw bytes.",
+        "Every layout change — appends included — bumps VERSION and registers an",
+        "upgrade from the layout it replaces in migrate_config.",
+        "",
+        "The treasury is not stored: it is the program-owned PDA at",
+        "treasury_address(), which execute_proposal spends from, so it can't be",
+        "repointed. Protocol fees are rerouted through `fee_recipients` instead."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin authority — Squads multisig in production."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version of this account. See ProgramConfig::VERSION."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "defaultFeeBps",
+            "docs": [
+              "Default auction fee in basis points (900 = 9%)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "bidIncrementBps",
+            "docs": [
+              "Minimum bid increment in basis points (500 = 5%).",
+              "New bids must exceed current bid by at least this percentage."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "timeBufferSecs",
+            "docs": [
+              "Anti-sniping time buffer in seconds (600 = 10 min).",
+              "A bid placed within this window extends the auction end time."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "minReserveLamports",
+            "docs": [
+              "Global minimum reserve price in lamports (0.42 SOL = 420_000_000)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "commonTokenMint",
+            "docs": [
+              "COMMON token mint — set once the token launches.",
+              "Used for fee discount tier calculations at settlement."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "discountTiers",
+            "docs": [
+              "Fee discount tiers based on COMMON token balance.",
+              "Each tier: (min_common_balance, fee_bps).",
+              "Populated via governance vote after COMMON token launches."
+            ],
+            "type": {
               "array": [
                 {
                   "defined": {
@@ -1153,386 +7534,863 @@ export type CommonersAuction = {
                 4
               ]
             }
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "requiredCollection",
+            "docs": [
+              "Required NFT collection mint. When set, list_slot verifies the NFT's",
+              "Metaplex metadata and rejects any mint not from this collection.",
+              "Set to the MidEvils collection mint before mainnet launch.",
+              "None on devnet allows testing with arbitrary mints."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "royaltiesEnabled",
+            "docs": [
+              "When true, settle_auction pays verified creators the royalty in the",
+              "NFT's Metaplex metadata out of the winning bid, before the seller."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "royaltyCapBps",
+            "docs": [
+              "Upper bound on the royalty rate honoured at settlement, in basis points.",
+              "None honours the metadata's seller_fee_basis_points as-is."
+            ],
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "referralBps",
+            "docs": [
+              "Share of the protocol fee paid to the winning bid's referrer,",
+              "in basis points of the fee (2000 = 20% of the fee). Zero disables."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "feeRecipients",
+            "docs": [
+              "Protocol fee distribution table: each entry receives `bps` of the fee.",
+              "Entries with bps == 0 are unused. Used entries sum to 10_000.",
+              "An empty table sends the whole fee to the treasury PDA. Forfeited referral",
+              "shares follow the same route as the rest of the fee."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "feeRecipient"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "settlerRewardLamports",
+            "docs": [
+              "Maximum bounty, in lamports, paid to whoever cranks a successful",
+              "settle_auction. Carved out of the treasury's share of the fee.",
+              "At most MAX_SETTLER_REWARD_LAMPORTS."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Admin nominated via propose_admin, awaiting accept_admin."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Emergency pause bitflags (PAUSE_* constants). A set bit halts that",
+              "capability; settlement, stale closes, reclaims and offer cancellation",
+              "are never paused so users can always get their assets out."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "crankAuthority",
+            "docs": [
+              "Hot key used by the daily crank to open auctions (create_auction)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "proposalManager",
+            "docs": [
+              "Key that creates governance proposals."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paramUpdateDelaySecs",
+            "docs": [
+              "Timelock between queue_param_update and execute_param_update, in seconds."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "commonerCollection",
+            "docs": [
+              "Commoner collection mint. cast_vote counts only NFTs verified as",
+              "members of it; voting is closed while unset."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "quorumBps",
+            "docs": [
+              "Share of max_vote_weight, in bps, that must vote (abstain",
+              "included) for a proposal to be valid."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "approvalThresholdBps",
+            "docs": [
+              "Share of yes + no votes, in bps, that yes must exceed to pass."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "executionDelaySecs",
+            "docs": [
+              "Seconds after voting ends before a passed proposal can be executed."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "proposalDepositLamports",
+            "docs": [
+              "Lamports a holder locks in their proposal when calling submit_proposal.",
+              "Refunded if the proposal reaches quorum, otherwise sent to the treasury."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proposalApprovalRequired",
+            "docs": [
+              "When true, submitted proposals wait for approve_proposal before",
+              "voting opens."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "nextProposalId",
+            "docs": [
+              "ID given to the next created or submitted proposal, so neither the",
+              "proposal manager nor holders pick IDs."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxVoteWeight",
+            "docs": [
+              "Total voting weight in existence — the Commoner supply while weight is",
+              "one vote per NFT. Caps a single vote and a proposal's cumulative",
+              "tally, and is the base quorum_bps is measured against."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "keeperRewardLamports",
+            "docs": [
+              "Bounty, in lamports, paid to whoever cranks a stale close or an",
+              "expired slot reclaim, out of the rent of the escrow it closes — the",
+              "rest of that rent still goes back to the seller.",
+              "At most MAX_KEEPER_REWARD_LAMPORTS. Zero disables."
+            ],
+            "type": "u64"
           }
-        }
-      ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "auctionState",
-      "discriminator": [
-        252,
-        227,
-        205,
-        147,
-        72,
-        64,
-        250,
-        126
-      ]
-    },
-    {
-      "name": "programConfig",
-      "discriminator": [
-        196,
-        210,
-        90,
-        231,
-        144,
-        149,
-        140,
-        63
-      ]
-    },
-    {
-      "name": "slotRegistration",
-      "discriminator": [
-        119,
-        114,
-        239,
-        196,
-        78,
-        13,
-        64,
-        243
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "auctionNotStarted",
-      "msg": "Auction has not started yet"
-    },
-    {
-      "code": 6001,
-      "name": "auctionEnded",
-      "msg": "Auction has already ended"
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "auctionNotEnded",
-      "msg": "Auction has not ended yet"
+      "name": "proposalApproved",
+      "docs": [
+        "A submitted proposal was approved and voting opened."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "approvedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "endsAt",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "alreadySettled",
-      "msg": "Auction has already been settled"
+      "name": "proposalContent",
+      "docs": [
+        "How a proposal's body is stored, chosen at creation."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "inline",
+            "fields": [
+              {
+                "name": "description",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "hosted",
+            "fields": [
+              {
+                "name": "uri",
+                "type": "string"
+              },
+              {
+                "name": "contentHash",
+                "type": {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "bidTooLow",
-      "msg": "Bid is below the minimum required amount"
+      "name": "proposalCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposalType",
+            "type": "string"
+          },
+          {
+            "name": "treasurySol",
+            "type": "u64"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "paramUpdate",
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "proposalParamUpdate"
+                }
+              }
+            }
+          },
+          {
+            "name": "endsAt",
+            "docs": [
+              "0 while the proposal awaits approval."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "contentUri",
+            "docs": [
+              "Empty, and the hash zero, for an inline proposal."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "contentHash",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "depositLamports",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "reserveTooLow",
-      "msg": "Reserve price is below the global minimum"
+      "name": "proposalDepositSettled",
+      "docs": [
+        "A proposal's deposit was refunded to the proposer or forfeited to the treasury."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "refunded",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "slotTaken",
-      "msg": "Slot is already taken for this date"
+      "name": "proposalExecuted",
+      "docs": [
+        "A passed proposal was executed, paying `amount` from the treasury PDA."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "recipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "notEscrowed",
-      "msg": "Slot has not been escrowed yet"
+      "name": "proposalFinalized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "type": "u8"
+          },
+          {
+            "name": "yes",
+            "type": "u64"
+          },
+          {
+            "name": "no",
+            "type": "u64"
+          },
+          {
+            "name": "abstain",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "slotConsumed",
-      "msg": "Slot has already been consumed"
+      "name": "proposalParamUpdate",
+      "docs": [
+        "The parameters a governance proposal may change: the auction economics,",
+        "the collection gate and the vote weight cap. Roles, the other governance",
+        "rules, fee routing and the timelock stay with the admin's",
+        "queue_param_update."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "bidIncrementBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "timeBufferSecs",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "minReserveLamports",
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "discountTiers",
+            "type": {
+              "option": {
+                "array": [
+                  {
+                    "defined": {
+                      "name": "discountTier"
+                    }
+                  },
+                  4
+                ]
+              }
+            }
+          },
+          {
+            "name": "requiredCollection",
+            "docs": [
+              "`Some(None)` clears the collection restriction."
+            ],
+            "type": {
+              "option": {
+                "option": "pubkey"
+              }
+            }
+          },
+          {
+            "name": "maxVoteWeight",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "unauthorized",
-      "msg": "Unauthorized — admin only"
+      "name": "proposalRejected",
+      "docs": [
+        "A submitted proposal was rejected before voting opened — by the proposal",
+        "manager, or by anyone once `expired`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "rejectedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "expired",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "overflow",
-      "msg": "Arithmetic overflow"
+      "name": "proposalVetoed",
+      "docs": [
+        "The admin vetoed a proposal that was active or had passed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "previousStatus",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "dateInPast",
-      "msg": "Scheduled date is in the past"
+      "name": "rolesUpdated",
+      "docs": [
+        "Role keys were reassigned by the admin."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "crankAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "proposalManager",
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "mintMismatch",
-      "msg": "NFT mint does not match the registered slot"
+      "name": "slotListed",
+      "docs": [
+        "An NFT was escrowed for a future auction date — by list_slot, or by",
+        "settle_auction relisting an unsold auto-relist NFT."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "type": "pubkey"
+          },
+          {
+            "name": "scheduledDate",
+            "type": "i64"
+          },
+          {
+            "name": "reservePrice",
+            "type": "u64"
+          },
+          {
+            "name": "autoRelist",
+            "type": "bool"
+          },
+          {
+            "name": "reserveDecayBps",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "sellerMismatch",
-      "msg": "Seller does not match the registered slot owner"
-    }
-  ],
-  "types": [
-    {
-      "name": "auctionState",
+      "name": "slotReclaimed",
       "docs": [
-        "Live auction state for a single day's auction."
+        "A slot's date passed without an auction and its NFT was returned."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "nftMint",
-            "docs": [
-              "The NFT mint being auctioned."
-            ],
             "type": "pubkey"
           },
           {
-            "name": "seller",
-            "docs": [
-              "Wallet that listed the NFT (receives proceeds minus fee)."
-            ],
+            "name": "owner",
             "type": "pubkey"
           },
           {
-            "name": "reservePrice",
+            "name": "scheduledDate",
+            "type": "i64"
+          },
+          {
+            "name": "keeperReward",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "slotRegistration",
+      "docs": [
+        "A holder's reservation of a future auction slot.",
+        "Created when a holder locks their NFT into escrow."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nftMint",
             "docs": [
-              "Minimum acceptable final bid in lamports."
+              "NFT mint locked in escrow."
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
-            "name": "startTime",
+            "name": "owner",
             "docs": [
-              "Unix timestamp when bidding opens."
+              "Original owner / seller."
             ],
-            "type": "i64"
+            "type": "pubkey"
           },
           {
-            "name": "endTime",
+            "name": "scheduledDate",
             "docs": [
-              "Unix timestamp when bidding closes.",
-              "Extended by time_buffer_secs on late bids."
+              "Scheduled auction date as a Unix timestamp (start of day UTC)."
             ],
             "type": "i64"
           },
           {
-            "name": "currentBid",
+            "name": "reservePrice",
             "docs": [
-              "Highest bid placed so far in lamports. Zero if no bids."
+              "Reserve price in lamports set by the seller at listing time."
             ],
             "type": "u64"
           },
           {
-            "name": "currentBidder",
+            "name": "escrowed",
             "docs": [
-              "Wallet that placed the current highest bid.",
-              "Refunded automatically when outbid."
+              "Whether the NFT has been transferred into escrow."
             ],
-            "type": {
-              "option": "pubkey"
-            }
+            "type": "bool"
           },
           {
-            "name": "feeBps",
+            "name": "consumed",
             "docs": [
-              "Fee bps applied at settlement (resolved from seller's COMMON balance)."
+              "Whether this slot has been consumed (auction created)."
             ],
-            "type": "u16"
+            "type": "bool"
           },
           {
-            "name": "settled",
-            "docs": [
-              "Whether this auction has been settled."
-            ],
-            "type": "bool"
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "reserveMet",
+            "name": "autoRelist",
             "docs": [
-              "Whether the reserve price was met at settlement."
+              "Seller opted in to automatic relisting: if the auction ends without",
+              "meeting reserve, settlement keeps the NFT in escrow and registers it",
+              "for the next free date instead of returning it. The seller can switch",
+              "it off with set_auto_relist."
             ],
             "type": "bool"
           },
           {
-            "name": "auctionId",
+            "name": "reserveDecayBps",
             "docs": [
-              "Auction sequence number (day index, 1-based)."
+              "Reserve reduction applied on each relist, in basis points of the",
+              "previous reserve (1000 = 10% lower). Floored at the global minimum."
             ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "staleClosed",
+      "docs": [
+        "An unsettled auction was closed as stale and its NFT returned."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "auctionId",
             "type": "u64"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "seller",
+            "type": "pubkey"
+          },
+          {
+            "name": "refundedBidder",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "refund",
+            "type": "u64"
+          },
+          {
+            "name": "keeperReward",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "discountTier",
+      "name": "voteCast",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "minBalance",
-            "docs": [
-              "Minimum COMMON token balance (in raw units) to qualify.",
-              "Zero means this tier slot is unused."
-            ],
+            "name": "proposalId",
             "type": "u64"
           },
           {
-            "name": "feeBps",
-            "docs": [
-              "Fee in basis points for this tier."
-            ],
-            "type": "u16"
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "name": "delegatedWeight",
+            "type": "u64"
+          },
+          {
+            "name": "yes",
+            "type": "u64"
+          },
+          {
+            "name": "no",
+            "type": "u64"
+          },
+          {
+            "name": "abstain",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "programConfig",
+      "name": "voteRecord",
       "docs": [
-        "Global program configuration. One per deployment, stored at a PDA.",
-        "Controlled by admin (Squads multisig in production)."
+        "Per-voter vote record, added to by each cast_vote call of the voter.",
+        "PDA seeds: [\"vote\", proposal_id.to_le_bytes(), voter_pubkey.as_ref()]"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "proposalId",
             "docs": [
-              "Admin authority — Squads multisig in production."
+              "The proposal this vote was cast for."
             ],
-            "type": "pubkey"
+            "type": "u64"
           },
           {
-            "name": "treasury",
+            "name": "voter",
             "docs": [
-              "SubDAO treasury wallet that receives auction fees."
+              "The wallet that cast the vote."
             ],
             "type": "pubkey"
           },
           {
-            "name": "defaultFeeBps",
+            "name": "weight",
             "docs": [
-              "Default auction fee in basis points (900 = 9%)."
+              "Total voting weight (number of Commoner NFTs counted)."
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "bidIncrementBps",
+            "name": "yes",
             "docs": [
-              "Minimum bid increment in basis points (500 = 5%).",
-              "New bids must exceed current bid by at least this percentage."
+              "Votes allocated to yes."
             ],
-            "type": "u16"
+            "type": "u64"
           },
           {
-            "name": "timeBufferSecs",
+            "name": "no",
             "docs": [
-              "Anti-sniping time buffer in seconds (600 = 10 min).",
-              "A bid placed within this window extends the auction end time."
+              "Votes allocated to no."
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "minReserveLamports",
+            "name": "abstain",
             "docs": [
-              "Global minimum reserve price in lamports (0.42 SOL = 420_000_000)."
+              "Votes allocated to abstain."
             ],
             "type": "u64"
           },
           {
-            "name": "commonTokenMint",
-            "docs": [
-              "COMMON token mint — set once the token launches.",
-              "Used for fee discount tier calculations at settlement."
-            ],
-            "type": {
-              "option": "pubkey"
-            }
+            "name": "bump",
+            "type": "u8"
           },
           {
-            "name": "discountTiers",
+            "name": "delegatedWeight",
             "docs": [
-              "Fee discount tiers based on COMMON token balance.",
-              "Each tier: (min_common_balance, fee_bps).",
-              "Populated via governance vote after COMMON token launches."
+              "Part of `weight` counted from NFTs delegated to this voter."
             ],
-            "type": {
-              "array": [
-                {
-                  "defined": {
-                    "name": "discountTier"
-                  }
-                },
-                4
-              ]
-            }
-          },
-          {
-            "name": "bump",
-            "type": "u8"
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "slotRegistration",
-      "docs": [
-        "A holder's reservation of a future auction slot.",
-        "Created when a holder locks their NFT into escrow."
-      ],
+      "name": "voteUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nftMint",
-            "docs": [
-              "NFT mint locked in escrow."
-            ],
-            "type": "pubkey"
+            "name": "proposalId",
+            "type": "u64"
           },
           {
-            "name": "owner",
-            "docs": [
-              "Original owner / seller."
-            ],
+            "name": "voter",
             "type": "pubkey"
           },
           {
-            "name": "scheduledDate",
-            "docs": [
-              "Scheduled auction date as a Unix timestamp (start of day UTC)."
-            ],
-            "type": "i64"
+            "name": "yes",
+            "type": "u64"
           },
           {
-            "name": "reservePrice",
-            "docs": [
-              "Reserve price in lamports set by the seller at listing time."
-            ],
+            "name": "no",
             "type": "u64"
           },
           {
-            "name": "escrowed",
-            "docs": [
-              "Whether the NFT has been transferred into escrow."
-            ],
-            "type": "bool"
+            "name": "abstain",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voteWithdrawn",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
           },
           {
-            "name": "consumed",
-            "docs": [
-              "Whether this slot has been consumed (auction created)."
-            ],
-            "type": "bool"
+            "name": "voter",
+            "type": "pubkey"
           },
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "weight",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "votesDelegated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "type": "pubkey"
           }
         ]
       }
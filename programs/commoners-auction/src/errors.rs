@@ -107,6 +107,186 @@ pub enum AuctionError {
     #[msg("Proposal duration must be between 1 hour and 7 days")]
     InvalidDuration,
 
-    #[msg("Vote tally would exceed the 120-NFT maximum for this collection")]
+    #[msg("Vote weight or tally would exceed the configured max vote weight")]
     TallyOverflow,
+
+    // ── Auto-relist ──────────────────────────────────────────────────────────
+    #[msg("Reserve decay basis points exceed 10000 (100%)")]
+    InvalidReserveDecay,
+
+    #[msg("Slot is set to auto-relist — relist accounts are required")]
+    RelistDateRequired,
+
+    #[msg("Relist slot or date reservation does not match the PDA for relist_date")]
+    RelistSlotMismatch,
+
+    #[msg("Only the seller can choose the relist date")]
+    RelistDateNotAllowed,
+
+    #[msg("Account is not a SlotRegistration owned by this program")]
+    InvalidSlotAccount,
+
+    // ── Date reservations ────────────────────────────────────────────────────
+    #[msg("Booked date accounts must be the reservations of consecutive dates from tomorrow")]
+    BookedDateMismatch,
+
+    #[msg("No free date within the relist lookahead — the seller must pick relist_date")]
+    NoFreeRelistDate,
+
+    #[msg("Date reservation payer does not match the one it records")]
+    ReservationPayerMismatch,
+
+    // ── Offers ───────────────────────────────────────────────────────────────
+    #[msg("Offer is below the global minimum reserve")]
+    OfferTooLow,
+
+    #[msg("Offer amount changed since it was quoted")]
+    OfferAmountMismatch,
+
+    // ── Royalties ────────────────────────────────────────────────────────────
+    #[msg("NFT metadata account is missing or malformed")]
+    InvalidMetadata,
+
+    #[msg("Creator accounts do not match the verified creators in the NFT metadata")]
+    CreatorMismatch,
+
+    #[msg("Royalty cap basis points exceed 10000 (100%)")]
+    RoyaltyCapTooHigh,
+
+    // ── Referrals ────────────────────────────────────────────────────────────
+    #[msg("Referrer account does not match the winning bid's referrer")]
+    ReferrerMismatch,
+
+    #[msg("Bidders cannot refer their own bid")]
+    SelfReferral,
+
+    // ── Fee distribution ─────────────────────────────────────────────────────
+    #[msg("Fee recipient shares must sum to 10000 basis points")]
+    InvalidFeeSplit,
+
+    #[msg("Fee recipient accounts do not match the fee distribution table")]
+    FeeRecipientMismatch,
+
+    #[msg("Fee recipients must be distinct, non-default addresses")]
+    InvalidFeeRecipient,
+
+    // ── Expired slots ────────────────────────────────────────────────────────
+    #[msg("Slot date must be at least 3 days past before it can be reclaimed")]
+    SlotNotExpired,
+
+    // ── Settler and keeper rewards ───────────────────────────────────────────
+    #[msg("Settler reward exceeds the maximum")]
+    SettlerRewardTooHigh,
+
+    #[msg("Keeper reward exceeds the maximum")]
+    KeeperRewardTooHigh,
+
+    // ── Config migration ─────────────────────────────────────────────────────
+    #[msg("Account is not an initialized ProgramConfig")]
+    InvalidConfigAccount,
+
+    #[msg("ProgramConfig version is not one this program can migrate")]
+    UnsupportedConfigVersion,
+
+    // ── Emergency pause ──────────────────────────────────────────────────────
+    #[msg("This capability is paused by the admin")]
+    ProgramPaused,
+
+    #[msg("Unknown pause flag bits")]
+    InvalidPauseFlags,
+
+    // ── On-chain vote weight ─────────────────────────────────────────────────
+    #[msg("Commoner collection is not configured")]
+    CommonerCollectionNotSet,
+
+    #[msg("Vote accounts must be (token account, metadata) pairs")]
+    InvalidVoteAccounts,
+
+    #[msg("Token account must be owned by the voter and hold exactly one NFT")]
+    InvalidCommonerTokenAccount,
+
+    #[msg("The same NFT was passed more than once")]
+    DuplicateNft,
+
+    #[msg("This NFT has already voted on this proposal")]
+    NftAlreadyVoted,
+
+    #[msg("NFT vote marker address does not match the proposal and mint")]
+    VoteMarkerMismatch,
+
+    // ── Delegation ───────────────────────────────────────────────────────────
+    #[msg("Cannot delegate votes to yourself")]
+    SelfDelegation,
+
+    #[msg("Delegation account is invalid or not delegated to this voter")]
+    InvalidDelegation,
+
+    #[msg("Vote record does not belong to the delegate being overridden")]
+    OverriddenVoteRecordMismatch,
+
+    // ── Proposal rules ───────────────────────────────────────────────────────
+    #[msg("Quorum must be at most 10000 bps and approval threshold below 10000 bps")]
+    InvalidGovernanceThreshold,
+
+    #[msg("Proposal can no longer be vetoed")]
+    ProposalNotVetoable,
+
+    // ── Proposal execution ───────────────────────────────────────────────────
+    #[msg("Only passed proposals can be executed")]
+    ProposalNotPassed,
+
+    #[msg("Execution delay has not elapsed since voting ended")]
+    ExecutionDelayNotElapsed,
+
+    #[msg("Treasury balance is too low to pay this proposal")]
+    InsufficientTreasury,
+
+    // ── Auction lifecycle ────────────────────────────────────────────────────
+    #[msg("Auction status does not allow this transition")]
+    InvalidStatusTransition,
+
+    #[msg("Auction already has bids")]
+    AuctionHasBids,
+
+    #[msg("Bid vault still holds lamports")]
+    BidVaultNotEmpty,
+
+    #[msg("Account is not an AuctionState owned by this program")]
+    InvalidAuctionAccount,
+
+    // ── Timelocked parameters ────────────────────────────────────────────────
+    #[msg("Delay must be at most 30 days, and the param update delay at least 1 second")]
+    InvalidParamUpdateDelay,
+
+    #[msg("Queued param update has not reached its eta")]
+    ParamUpdateNotReady,
+
+    // ── Proposal submission ──────────────────────────────────────────────────
+    #[msg("Proposal is not awaiting approval")]
+    ProposalNotPending,
+
+    #[msg("Only the proposal manager can reject a proposal before its approval window ends")]
+    ApprovalWindowOpen,
+
+    // ── Vote weight ──────────────────────────────────────────────────────────
+    #[msg("Max vote weight must be greater than zero")]
+    InvalidMaxVoteWeight,
+
+    // ── Proposal content ─────────────────────────────────────────────────────
+    #[msg("Hosted proposal needs a URI of at most 200 chars and a non-zero content hash")]
+    InvalidProposalContent,
+
+    #[msg("Account is not a GovernanceProposal")]
+    InvalidProposalAccount,
+
+    // ── Bid logs ─────────────────────────────────────────────────────────────
+    #[msg("Account at the bid log address is not a BidLog")]
+    InvalidBidLogAccount,
+
+    #[msg("Bid log payer does not match the one it records")]
+    BidLogPayerMismatch,
+
+    // ── Bid refunds ──────────────────────────────────────────────────────────
+    #[msg("Bidder account does not match the auction's standing bidder")]
+    BidderMismatch,
 }
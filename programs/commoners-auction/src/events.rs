@@ -0,0 +1,312 @@
+use anchor_lang::prelude::*;
+use crate::state::{ParamUpdate, ProposalParamUpdate};
+
+// ── Slots & auctions ─────────────────────────────────────────────────────────
+
+/// An NFT was escrowed for a future auction date — by list_slot, or by
+/// settle_auction relisting an unsold auto-relist NFT.
+#[event]
+pub struct SlotListed {
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    pub scheduled_date: i64,
+    pub reserve_price: u64,
+    pub auto_relist: bool,
+    pub reserve_decay_bps: u16,
+}
+
+/// A seller switched automatic relisting on or off for a slot.
+#[event]
+pub struct AutoRelistUpdated {
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    pub scheduled_date: i64,
+    pub auto_relist: bool,
+}
+
+/// A slot's date passed without an auction and its NFT was returned.
+#[event]
+pub struct SlotReclaimed {
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    pub scheduled_date: i64,
+    pub keeper_reward: u64,
+}
+
+#[event]
+pub struct AuctionCreated {
+    pub auction_id: u64,
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub reserve_price: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub fee_bps: u16,
+}
+
+/// A new high bid. `refunded_bidder` got `refund_amount` lamports back.
+#[event]
+pub struct BidPlaced {
+    pub auction_id: u64,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub referrer: Option<Pubkey>,
+    pub refunded_bidder: Option<Pubkey>,
+    pub refund_amount: u64,
+}
+
+/// Anti-snipe extension triggered by a late bid.
+#[event]
+pub struct AuctionExtended {
+    pub auction_id: u64,
+    pub previous_end_time: i64,
+    pub end_time: i64,
+}
+
+/// An auction was settled. When sold, `price` splits exactly into
+/// seller_proceeds + protocol_fee + referral + royalties + settler_reward.
+/// When unsold, every amount is zero and `relist_date` is set if the NFT was
+/// relisted rather than returned.
+#[event]
+pub struct AuctionSettled {
+    pub auction_id: u64,
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub winner: Option<Pubkey>,
+    pub price: u64,
+    pub seller_proceeds: u64,
+    pub protocol_fee: u64,
+    pub referral: u64,
+    pub royalties: u64,
+    pub settler_reward: u64,
+    pub relist_date: Option<i64>,
+}
+
+/// The admin cancelled an auction before any bid; its NFT was returned.
+#[event]
+pub struct AuctionCancelled {
+    pub auction_id: u64,
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+}
+
+/// An unsettled auction was closed as stale and its NFT returned.
+#[event]
+pub struct StaleClosed {
+    pub auction_id: u64,
+    pub nft_mint: Pubkey,
+    pub seller: Pubkey,
+    pub refunded_bidder: Option<Pubkey>,
+    pub refund: u64,
+    pub keeper_reward: u64,
+}
+
+/// A finished auction and its bid log were closed; `refund` is a standing bid
+/// the unsold auction still owed its bidder, `swept` what was left in its bid
+/// vault after that and went to the crank.
+#[event]
+pub struct AuctionClosed {
+    pub auction_id: u64,
+    pub total_bids: u64,
+    pub refunded_bidder: Option<Pubkey>,
+    pub refund: u64,
+    pub swept: u64,
+}
+
+// ── Offers ───────────────────────────────────────────────────────────────────
+
+#[event]
+pub struct OfferMade {
+    pub nft_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct OfferCancelled {
+    pub nft_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+}
+
+/// `amount` splits into holder_proceeds + fee.
+#[event]
+pub struct OfferAccepted {
+    pub nft_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub holder_proceeds: u64,
+    pub fee: u64,
+}
+
+// ── Governance ───────────────────────────────────────────────────────────────
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub proposal_type: String,
+    pub treasury_sol: u64,
+    pub recipient: Pubkey,
+    pub param_update: Option<ProposalParamUpdate>,
+    /// 0 while the proposal awaits approval.
+    pub ends_at: i64,
+    /// Empty, and the hash zero, for an inline proposal.
+    pub content_uri: String,
+    pub content_hash: [u8; 32],
+    pub deposit_lamports: u64,
+}
+
+/// A submitted proposal was approved and voting opened.
+#[event]
+pub struct ProposalApproved {
+    pub proposal_id: u64,
+    pub approved_by: Pubkey,
+    pub ends_at: i64,
+}
+
+/// A submitted proposal was rejected before voting opened — by the proposal
+/// manager, or by anyone once `expired`.
+#[event]
+pub struct ProposalRejected {
+    pub proposal_id: u64,
+    pub rejected_by: Pubkey,
+    pub expired: bool,
+}
+
+/// A proposal's deposit was refunded to the proposer or forfeited to the treasury.
+#[event]
+pub struct ProposalDepositSettled {
+    pub proposal_id: u64,
+    pub amount: u64,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub delegated_weight: u64,
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+#[event]
+pub struct VoteUpdated {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+#[event]
+pub struct VoteWithdrawn {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub weight: u64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub status: u8,
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+#[event]
+pub struct VotesDelegated {
+    pub holder: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub holder: Pubkey,
+    pub delegate: Pubkey,
+}
+
+/// A holder voted directly with Commoners their delegate had already
+/// counted. `weight` NFTs moved from the delegate's vote to the holder's,
+/// and the delegate's allocation was trimmed by yes/no/abstain.
+#[event]
+pub struct DelegateVoteOverridden {
+    pub proposal_id: u64,
+    pub holder: Pubkey,
+    pub delegate: Pubkey,
+    pub weight: u64,
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+/// The admin vetoed a proposal that was active or had passed.
+#[event]
+pub struct ProposalVetoed {
+    pub proposal_id: u64,
+    pub previous_status: u8,
+}
+
+/// A passed proposal was executed, paying `amount` from the treasury PDA.
+#[event]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+// ── Config ───────────────────────────────────────────────────────────────────
+
+/// Admin handoff started (or cancelled, when `pending_admin` is None).
+/// The pending admin must call accept_admin to complete it.
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+/// Admin handoff completed by the pending admin.
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// Emergency pause flags changed. See ProgramConfig::PAUSE_*.
+#[event]
+pub struct PauseUpdated {
+    pub previous: u8,
+    pub paused: u8,
+}
+
+/// A config change was queued behind the timelock.
+#[event]
+pub struct ParamUpdateQueued {
+    pub update: ParamUpdate,
+    pub eta: i64,
+}
+
+/// A queued config change was cancelled before execution.
+#[event]
+pub struct ParamUpdateCancelled {
+    pub update: ParamUpdate,
+}
+
+/// A config change was applied to ProgramConfig, by execute_param_update
+/// or by executing a parameter proposal.
+#[event]
+pub struct ParamsUpdated {
+    pub update: ParamUpdate,
+}
+
+/// Role keys were reassigned by the admin.
+#[event]
+pub struct RolesUpdated {
+    pub crank_authority: Pubkey,
+    pub proposal_manager: Pubkey,
+}
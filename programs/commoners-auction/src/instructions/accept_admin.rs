@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::ProgramConfig;
+use crate::errors::AuctionError;
+use crate::events::AdminAccepted;
+
+/// Step two of the admin handoff: the nominated admin signs to take over.
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = config.admin;
+    config.admin = ctx.accounts.new_admin.key();
+    config.pending_admin = None;
+
+    emit!(AdminAccepted {
+        previous_admin,
+        new_admin: config.admin,
+    });
+    msg!("Admin handed over from {} to {}", previous_admin, config.admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The wallet nominated by propose_admin.
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = config.pending_admin == Some(new_admin.key()) @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::state::{ProgramConfig, Offer};
+use crate::errors::AuctionError;
+use crate::events::OfferAccepted;
+
+/// Called by the NFT holder to accept a standing offer.
+///
+/// - NFT → buyer
+/// - Offer amount → holder (minus fee), fee → treasury or fee recipients
+/// - Offer PDA closed, rent back to the buyer who paid it
+///
+/// The fee is resolved exactly as for auctions via `resolve_fee_bps`, and
+/// split the same way: pass one writable remaining account per used entry of
+/// config.fee_recipients, in table order.
+pub fn accept_offer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AcceptOffer<'info>>,
+    expected_amount: u64,
+) -> Result<()> {
+    let amount = ctx.accounts.offer.amount;
+    require!(amount == expected_amount, AuctionError::OfferAmountMismatch);
+
+    // Transfer NFT from holder to buyer.
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.holder_token_account.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, 1)?;
+
+    // Resolve the holder's fee based on their COMMON token balance.
+    // common_balance is 0 until the COMMON token launches.
+    let common_balance: u64 = 0; // TODO: CPI to SPL token when COMMON mint is set
+    let fee_bps = ctx.accounts.config.resolve_fee_bps(common_balance);
+
+    // --- Split offer: holder gets (amount - fee), treasury gets fee ---
+    let fee = amount
+        .checked_mul(fee_bps as u64)
+        .ok_or(AuctionError::Overflow)?
+        / 10_000;
+    let holder_proceeds = amount.checked_sub(fee).ok_or(AuctionError::Overflow)?;
+
+    // The offer PDA is program-owned, so lamports move directly.
+    // Its remaining rent goes back to the buyer via `close = buyer`.
+    let offer_info = ctx.accounts.offer.to_account_info();
+    offer_info.sub_lamports(amount)?;
+    ctx.accounts.holder.add_lamports(holder_proceeds)?;
+    if fee > 0 {
+        let config = &ctx.accounts.config;
+        let fee_accounts = ctx.remaining_accounts;
+        require!(
+            fee_accounts.len() == config.fee_recipient_count(),
+            AuctionError::FeeRecipientMismatch
+        );
+        if fee_accounts.is_empty() {
+            ctx.accounts.treasury.add_lamports(fee)?;
+        } else {
+            for ((recipient, amount), account) in config.split_fee(fee).into_iter().zip(fee_accounts) {
+                require_keys_eq!(account.key(), recipient, AuctionError::FeeRecipientMismatch);
+                account.add_lamports(amount)?;
+            }
+        }
+    }
+
+    emit!(OfferAccepted {
+        nft_mint: ctx.accounts.offer.nft_mint,
+        buyer: ctx.accounts.offer.buyer,
+        holder: ctx.accounts.holder.key(),
+        amount,
+        holder_proceeds,
+        fee,
+    });
+    msg!(
+        "Offer accepted: mint={} → {}, {} lamports → holder, {} lamports → fee recipients",
+        ctx.accounts.offer.nft_mint,
+        ctx.accounts.offer.buyer,
+        holder_proceeds,
+        fee,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptOffer<'info> {
+    /// Current NFT holder. Pays tx fee + buyer ATA rent if needed.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_OFFERS) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [Offer::SEED, nft_mint.key().as_ref(), buyer.key().as_ref()],
+        bump = offer.bump,
+        has_one = buyer,
+        constraint = offer.nft_mint == nft_mint.key() @ AuctionError::MintMismatch,
+        close = buyer,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Holder's token account for this NFT.
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Buyer's token account — receives the NFT.
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = nft_mint,
+        associated_token::authority = buyer,
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: buyer identity — validated by offer has_one constraint.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Treasury PDA — receives the fee when no fee recipients are set.
+    /// CHECK: validated by the PDA seeds.
+    #[account(
+        mut,
+        seeds = [ProgramConfig::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, GovernanceProposal};
+use crate::errors::AuctionError;
+use crate::events::ProposalApproved;
+
+/// Called by the proposal manager to open voting on a submitted proposal
+/// when config.proposal_approval_required is set. The voting window starts
+/// now and lasts the duration chosen at submission. reject_proposal turns a
+/// proposal down and refunds its deposit; an admin veto forfeits it.
+pub fn approve_proposal(ctx: Context<ApproveProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == GovernanceProposal::STATUS_PENDING_APPROVAL,
+        AuctionError::ProposalNotPending
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let ends_at = now
+        .checked_add(proposal.voting_duration_secs)
+        .ok_or(AuctionError::Overflow)?;
+    proposal.admin = ctx.accounts.proposal_manager.key();
+    proposal.ends_at = ends_at;
+    proposal.status = GovernanceProposal::STATUS_ACTIVE;
+
+    emit!(ProposalApproved {
+        proposal_id: proposal.proposal_id,
+        approved_by: proposal.admin,
+        ends_at,
+    });
+    msg!(
+        "Proposal {} approved. Ends: {}",
+        proposal.proposal_id,
+        ends_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveProposal<'info> {
+    /// Must be config.proposal_manager.
+    pub proposal_manager: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+        has_one = proposal_manager @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use crate::state::{ProgramConfig, AuctionState, AuctionStatus, DateReservation, SlotRegistration};
+use crate::errors::AuctionError;
+use crate::events::AuctionCancelled;
+
+/// Admin cancels an auction that has not received a bid yet, e.g. one
+/// opened for the wrong NFT. The NFT goes back to the seller, who also gets
+/// the escrow rent they paid at list_slot. The date's reservation is closed
+/// to whoever paid for it.
+pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(auction.current_bidder.is_none(), AuctionError::AuctionHasBids);
+    auction.transition(AuctionStatus::Cancelled, now)?;
+
+    // Return NFT to seller via slot PDA as escrow authority.
+    let nft_mint_key = ctx.accounts.nft_mint.key();
+    let scheduled_date_bytes = ctx.accounts.slot.scheduled_date.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SlotRegistration::SEED,
+        nft_mint_key.as_ref(),
+        &scheduled_date_bytes,
+        &[ctx.accounts.slot.bump],
+    ]];
+
+    let nft_return_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.seller_token_account.to_account_info(),
+            authority: ctx.accounts.slot.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(nft_return_ctx, 1)?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_token_account.to_account_info(),
+            destination: ctx.accounts.seller.to_account_info(),
+            authority: ctx.accounts.slot.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    DateReservation::release(
+        &ctx.accounts.date_reservation.to_account_info(),
+        &ctx.accounts.slot.nft_mint,
+        &ctx.accounts.date_reservation_payer.to_account_info(),
+    )?;
+
+    emit!(AuctionCancelled {
+        auction_id: auction.auction_id,
+        nft_mint: auction.nft_mint,
+        seller: auction.seller,
+    });
+    msg!(
+        "Auction #{} cancelled — NFT returned to seller {}",
+        auction.auction_id,
+        auction.seller,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    /// Pays ATA rent for the seller if needed.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [AuctionState::SEED, &auction.auction_id.to_le_bytes()],
+        bump = auction.bump,
+        has_one = seller,
+        constraint = auction.nft_mint == nft_mint.key() @ AuctionError::MintMismatch,
+    )]
+    pub auction: Account<'info, AuctionState>,
+
+    #[account(
+        seeds = [SlotRegistration::SEED, nft_mint.key().as_ref(), &slot.scheduled_date.to_le_bytes()],
+        bump = slot.bump,
+        constraint = slot.owner == auction.seller @ AuctionError::SellerMismatch,
+    )]
+    pub slot: Account<'info, SlotRegistration>,
+
+    /// The slot's date reservation, if it has one — slots listed before
+    /// reservations existed don't.
+    /// CHECK: address checked by the seeds, contents by DateReservation::release.
+    #[account(
+        mut,
+        seeds = [DateReservation::SEED, &slot.scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub date_reservation: UncheckedAccount<'info>,
+
+    /// CHECK: receives the date reservation's rent; must be the payer it records.
+    #[account(mut)]
+    pub date_reservation_payer: UncheckedAccount<'info>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Escrow token account holding the NFT (owned by slot PDA).
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = slot,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Seller's token account — receives the NFT back.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = nft_mint,
+        associated_token::authority = seller,
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: validated by auction has_one = seller.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::Offer;
+use crate::events::OfferCancelled;
+
+/// Called by the buyer to withdraw an open offer.
+/// Closing the offer PDA returns the escrowed lamports and the rent.
+pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    emit!(OfferCancelled {
+        nft_mint: offer.nft_mint,
+        buyer: offer.buyer,
+        amount: offer.amount,
+    });
+    msg!(
+        "Offer cancelled: {} lamports returned to {} for mint {}",
+        offer.amount,
+        offer.buyer,
+        offer.nft_mint,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOffer<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Offer::SEED, offer.nft_mint.as_ref(), buyer.key().as_ref()],
+        bump = offer.bump,
+        has_one = buyer,
+        close = buyer,
+    )]
+    pub offer: Account<'info, Offer>,
+}
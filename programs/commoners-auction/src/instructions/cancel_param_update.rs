@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, PendingParamUpdate};
+use crate::errors::AuctionError;
+use crate::events::ParamUpdateCancelled;
+
+/// Admin drops a queued config change before it is executed.
+/// Rent from the pending account returns to the admin that queued it.
+pub fn cancel_param_update(ctx: Context<CancelParamUpdate>) -> Result<()> {
+    emit!(ParamUpdateCancelled {
+        update: ctx.accounts.pending.update.clone(),
+    });
+    msg!("Queued param update cancelled");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelParamUpdate<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [PendingParamUpdate::SEED],
+        bump = pending.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending: Account<'info, PendingParamUpdate>,
+
+    /// CHECK: receives the pending account's rent; validated by has_one.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
@@ -1,28 +1,78 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProgramConfig, GovernanceProposal, VoteRecord};
+use anchor_spl::token::{self, TokenAccount};
+use crate::state::{ProgramConfig, Delegation, GovernanceProposal, NftVoteMarker, VoteRecord};
 use crate::errors::AuctionError;
+use crate::metadata::verify_collection_membership;
+use crate::pda::create_pda_account;
+use crate::events::{DelegateVoteOverridden, VoteCast};
 
 /// Called by a Commoner NFT holder to cast their vote on an active proposal.
 ///
-/// Requires TWO signers:
-///   - `voter`  — the Commoner holder's wallet (proves on-chain identity)
-///   - `admin`  — the program admin (proves NFT holdings were verified off-chain)
+/// Holdings are proven on-chain: remaining accounts are (token account,
+/// metadata, vote marker) triples, one per Commoner. Each token account must
+/// be owned by the voter and hold exactly one token of a mint whose metadata
+/// is a verified member of config.commoner_collection.
 ///
-/// The VoteRecord PDA is keyed by (proposal_id, voter) so a second call
-/// with the same voter will fail at account init — preventing double-votes.
+/// The VoteRecord PDA is keyed by (proposal_id, voter). Each counted NFT
+/// gets an NftVoteMarker keyed by (proposal_id, mint), so no NFT votes twice,
+/// whether from the same wallet or after moving to another one.
 ///
-/// `weight` is the voter's Commoner NFT count, verified by the admin before
-/// co-signing. Allocations (yes + no + abstain) must not exceed weight.
-pub fn cast_vote(
-    ctx: Context<CastVote>,
-    _proposal_id: u64,
-    weight: u64,
+/// Calling again adds more Commoners to the voter's record: the call's
+/// weight and allocation are added to what the record already holds. This
+/// lets a voter count more NFTs than fit in one transaction.
+///
+/// Delegates vote with their holders' NFTs too: the first
+/// `delegation_count` remaining accounts are Delegation PDAs naming the voter,
+/// and triples may then include token accounts owned by those holders. A
+/// delegate spreading those NFTs over several calls passes, in each call, the
+/// Delegations of the holders whose NFTs that call counts.
+///
+/// A holder's own vote overrides their delegate's, whichever comes first.
+/// If the holder votes first, the markers keep the delegate from counting
+/// those NFTs. If the delegate voted first, the holder takes the markers
+/// over: the next `overridden_count` remaining accounts are the VoteRecords
+/// of the delegates that counted them, whose weight and allocation shrink
+/// by those NFTs before the holder's vote is added.
+///
+/// `weight` is the number of Commoners proven this way.
+/// A call's allocation (yes + no + abstain) must not exceed its weight.
+pub fn cast_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+    proposal_id: u64,
     yes: u64,
     no: u64,
     abstain: u64,
+    delegation_count: u8,
+    overridden_count: u8,
 ) -> Result<()> {
+    let collection = ctx
+        .accounts
+        .config
+        .commoner_collection
+        .ok_or(AuctionError::CommonerCollectionNotSet)?;
+    let voter = ctx.accounts.voter.key();
+
+    let prefix_len = delegation_count as usize + overridden_count as usize;
+    require!(
+        ctx.remaining_accounts.len() >= prefix_len,
+        AuctionError::InvalidVoteAccounts
+    );
+    let (delegation_accounts, rest) = ctx.remaining_accounts.split_at(delegation_count as usize);
+    let (overridden_records, nft_accounts) = rest.split_at(overridden_count as usize);
+    let holders = verify_delegations(delegation_accounts, &voter)?;
+
+    let holdings = verify_commoner_holdings(nft_accounts, &voter, &holders, collection)?;
+    let weight = holdings.len() as u64;
+    let delegated_weight = holdings.iter().filter(|(_, _, owner)| *owner != voter).count() as u64;
     require!(weight > 0, AuctionError::NoVotesAllocated);
-    require!(weight <= 120, AuctionError::TallyOverflow);
+    let max_vote_weight = ctx.accounts.config.max_vote_weight;
+    let record_weight = ctx
+        .accounts
+        .vote_record
+        .weight
+        .checked_add(weight)
+        .ok_or(AuctionError::Overflow)?;
+    require!(record_weight <= max_vote_weight, AuctionError::TallyOverflow);
 
     let total = yes
         .checked_add(no)
@@ -33,19 +83,49 @@ pub fn cast_vote(
     require!(total > 0, AuctionError::NoVotesAllocated);
     require!(total <= weight, AuctionError::AllocationExceedsWeight);
 
-    let proposal = &mut ctx.accounts.proposal;
     let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.proposal.status == GovernanceProposal::STATUS_ACTIVE,
+        AuctionError::ProposalNotActive
+    );
+    require!(now < ctx.accounts.proposal.ends_at, AuctionError::VotingEnded);
 
-    require!(proposal.status == 0, AuctionError::ProposalNotActive);
-    require!(now < proposal.ends_at, AuctionError::VotingEnded);
+    // Delegates whose votes counted some of these NFTs, with how many.
+    let mut overridden: Vec<(Pubkey, u64)> = Vec::new();
+    for (mint, marker, owner) in holdings {
+        match take_over_marker(marker, proposal_id, &mint, &voter, &owner)? {
+            Some(delegate) => match overridden.iter_mut().find(|(d, _)| *d == delegate) {
+                Some((_, count)) => *count += 1,
+                None => overridden.push((delegate, 1)),
+            },
+            None => mark_nft_voted(&ctx, marker, proposal_id, mint, owner)?,
+        }
+    }
+    require!(
+        overridden.len() == overridden_records.len(),
+        AuctionError::OverriddenVoteRecordMismatch
+    );
+    for (delegate, count) in overridden {
+        let (record_key, _) = Pubkey::find_program_address(
+            &[VoteRecord::SEED, &proposal_id.to_le_bytes(), delegate.as_ref()],
+            ctx.program_id,
+        );
+        let record_info = overridden_records
+            .iter()
+            .find(|info| info.key() == record_key)
+            .ok_or(AuctionError::OverriddenVoteRecordMismatch)?;
+        override_delegate_vote(&mut ctx.accounts.proposal, record_info, &voter, delegate, count)?;
+    }
 
-    // Ensure global tally cannot exceed the 120-NFT collection ceiling.
+    let proposal = &mut ctx.accounts.proposal;
+
+    // Ensure global tally cannot exceed the max vote weight.
     let accumulated = proposal.yes
         .checked_add(proposal.no)
         .and_then(|s| s.checked_add(proposal.abstain))
         .ok_or(AuctionError::Overflow)?;
     require!(
-        accumulated.checked_add(total).ok_or(AuctionError::Overflow)? <= 120,
+        accumulated.checked_add(total).ok_or(AuctionError::Overflow)? <= max_vote_weight,
         AuctionError::TallyOverflow
     );
 
@@ -54,16 +134,29 @@ pub fn cast_vote(
     proposal.no = proposal.no.checked_add(no).ok_or(AuctionError::Overflow)?;
     proposal.abstain = proposal.abstain.checked_add(abstain).ok_or(AuctionError::Overflow)?;
 
-    // Write vote record (init fails if this voter already voted).
+    // Write the vote record, adding to it if this voter already voted.
     let record = &mut ctx.accounts.vote_record;
     record.proposal_id = proposal.proposal_id;
     record.voter = ctx.accounts.voter.key();
-    record.weight = weight;
-    record.yes = yes;
-    record.no = no;
-    record.abstain = abstain;
     record.bump = ctx.bumps.vote_record;
+    record.weight = record_weight;
+    record.yes = record.yes.checked_add(yes).ok_or(AuctionError::Overflow)?;
+    record.no = record.no.checked_add(no).ok_or(AuctionError::Overflow)?;
+    record.abstain = record.abstain.checked_add(abstain).ok_or(AuctionError::Overflow)?;
+    record.delegated_weight = record
+        .delegated_weight
+        .checked_add(delegated_weight)
+        .ok_or(AuctionError::Overflow)?;
 
+    emit!(VoteCast {
+        proposal_id: proposal.proposal_id,
+        voter: record.voter,
+        weight,
+        delegated_weight,
+        yes,
+        no,
+        abstain,
+    });
     msg!(
         "Vote recorded: voter={} yes={} no={} abstain={}",
         ctx.accounts.voter.key(),
@@ -74,6 +167,176 @@ pub fn cast_vote(
     Ok(())
 }
 
+/// Check that each account is a Delegation to `voter` and return the
+/// delegating holders.
+fn verify_delegations(accounts: &[AccountInfo], voter: &Pubkey) -> Result<Vec<Pubkey>> {
+    let mut holders: Vec<Pubkey> = Vec::with_capacity(accounts.len());
+    for info in accounts {
+        require_keys_eq!(*info.owner, crate::ID, AuctionError::InvalidDelegation);
+        let delegation = Delegation::try_deserialize(&mut &info.try_borrow_data()?[..])
+            .map_err(|_| error!(AuctionError::InvalidDelegation))?;
+        require_keys_eq!(delegation.delegate, *voter, AuctionError::InvalidDelegation);
+        if !holders.contains(&delegation.holder) {
+            holders.push(delegation.holder);
+        }
+    }
+    Ok(holders)
+}
+
+/// Check each (token account, metadata, marker) triple in `accounts` and
+/// return the distinct Commoner mints held by `voter` or a delegating holder,
+/// with their markers and owners.
+fn verify_commoner_holdings<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    voter: &Pubkey,
+    holders: &[Pubkey],
+    collection: Pubkey,
+) -> Result<Vec<(Pubkey, &'a AccountInfo<'info>, Pubkey)>> {
+    require!(accounts.len() % 3 == 0, AuctionError::InvalidVoteAccounts);
+
+    let mut holdings: Vec<(Pubkey, &AccountInfo, Pubkey)> = Vec::with_capacity(accounts.len() / 3);
+    for triple in accounts.chunks_exact(3) {
+        let (token_info, metadata_info, marker_info) = (&triple[0], &triple[1], &triple[2]);
+
+        require_keys_eq!(*token_info.owner, token::ID, AuctionError::InvalidCommonerTokenAccount);
+        let token_account = TokenAccount::try_deserialize(&mut &token_info.try_borrow_data()?[..])
+            .map_err(|_| error!(AuctionError::InvalidCommonerTokenAccount))?;
+        require!(
+            (token_account.owner == *voter || holders.contains(&token_account.owner))
+                && token_account.amount == 1,
+            AuctionError::InvalidCommonerTokenAccount
+        );
+
+        verify_collection_membership(metadata_info, &token_account.mint, collection)?;
+
+        require!(
+            holdings.iter().all(|(mint, _, _)| *mint != token_account.mint),
+            AuctionError::DuplicateNft
+        );
+        holdings.push((token_account.mint, marker_info, token_account.owner));
+    }
+    Ok(holdings)
+}
+
+/// If `marker` already exists, let `voter` take it over and return the
+/// delegate it is taken from. Only the wallet that held the NFT when its
+/// delegate counted it, and still holds it, may do so; any other existing
+/// marker means the NFT has voted. Returns None for a marker not yet created.
+/// `marker` must be the NFT's marker PDA for this proposal, so one taken
+/// from another proposal or mint can't stand in for it.
+fn take_over_marker(
+    marker: &AccountInfo,
+    proposal_id: u64,
+    mint: &Pubkey,
+    voter: &Pubkey,
+    owner: &Pubkey,
+) -> Result<Option<Pubkey>> {
+    let (marker_key, _) = Pubkey::find_program_address(
+        &[NftVoteMarker::SEED, &proposal_id.to_le_bytes(), mint.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(marker.key(), marker_key, AuctionError::VoteMarkerMismatch);
+    if *marker.owner != crate::ID || marker.data_is_empty() {
+        return Ok(None);
+    }
+    let mut record = NftVoteMarker::try_deserialize(&mut &marker.try_borrow_data()?[..])
+        .map_err(|_| error!(AuctionError::VoteMarkerMismatch))?;
+    require!(
+        record.proposal_id == proposal_id && record.nft_mint == *mint,
+        AuctionError::VoteMarkerMismatch
+    );
+    require!(
+        record.holder == *voter && *owner == *voter && record.voter != *voter,
+        AuctionError::NftAlreadyVoted
+    );
+    let delegate = record.voter;
+    record.voter = *voter;
+    record.try_serialize(&mut &mut marker.try_borrow_mut_data()?[..])?;
+    Ok(Some(delegate))
+}
+
+/// Move `count` of `holder`'s NFTs out of `delegate`'s vote: shrink the
+/// delegate's VoteRecord and take the trimmed allocation out of the tallies.
+/// A record the delegate has since withdrawn no longer counts — nothing to do.
+fn override_delegate_vote(
+    proposal: &mut GovernanceProposal,
+    record_info: &AccountInfo,
+    holder: &Pubkey,
+    delegate: Pubkey,
+    count: u64,
+) -> Result<()> {
+    if *record_info.owner != crate::ID || record_info.data_is_empty() {
+        return Ok(());
+    }
+    let mut record = VoteRecord::try_deserialize(&mut &record_info.try_borrow_data()?[..])
+        .map_err(|_| error!(AuctionError::OverriddenVoteRecordMismatch))?;
+    let (yes, no, abstain) = record.remove_delegated_weight(count);
+    record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+
+    proposal.yes = proposal.yes.checked_sub(yes).ok_or(AuctionError::Overflow)?;
+    proposal.no = proposal.no.checked_sub(no).ok_or(AuctionError::Overflow)?;
+    proposal.abstain = proposal.abstain.checked_sub(abstain).ok_or(AuctionError::Overflow)?;
+
+    emit!(DelegateVoteOverridden {
+        proposal_id: proposal.proposal_id,
+        holder: *holder,
+        delegate,
+        weight: count,
+        yes,
+        no,
+        abstain,
+    });
+    Ok(())
+}
+
+/// Create the NftVoteMarker PDA for `nft_mint`, paid by the voter, and
+/// record `holder` as the wallet holding it.
+/// Fails if the marker already exists, i.e. the NFT has voted.
+fn mark_nft_voted<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CastVote<'info>>,
+    marker: &AccountInfo<'info>,
+    proposal_id: u64,
+    nft_mint: Pubkey,
+    holder: Pubkey,
+) -> Result<()> {
+    let proposal_id_bytes = proposal_id.to_le_bytes();
+    let (marker_key, bump) = Pubkey::find_program_address(
+        &[NftVoteMarker::SEED, &proposal_id_bytes, nft_mint.as_ref()],
+        ctx.program_id,
+    );
+    require_keys_eq!(marker.key(), marker_key, AuctionError::VoteMarkerMismatch);
+    require!(
+        *marker.owner != *ctx.program_id && marker.data_is_empty(),
+        AuctionError::NftAlreadyVoted
+    );
+
+    let space = 8 + NftVoteMarker::INIT_SPACE;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        NftVoteMarker::SEED,
+        &proposal_id_bytes,
+        nft_mint.as_ref(),
+        &[bump],
+    ]];
+    create_pda_account(
+        &ctx.accounts.voter.to_account_info(),
+        marker,
+        &ctx.accounts.system_program.to_account_info(),
+        space,
+        ctx.program_id,
+        signer_seeds,
+    )?;
+
+    let record = NftVoteMarker {
+        proposal_id,
+        nft_mint,
+        voter: ctx.accounts.voter.key(),
+        bump,
+        holder,
+    };
+    record.try_serialize(&mut &mut marker.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(proposal_id: u64)]
 pub struct CastVote<'info> {
@@ -81,14 +344,10 @@ pub struct CastVote<'info> {
     #[account(mut)]
     pub voter: Signer<'info>,
 
-    /// Admin co-signature proves this voter's NFT holdings were verified
-    /// off-chain via Helius DAS before the transaction was constructed.
-    pub admin: Signer<'info>,
-
     #[account(
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
-        has_one = admin @ AuctionError::Unauthorized,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -99,10 +358,10 @@ pub struct CastVote<'info> {
     )]
     pub proposal: Account<'info, GovernanceProposal>,
 
-    /// VoteRecord PDA — `init` will fail if voter already voted for this
-    /// proposal, preventing double-voting without any explicit check.
+    /// VoteRecord PDA — created on the voter's first call, added to by
+    /// later ones. The NFT markers are what prevent double-voting.
     #[account(
-        init,
+        init_if_needed,
         payer = voter,
         space = 8 + VoteRecord::INIT_SPACE,
         seeds = [VoteRecord::SEED, &proposal_id.to_le_bytes(), voter.key().as_ref()],
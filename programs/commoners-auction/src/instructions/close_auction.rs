@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::state::{ProgramConfig, AuctionState, AuctionStatus, BidLog};
+use crate::errors::AuctionError;
+use crate::events::AuctionClosed;
+use crate::pda::{close_pda_account, refund_standing_bid};
+
+/// Crank closes a finished auction, recovering the rent it paid in
+/// create_auction, and its bid log, whose rent goes back to the payer the log
+/// records. Only allowed once the auction is in a terminal status. An auction
+/// that didn't sell but still has a bidder owes them the standing bid — stale
+/// closes used to leave it in the vault — so it is refunded first. Whatever
+/// the bid vault holds after that was sent to it by someone else and is swept
+/// to the crank rather than blocking the close.
+pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let now = Clock::get()?.unix_timestamp;
+
+    let status = auction.status_at(now);
+    require!(status.is_terminal(), AuctionError::InvalidStatusTransition);
+
+    let (refunded_bidder, refund) = if status == AuctionStatus::SettledSold {
+        (None, 0)
+    } else {
+        refund_standing_bid(
+            auction,
+            &ctx.accounts.bid_vault.to_account_info(),
+            ctx.bumps.bid_vault,
+            &ctx.accounts.current_bidder.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?
+    };
+
+    let leftover = ctx.accounts.bid_vault.lamports();
+    if leftover > 0 {
+        let auction_id_bytes = auction.auction_id.to_le_bytes();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.bid_vault.to_account_info(),
+                    to: ctx.accounts.crank_authority.to_account_info(),
+                },
+                &[&[b"bid-vault", &auction_id_bytes, &[ctx.bumps.bid_vault]]],
+            ),
+            leftover,
+        )?;
+    }
+
+    // The log only exists once the auction took a bid, or was created with
+    // one — when it does, it is closed with the auction. BidLog is zero-copy:
+    // total_bids and payer follow the discriminator and auction_id.
+    let bid_log = ctx.accounts.bid_log.to_account_info();
+    let total_bids = if *bid_log.owner == crate::ID && !bid_log.data_is_empty() {
+        let total_bids = {
+            let data = bid_log.try_borrow_data()?;
+            require!(
+                data.len() == BidLog::SPACE && data[..8] == *BidLog::DISCRIMINATOR,
+                AuctionError::InvalidBidLogAccount
+            );
+            require!(
+                data[24..56] == ctx.accounts.bid_log_payer.key().to_bytes(),
+                AuctionError::BidLogPayerMismatch
+            );
+            u64::from_le_bytes(data[16..24].try_into().unwrap())
+        };
+        close_pda_account(&bid_log, &ctx.accounts.bid_log_payer.to_account_info())?;
+        total_bids
+    } else {
+        0
+    };
+
+    emit!(AuctionClosed {
+        auction_id: auction.auction_id,
+        total_bids,
+        refunded_bidder,
+        refund,
+        swept: leftover,
+    });
+    msg!(
+        "Auction #{} closed with {} logged bids, {} lamports refunded and {} swept from its vault",
+        auction.auction_id,
+        total_bids,
+        refund,
+        leftover,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
+    /// Must be config.crank_authority. Receives the auction's rent.
+    #[account(mut)]
+    pub crank_authority: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = crank_authority @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [AuctionState::SEED, &auction.auction_id.to_le_bytes()],
+        bump = auction.bump,
+        close = crank_authority,
+    )]
+    pub auction: Account<'info, AuctionState>,
+
+    /// CHECK: The auction's BidLog PDA, closed by the handler if it exists.
+    /// It is only missing for an auction created before bid logs that never
+    /// took a bid — place_bid creates the log otherwise.
+    #[account(
+        mut,
+        seeds = [BidLog::SEED, &auction.auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_log: UncheckedAccount<'info>,
+
+    /// Receives the bid log's rent. Any account when there is no log.
+    /// CHECK: must be the payer the bid log records.
+    #[account(mut)]
+    pub bid_log_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bid-vault", &auction.auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_vault: SystemAccount<'info>,
+
+    /// Standing bidder — refunded if the auction didn't sell and still holds
+    /// their bid. Any account when there is none.
+    /// CHECK: must match auction.current_bidder if set.
+    #[account(
+        mut,
+        constraint = auction.current_bidder.is_none_or(|b| b == current_bidder.key()),
+    )]
+    pub current_bidder: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -3,27 +3,43 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
-use crate::state::{AuctionState, SlotRegistration};
+use crate::state::{AuctionState, AuctionStatus, DateReservation, ProgramConfig, SlotRegistration};
 use crate::errors::AuctionError;
+use crate::pda::{close_escrow_paying_keeper, refund_standing_bid};
+use crate::events::StaleClosed;
 
 /// Minimum seconds after auction.end_time before admin can close as stale.
-const STALE_THRESHOLD_SECS: i64 = 3 * 24 * 60 * 60; // 3 days
+/// Also the grace period after a slot's date before it can be reclaimed.
+pub const STALE_THRESHOLD_SECS: i64 = 3 * 24 * 60 * 60; // 3 days
 
 /// Permissionless stale auction cleanup — callable by anyone.
 /// Returns the escrowed NFT to the original seller for auctions that ended
-/// 3+ days ago without being settled. The seller is most incentivized to
-/// call this, but any wallet can trigger it.
+/// 3+ days ago without being settled, and refunds the standing bid (if any)
+/// from the bid vault so the auction can later be closed. The seller is most
+/// incentivized to call this, but any wallet can trigger it. The rent of the
+/// emptied escrow token account goes back to the seller who paid it, less
+/// config.keeper_reward_lamports to the payer; that of the date's
+/// reservation goes to whoever paid for it.
 pub fn close_stale_auction(ctx: Context<CloseStaleAuction>) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
     let now = Clock::get()?.unix_timestamp;
 
-    require!(!auction.settled, AuctionError::AlreadySettled);
     require!(
         now >= auction.end_time
             .checked_add(STALE_THRESHOLD_SECS)
             .ok_or(AuctionError::Overflow)?,
         AuctionError::AuctionNotStale
     );
+    auction.transition(AuctionStatus::ClosedStale, now)?;
+
+    // Refund the standing bid — nobody settled, so the bidder gets it back.
+    let (refunded_bidder, refund) = refund_standing_bid(
+        auction,
+        &ctx.accounts.bid_vault.to_account_info(),
+        ctx.bumps.bid_vault,
+        &ctx.accounts.current_bidder.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
 
     // Return NFT to seller via slot PDA as escrow authority.
     let nft_mint_key = ctx.accounts.nft_mint.key();
@@ -46,23 +62,56 @@ pub fn close_stale_auction(ctx: Context<CloseStaleAuction>) -> Result<()> {
     );
     token::transfer(nft_return_ctx, 1)?;
 
-    auction.settled = true;
-    auction.reserve_met = false;
+    // Escrow is now empty — its rent rewards the keeper, the rest goes back
+    // to the seller who paid it.
+    let keeper_reward = close_escrow_paying_keeper(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.slot.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.config.keeper_reward_lamports,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    DateReservation::release(
+        &ctx.accounts.date_reservation.to_account_info(),
+        &ctx.accounts.slot.nft_mint,
+        &ctx.accounts.date_reservation_payer.to_account_info(),
+    )?;
 
+    emit!(StaleClosed {
+        auction_id: auction.auction_id,
+        nft_mint: auction.nft_mint,
+        seller: auction.seller,
+        refunded_bidder,
+        refund,
+        keeper_reward,
+    });
     msg!(
-        "Stale auction #{} closed — NFT returned to seller {}",
+        "Stale auction #{} closed — NFT returned to seller {}, {} lamports refunded, keeper paid {}",
         auction.auction_id,
         auction.seller,
+        refund,
+        keeper_reward,
     );
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct CloseStaleAuction<'info> {
-    /// Anyone can trigger stale cleanup. Pays tx fee + ATA rent if needed.
+    /// Anyone can trigger stale cleanup. Pays tx fee + ATA rent if needed,
+    /// and receives the keeper reward.
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [AuctionState::SEED, &auction.auction_id.to_le_bytes()],
@@ -79,6 +128,20 @@ pub struct CloseStaleAuction<'info> {
     )]
     pub slot: Account<'info, SlotRegistration>,
 
+    /// The slot's date reservation, if it has one — slots listed before
+    /// reservations existed don't.
+    /// CHECK: address checked by the seeds, contents by DateReservation::release.
+    #[account(
+        mut,
+        seeds = [DateReservation::SEED, &slot.scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub date_reservation: UncheckedAccount<'info>,
+
+    /// CHECK: receives the date reservation's rent; must be the payer it records.
+    #[account(mut)]
+    pub date_reservation_payer: UncheckedAccount<'info>,
+
     pub nft_mint: Account<'info, Mint>,
 
     /// Escrow token account holding the NFT (owned by slot PDA).
@@ -102,6 +165,22 @@ pub struct CloseStaleAuction<'info> {
     #[account(mut)]
     pub seller: UncheckedAccount<'info>,
 
+    /// The auction vault holding the standing bid, if any.
+    #[account(
+        mut,
+        seeds = [b"bid-vault", &auction.auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_vault: SystemAccount<'info>,
+
+    /// Standing bidder — receives the refund. Any account when there is none.
+    /// CHECK: must match auction.current_bidder if set.
+    #[account(
+        mut,
+        constraint = auction.current_bidder.is_none_or(|b| b == current_bidder.key()),
+    )]
+    pub current_bidder: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProgramConfig, AuctionState, SlotRegistration};
+use crate::state::{ProgramConfig, AuctionState, AuctionStatus, BidLog, SlotRegistration};
 use crate::errors::AuctionError;
+use crate::events::AuctionCreated;
 
 /// Called by the backend crank at the start of each auction day.
 /// Opens bidding for the NFT registered in the given slot.
@@ -57,7 +58,22 @@ pub fn create_auction(
     auction.reserve_met = false;
     auction.auction_id = auction_id;
     auction.bump = ctx.bumps.auction;
+    auction.current_referrer = None;
+    auction.status = AuctionStatus::Live;
 
+    let mut bid_log = ctx.accounts.bid_log.load_init()?;
+    bid_log.auction_id = auction_id;
+    bid_log.payer = ctx.accounts.crank_authority.key();
+
+    emit!(AuctionCreated {
+        auction_id,
+        nft_mint: nft_mint_key,
+        seller,
+        reserve_price,
+        start_time: now,
+        end_time,
+        fee_bps,
+    });
     msg!(
         "Auction #{} created: mint={} seller={} end={} fee={}bps",
         auction_id,
@@ -72,14 +88,15 @@ pub fn create_auction(
 #[derive(Accounts)]
 #[instruction(auction_id: u64)]
 pub struct CreateAuction<'info> {
-    /// Backend crank wallet — must be the program admin.
+    /// Backend crank wallet — must be config.crank_authority.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub crank_authority: Signer<'info>,
 
     #[account(
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
-        has_one = admin @ AuctionError::Unauthorized,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_AUCTION_CREATION) @ AuctionError::ProgramPaused,
+        has_one = crank_authority @ AuctionError::Unauthorized,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -95,12 +112,21 @@ pub struct CreateAuction<'info> {
 
     #[account(
         init,
-        payer = admin,
+        payer = crank_authority,
         space = 8 + AuctionState::INIT_SPACE,
         seeds = [AuctionState::SEED, &auction_id.to_le_bytes()],
         bump,
     )]
     pub auction: Account<'info, AuctionState>,
 
+    #[account(
+        init,
+        payer = crank_authority,
+        space = BidLog::SPACE,
+        seeds = [BidLog::SEED, &auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_log: AccountLoader<'info, BidLog>,
+
     pub system_program: Program<'info, System>,
 }
@@ -1,28 +1,38 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProgramConfig, GovernanceProposal};
+use crate::state::{ProgramConfig, GovernanceProposal, ParamUpdate, ProposalContent, ProposalParamUpdate};
 use crate::errors::AuctionError;
+use crate::events::ProposalCreated;
 
-/// Called by the admin after a proposal has been reviewed and approved
+/// Called by the proposal manager after a proposal has been reviewed and approved
 /// off-chain (Discord discussion). Creates the immutable on-chain record
 /// and opens the voting window.
+///
+/// `content` keeps the description inline or points to a hosted document by
+/// URI and SHA-256, which allocates a smaller account.
+///
+/// A parameter proposal carries `param_update`, which anyone can apply with
+/// execute_proposal once the proposal passes — the vote governs the auction
+/// parameters it covers.
+///
+/// The proposal ID is config.next_proposal_id, shared with submit_proposal,
+/// so the proposal account must be the PDA for that ID.
+#[allow(clippy::too_many_arguments)]
 pub fn create_proposal(
     ctx: Context<CreateProposal>,
-    proposal_id: u64,
     proposer: Pubkey,
     title: String,
-    description: String,
+    content: ProposalContent,
     proposal_type: String,
     treasury_sol: u64,
+    recipient: Pubkey,
+    param_update: Option<ProposalParamUpdate>,
     duration_secs: i64,
 ) -> Result<()> {
-    require!(title.len() <= 100, AuctionError::TitleTooLong);
-    require!(description.len() <= 800, AuctionError::DescriptionTooLong);
-    require!(proposal_type.len() <= 50, AuctionError::TypeTooLong);
-    // 1 hour minimum, 7 days maximum
-    require!(
-        duration_secs >= 3_600 && duration_secs <= 604_800,
-        AuctionError::InvalidDuration
-    );
+    validate_proposal(&title, &content, &proposal_type, &param_update, duration_secs)?;
+
+    let config = &mut ctx.accounts.config;
+    let proposal_id = config.next_proposal_id;
+    config.next_proposal_id = proposal_id.checked_add(1).ok_or(AuctionError::Overflow)?;
 
     let now = Clock::get()?.unix_timestamp;
     let ends_at = now.checked_add(duration_secs).ok_or(AuctionError::Overflow)?;
@@ -30,19 +40,36 @@ pub fn create_proposal(
     let proposal = &mut ctx.accounts.proposal;
     proposal.proposal_id = proposal_id;
     proposal.proposer = proposer;
-    proposal.admin = ctx.accounts.admin.key();
+    proposal.admin = ctx.accounts.proposal_manager.key();
     proposal.title = title;
-    proposal.description = description;
+    proposal.set_content(content);
     proposal.proposal_type = proposal_type;
     proposal.treasury_sol = treasury_sol;
+    proposal.recipient = recipient;
+    proposal.executed_at = 0;
+    proposal.param_update = param_update;
+    proposal.deposit_lamports = 0;
+    proposal.voting_duration_secs = duration_secs;
     proposal.created_at = now;
     proposal.ends_at = ends_at;
     proposal.yes = 0;
     proposal.no = 0;
     proposal.abstain = 0;
-    proposal.status = 0; // active
+    proposal.status = GovernanceProposal::STATUS_ACTIVE;
     proposal.bump = ctx.bumps.proposal;
 
+    emit!(ProposalCreated {
+        proposal_id,
+        proposer,
+        proposal_type: proposal.proposal_type.clone(),
+        treasury_sol,
+        recipient,
+        param_update: proposal.param_update.clone(),
+        ends_at,
+        content_uri: proposal.content_uri.clone(),
+        content_hash: proposal.content_hash,
+        deposit_lamports: 0,
+    });
     msg!(
         "Governance proposal {} created. Ends: {}",
         proposal_id,
@@ -51,24 +78,61 @@ pub fn create_proposal(
     Ok(())
 }
 
+/// Content and duration checks shared with submit_proposal.
+pub(crate) fn validate_proposal(
+    title: &str,
+    content: &ProposalContent,
+    proposal_type: &str,
+    param_update: &Option<ProposalParamUpdate>,
+    duration_secs: i64,
+) -> Result<()> {
+    require!(title.len() <= 100, AuctionError::TitleTooLong);
+    match content {
+        ProposalContent::Inline { description } => require!(
+            description.len() <= GovernanceProposal::MAX_DESCRIPTION_LEN,
+            AuctionError::DescriptionTooLong
+        ),
+        ProposalContent::Hosted { uri, content_hash } => require!(
+            !uri.is_empty()
+                && uri.len() <= GovernanceProposal::MAX_URI_LEN
+                && *content_hash != [0; 32],
+            AuctionError::InvalidProposalContent
+        ),
+    }
+    require!(proposal_type.len() <= 50, AuctionError::TypeTooLong);
+    // 1 hour minimum, 7 days maximum
+    require!(
+        (3_600..=604_800).contains(&duration_secs),
+        AuctionError::InvalidDuration
+    );
+
+    if let Some(update) = param_update {
+        ParamUpdate::from(update.clone()).validate()?;
+    }
+    Ok(())
+}
+
 #[derive(Accounts)]
-#[instruction(proposal_id: u64)]
+#[instruction(proposer: Pubkey, title: String, content: ProposalContent)]
 pub struct CreateProposal<'info> {
+    /// Must be config.proposal_manager.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub proposal_manager: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
-        has_one = admin @ AuctionError::Unauthorized,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+        has_one = proposal_manager @ AuctionError::Unauthorized,
     )]
     pub config: Account<'info, ProgramConfig>,
 
     #[account(
         init,
-        payer = admin,
-        space = 8 + GovernanceProposal::INIT_SPACE,
-        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        payer = proposal_manager,
+        space = GovernanceProposal::space(&content),
+        seeds = [GovernanceProposal::SEED, &config.next_proposal_id.to_le_bytes()],
         bump,
     )]
     pub proposal: Account<'info, GovernanceProposal>,
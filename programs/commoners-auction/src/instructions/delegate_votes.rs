@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, Delegation};
+use crate::errors::AuctionError;
+use crate::events::VotesDelegated;
+
+/// Holder delegates the votes of the Commoners they hold to `delegate`.
+/// The NFTs stay in the holder's wallet; the delegate passes the holder's
+/// token accounts to cast_vote. One delegation per holder — revoke it to
+/// pick a different delegate.
+pub fn delegate_votes(ctx: Context<DelegateVotes>, delegate: Pubkey) -> Result<()> {
+    let holder = ctx.accounts.holder.key();
+    require_keys_neq!(delegate, holder, AuctionError::SelfDelegation);
+
+    let delegation = &mut ctx.accounts.delegation;
+    delegation.holder = holder;
+    delegation.delegate = delegate;
+    delegation.created_at = Clock::get()?.unix_timestamp;
+    delegation.bump = ctx.bumps.delegation;
+
+    emit!(VotesDelegated { holder, delegate });
+    msg!("Votes of {} delegated to {}", holder, delegate);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + Delegation::INIT_SPACE,
+        seeds = [Delegation::SEED, holder.key().as_ref()],
+        bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
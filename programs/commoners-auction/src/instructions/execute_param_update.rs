@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, PendingParamUpdate};
+use crate::errors::AuctionError;
+use crate::events::ParamsUpdated;
+
+/// Permissionless — applies a queued config change once its eta has passed.
+/// Rent from the pending account returns to the admin that queued it.
+pub fn execute_param_update(ctx: Context<ExecuteParamUpdate>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &ctx.accounts.pending;
+    require!(now >= pending.eta, AuctionError::ParamUpdateNotReady);
+
+    let update = pending.update.clone();
+    update.apply(&mut ctx.accounts.config);
+
+    emit!(ParamsUpdated { update });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteParamUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [PendingParamUpdate::SEED],
+        bump = pending.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub pending: Account<'info, PendingParamUpdate>,
+
+    /// CHECK: receives the pending account's rent; validated by has_one.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::{ProgramConfig, GovernanceProposal, ParamUpdate};
+use crate::errors::AuctionError;
+use crate::events::{ParamsUpdated, ProposalExecuted};
+
+/// Permissionless — pays a passed proposal's `treasury_sol` from the treasury
+/// PDA to its `recipient` and applies its `param_update` to ProgramConfig,
+/// once `config.execution_delay_secs` has passed since voting ended.
+/// The delay leaves the admin time to veto.
+/// Marks the proposal executed, so it can never pay out twice.
+pub fn execute_proposal(ctx: Context<ExecuteProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == GovernanceProposal::STATUS_PASSED,
+        AuctionError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let executable_at = proposal
+        .ends_at
+        .checked_add(ctx.accounts.config.execution_delay_secs)
+        .ok_or(AuctionError::Overflow)?;
+    require!(now >= executable_at, AuctionError::ExecutionDelayNotElapsed);
+
+    let amount = proposal.treasury_sol;
+    if amount > 0 {
+        // The treasury is a data-less system account and must stay rent exempt.
+        let spendable = ctx
+            .accounts
+            .treasury
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        require!(amount <= spendable, AuctionError::InsufficientTreasury);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            ProgramConfig::TREASURY_SEED,
+            &[ctx.bumps.treasury],
+        ]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: ctx.accounts.recipient.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
+
+    if let Some(update) = proposal.param_update.clone() {
+        let update = ParamUpdate::from(update);
+        update.apply(&mut ctx.accounts.config);
+        emit!(ParamsUpdated { update });
+    }
+
+    proposal.status = GovernanceProposal::STATUS_EXECUTED;
+    proposal.executed_at = now;
+
+    emit!(ProposalExecuted {
+        proposal_id: proposal.proposal_id,
+        recipient: proposal.recipient,
+        amount,
+    });
+    msg!(
+        "Proposal {} executed: {} lamports → {}",
+        proposal.proposal_id,
+        amount,
+        proposal.recipient,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Program-owned treasury PDA that collects protocol fees.
+    #[account(
+        mut,
+        seeds = [ProgramConfig::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// CHECK: must be the recipient recorded on the proposal.
+    #[account(mut, address = proposal.recipient)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,27 +1,54 @@
 use anchor_lang::prelude::*;
 use crate::state::{ProgramConfig, GovernanceProposal};
 use crate::errors::AuctionError;
+use crate::events::{ProposalDepositSettled, ProposalFinalized};
 
-/// Called by admin after the voting window closes to set the final status.
-/// Status values: 1 = passed, 2 = failed, 3 = queued (awaiting execution).
-pub fn finalize_proposal(
-    ctx: Context<FinalizeProposal>,
-    _proposal_id: u64,
-    status: u8,
-) -> Result<()> {
+/// Permissionless — callable by anyone after the voting window closes.
+/// The outcome is computed from the tallies against config.quorum_bps and
+/// config.approval_threshold_bps; see GovernanceProposal::outcome.
+///
+/// Also settles a submitted proposal's deposit: back to the proposer if
+/// the vote reached quorum, pass or fail, otherwise to the treasury.
+pub fn finalize_proposal(ctx: Context<FinalizeProposal>, _proposal_id: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let proposal = &mut ctx.accounts.proposal;
     require!(
-        status >= 1 && status <= 3,
-        AuctionError::InvalidFinalStatus
+        proposal.status == GovernanceProposal::STATUS_ACTIVE,
+        AuctionError::ProposalNotActive
     );
 
-    let proposal = &mut ctx.accounts.proposal;
-    require!(proposal.status == 0, AuctionError::ProposalNotActive);
-
     let now = Clock::get()?.unix_timestamp;
     require!(now >= proposal.ends_at, AuctionError::VotingStillActive);
 
+    let status = proposal.outcome(
+        config.max_vote_weight,
+        config.quorum_bps,
+        config.approval_threshold_bps,
+    );
     proposal.status = status;
 
+    let refunded = proposal.quorum_met(config.max_vote_weight, config.quorum_bps);
+    let to = if refunded {
+        ctx.accounts.proposer.to_account_info()
+    } else {
+        ctx.accounts.treasury.to_account_info()
+    };
+    let amount = GovernanceProposal::release_deposit(proposal, &to)?;
+    if amount > 0 {
+        emit!(ProposalDepositSettled {
+            proposal_id: proposal.proposal_id,
+            amount,
+            refunded,
+        });
+    }
+
+    emit!(ProposalFinalized {
+        proposal_id: proposal.proposal_id,
+        status,
+        yes: proposal.yes,
+        no: proposal.no,
+        abstain: proposal.abstain,
+    });
     msg!(
         "Proposal {} finalized with status {}",
         proposal.proposal_id,
@@ -33,12 +60,10 @@ pub fn finalize_proposal(
 #[derive(Accounts)]
 #[instruction(proposal_id: u64)]
 pub struct FinalizeProposal<'info> {
-    pub admin: Signer<'info>,
-
     #[account(
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
-        has_one = admin @ AuctionError::Unauthorized,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -48,4 +73,16 @@ pub struct FinalizeProposal<'info> {
         bump = proposal.bump,
     )]
     pub proposal: Account<'info, GovernanceProposal>,
+
+    /// CHECK: must be the proposer recorded on the proposal; receives a refunded deposit.
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Program-owned treasury PDA; receives a forfeited deposit.
+    #[account(
+        mut,
+        seeds = [ProgramConfig::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
 }
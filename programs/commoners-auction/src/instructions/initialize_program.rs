@@ -1,23 +1,31 @@
 use anchor_lang::prelude::*;
-use crate::state::{ProgramConfig, DiscountTier};
+use crate::state::{ProgramConfig, DiscountTier, FeeRecipient};
 use crate::errors::AuctionError;
+use crate::program::CommonersAuction;
 
+/// One-time setup. Only the program's upgrade authority (the deployer) can
+/// initialize, so nobody can front-run a fresh deploy and claim `admin`.
 pub fn initialize_program(
     ctx: Context<InitializeProgram>,
-    treasury: Pubkey,
     default_fee_bps: u16,
     bid_increment_bps: u16,
     time_buffer_secs: i64,
     min_reserve_lamports: u64,
     required_collection: Option<Pubkey>,
+    param_update_delay_secs: i64,
 ) -> Result<()> {
     require!(default_fee_bps <= 10_000, AuctionError::FeeTooHigh);
     require!(bid_increment_bps <= 5_000, AuctionError::InvalidBidIncrement);
     require!(time_buffer_secs >= 0, AuctionError::InvalidTimeBuffer);
+    require!(
+        (ProgramConfig::MIN_PARAM_UPDATE_DELAY_SECS..=ProgramConfig::MAX_PARAM_UPDATE_DELAY_SECS)
+            .contains(&param_update_delay_secs),
+        AuctionError::InvalidParamUpdateDelay
+    );
 
     let config = &mut ctx.accounts.config;
     config.admin = ctx.accounts.admin.key();
-    config.treasury = treasury;
+    config.version = ProgramConfig::VERSION;
     config.default_fee_bps = default_fee_bps;
     config.bid_increment_bps = bid_increment_bps;
     config.time_buffer_secs = time_buffer_secs;
@@ -25,11 +33,29 @@ pub fn initialize_program(
     config.common_token_mint = None;
     config.discount_tiers = [DiscountTier::default(); 4];
     config.required_collection = required_collection;
+    config.royalties_enabled = false;
+    config.royalty_cap_bps = None;
+    config.referral_bps = 0;
+    config.fee_recipients = [FeeRecipient::default(); 4];
+    config.settler_reward_lamports = 0;
+    config.keeper_reward_lamports = 0;
+    config.pending_admin = None;
+    config.paused = 0;
+    config.crank_authority = config.admin;
+    config.commoner_collection = None;
+    config.quorum_bps = ProgramConfig::DEFAULT_QUORUM_BPS;
+    config.approval_threshold_bps = ProgramConfig::DEFAULT_APPROVAL_THRESHOLD_BPS;
+    config.execution_delay_secs = ProgramConfig::DEFAULT_EXECUTION_DELAY_SECS;
+    config.proposal_deposit_lamports = ProgramConfig::DEFAULT_PROPOSAL_DEPOSIT_LAMPORTS;
+    config.proposal_approval_required = false;
+    config.max_vote_weight = ProgramConfig::DEFAULT_MAX_VOTE_WEIGHT;
+    config.proposal_manager = config.admin;
+    config.param_update_delay_secs = param_update_delay_secs;
     config.bump = ctx.bumps.config;
 
     msg!(
         "Program initialized. Treasury: {}, Fee: {}bps, Buffer: {}s, MinReserve: {} lamports",
-        treasury,
+        ProgramConfig::treasury_address(),
         default_fee_bps,
         time_buffer_secs,
         min_reserve_lamports
@@ -39,6 +65,7 @@ pub fn initialize_program(
 
 #[derive(Accounts)]
 pub struct InitializeProgram<'info> {
+    /// Must be the program's upgrade authority. Becomes the config admin.
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -51,5 +78,17 @@ pub struct InitializeProgram<'info> {
     )]
     pub config: Account<'info, ProgramConfig>,
 
+    /// This program — used to locate its ProgramData account.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ AuctionError::Unauthorized,
+    )]
+    pub program: Program<'info, CommonersAuction>,
+
+    /// Upgradeable loader ProgramData account holding the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ AuctionError::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
     pub system_program: Program<'info, System>,
 }
@@ -3,22 +3,26 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount, Transfer},
 };
-use borsh::BorshDeserialize;
-use crate::state::{ProgramConfig, SlotRegistration};
+use crate::state::{DateReservation, ProgramConfig, SlotRegistration};
 use crate::errors::AuctionError;
-
-/// Metaplex Token Metadata program ID.
-const TOKEN_METADATA_PROGRAM_ID: Pubkey =
-    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+use crate::metadata::verify_collection_membership;
+use crate::events::SlotListed;
 
 /// Called by an NFT holder to lock their MidEvil into escrow
 /// and claim a future auction slot. Once locked, the NFT will
 /// be auctioned on the scheduled date regardless of whether
-/// the holder changes their mind.
+/// the holder changes their mind. Each date takes one NFT — the
+/// date reservation fails to initialize if the date is already booked.
+///
+/// With `auto_relist` set, an unsold NFT stays in escrow at settlement and
+/// is re-registered for a later date, its reserve lowered by
+/// `reserve_decay_bps` each time.
 pub fn list_slot(
     ctx: Context<ListSlot>,
     scheduled_date: i64,
     reserve_price: u64,
+    auto_relist: bool,
+    reserve_decay_bps: u16,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
     let now = Clock::get()?.unix_timestamp;
@@ -29,27 +33,13 @@ pub fn list_slot(
         reserve_price >= config.min_reserve_lamports,
         AuctionError::ReserveTooLow
     );
+    require!(reserve_decay_bps <= 10_000, AuctionError::InvalidReserveDecay);
 
     // Collection verification — only enforced when required_collection is configured.
     if let Some(required_collection) = config.required_collection {
-        // Derive the expected Metaplex metadata PDA and verify the account matches.
-        let tmid = TOKEN_METADATA_PROGRAM_ID;
-        let (metadata_pda, _) = Pubkey::find_program_address(
-            &[b"metadata", tmid.as_ref(), ctx.accounts.nft_mint.key().as_ref()],
-            &tmid,
-        );
-        require_keys_eq!(
-            ctx.accounts.nft_metadata.key(),
-            metadata_pda,
-            AuctionError::CollectionNotVerified
-        );
-        // Verify the account is actually owned by the Metaplex program.
-        require!(
-            *ctx.accounts.nft_metadata.owner == TOKEN_METADATA_PROGRAM_ID,
-            AuctionError::CollectionNotVerified
-        );
-        verify_nft_collection(
+        verify_collection_membership(
             &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.key(),
             required_collection,
         )?;
     }
@@ -74,12 +64,29 @@ pub fn list_slot(
     slot.escrowed = true;
     slot.consumed = false;
     slot.bump = ctx.bumps.slot;
-
+    slot.auto_relist = auto_relist;
+    slot.reserve_decay_bps = reserve_decay_bps;
+
+    let reservation = &mut ctx.accounts.date_reservation;
+    reservation.scheduled_date = scheduled_date;
+    reservation.nft_mint = slot.nft_mint;
+    reservation.payer = ctx.accounts.holder.key();
+    reservation.bump = ctx.bumps.date_reservation;
+
+    emit!(SlotListed {
+        nft_mint: slot.nft_mint,
+        owner: slot.owner,
+        scheduled_date,
+        reserve_price,
+        auto_relist,
+        reserve_decay_bps,
+    });
     msg!(
-        "Slot registered: mint={} owner={} date={}",
+        "Slot registered: mint={} owner={} date={} auto_relist={}",
         slot.nft_mint,
         slot.owner,
-        slot.scheduled_date
+        slot.scheduled_date,
+        slot.auto_relist,
     );
     Ok(())
 }
@@ -90,12 +97,17 @@ pub struct ListSlot<'info> {
     #[account(mut)]
     pub holder: Signer<'info>,
 
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_LISTING) @ AuctionError::ProgramPaused,
+    )]
     pub config: Account<'info, ProgramConfig>,
 
     pub nft_mint: Account<'info, Mint>,
 
     /// Metaplex metadata account for collection verification.
-    /// CHECK: Validated in instruction via MetaplexMetadata::find_pda + safe_deserialize.
+    /// CHECK: Validated in instruction via metadata::verify_collection_membership.
     pub nft_metadata: UncheckedAccount<'info>,
 
     /// Holder's token account for this NFT.
@@ -125,59 +137,17 @@ pub struct ListSlot<'info> {
     )]
     pub slot: Account<'info, SlotRegistration>,
 
+    /// Date reservation PDA — seeds ensure one slot per date across all mints.
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + DateReservation::INIT_SPACE,
+        seeds = [DateReservation::SEED, &scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub date_reservation: Account<'info, DateReservation>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
-
-/// Verify that the NFT belongs to the required Metaplex collection.
-///
-/// Deserializes the Metaplex metadata account (borsh v0.10) and checks:
-///   - key == 4 (MetadataV1)
-///   - collection.verified == true
-///   - collection.key == required_collection
-///
-/// Metaplex on-chain layout (borsh-encoded):
-///   key: u8, update_authority: [u8;32], mint: [u8;32],
-///   name: String, symbol: String, uri: String,
-///   seller_fee_basis_points: u16,
-///   creators: Option<Vec<{addr:[u8;32], verified:bool, share:u8}>>,
-///   primary_sale_happened: bool, is_mutable: bool,
-///   edition_nonce: Option<u8>, token_standard: Option<u8>,
-///   collection: Option<{verified:bool, key:[u8;32]}>
-fn verify_nft_collection(
-    metadata_info: &AccountInfo,
-    required_collection: Pubkey,
-) -> Result<()> {
-    #[derive(BorshDeserialize)]
-    struct MdCreator { _a: [u8; 32], _v: bool, _s: u8 }
-
-    #[derive(BorshDeserialize)]
-    struct MdCollection { verified: bool, key: [u8; 32] }
-
-    let data = metadata_info.try_borrow_data()?;
-    let buf = &mut &data[..];
-
-    let key: u8 = u8::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?;
-    require!(key == 4, AuctionError::CollectionNotVerified);
-    let _: [u8; 32] = <[u8; 32]>::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // update_authority
-    let _: [u8; 32] = <[u8; 32]>::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // mint
-    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // name
-    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // symbol
-    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // uri
-    let _: u16 = u16::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // seller_fee_basis_points
-    let _: Option<Vec<MdCreator>> = Option::<Vec<MdCreator>>::deserialize(buf)
-        .map_err(|_| error!(AuctionError::CollectionNotVerified))?;
-    let _: bool = bool::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // primary_sale_happened
-    let _: bool = bool::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // is_mutable
-    let _: Option<u8> = Option::<u8>::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // edition_nonce
-    let _: Option<u8> = Option::<u8>::deserialize(buf).map_err(|_| error!(AuctionError::CollectionNotVerified))?; // token_standard
-    let col: Option<MdCollection> = Option::<MdCollection>::deserialize(buf)
-        .map_err(|_| error!(AuctionError::CollectionNotVerified))?;
-
-    let col = col.ok_or_else(|| error!(AuctionError::CollectionNotVerified))?;
-    require!(col.verified, AuctionError::CollectionNotVerified);
-    require!(col.key == required_collection.to_bytes(), AuctionError::CollectionMismatch);
-
-    Ok(())
-}
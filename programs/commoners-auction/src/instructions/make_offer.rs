@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::Mint;
+use crate::state::{ProgramConfig, Offer};
+use crate::errors::AuctionError;
+use crate::events::OfferMade;
+use crate::metadata::verify_collection_membership;
+
+/// Called by any wallet to place a standing SOL offer on an NFT.
+/// The NFT does not need to be listed — any mint from the required
+/// collection qualifies. The offer is escrowed in its own PDA until the
+/// holder accepts it or the buyer cancels.
+pub fn make_offer(ctx: Context<MakeOffer>, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    require!(
+        amount >= config.min_reserve_lamports,
+        AuctionError::OfferTooLow
+    );
+
+    // Collection verification — only enforced when required_collection is configured.
+    if let Some(required_collection) = config.required_collection {
+        verify_collection_membership(
+            &ctx.accounts.nft_metadata.to_account_info(),
+            &ctx.accounts.nft_mint.key(),
+            required_collection,
+        )?;
+    }
+
+    // Escrow the offered lamports in the offer PDA.
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: ctx.accounts.offer.to_account_info(),
+        },
+    );
+    system_program::transfer(transfer_ctx, amount)?;
+
+    let offer = &mut ctx.accounts.offer;
+    offer.nft_mint = ctx.accounts.nft_mint.key();
+    offer.buyer = ctx.accounts.buyer.key();
+    offer.amount = amount;
+    offer.created_at = Clock::get()?.unix_timestamp;
+    offer.bump = ctx.bumps.offer;
+
+    emit!(OfferMade {
+        nft_mint: offer.nft_mint,
+        buyer: offer.buyer,
+        amount,
+    });
+    msg!(
+        "Offer made: {} lamports by {} for mint {}",
+        amount,
+        offer.buyer,
+        offer.nft_mint,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_OFFERS) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Metaplex metadata account for collection verification.
+    /// CHECK: Validated in instruction via metadata::verify_collection_membership.
+    pub nft_metadata: UncheckedAccount<'info>,
+
+    /// Offer PDA — seeds allow one open offer per buyer per mint.
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Offer::INIT_SPACE,
+        seeds = [Offer::SEED, nft_mint.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    pub system_program: Program<'info, System>,
+}
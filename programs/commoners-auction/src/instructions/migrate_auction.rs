@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{AuctionState, AuctionStatus};
+use crate::errors::AuctionError;
+use crate::pda::refund_standing_bid;
+
+/// Size of an AuctionState created before `current_referrer` (an
+/// Option<Pubkey>) and `status` were appended.
+const ORIGINAL_LEN: usize = 8 + AuctionState::INIT_SPACE - (1 + 32) - AuctionStatus::INIT_SPACE;
+
+/// Permissionless migration of an AuctionState in the original layout.
+/// The appended `current_referrer` is None, and the status is derived from
+/// the legacy flags and the dates:
+///   - not settled                        → Live, or Ended once past end_time
+///   - settled, reserve met               → SettledSold
+///   - settled, reserve not met, a bidder → ClosedStale (settlement would have sold it)
+///   - settled, reserve not met, no bids  → SettledUnsold
+///
+/// A stale close of an auction without bids left the same flags as an unsold
+/// settlement, so those are recorded as SettledUnsold. Stale closes used to
+/// leave the standing bid in the vault; it is refunded here, as
+/// close_stale_auction now does.
+/// The payer tops up rent for the extra bytes. A current account is left unchanged.
+pub fn migrate_auction(ctx: Context<MigrateAuction>, _auction_id: u64) -> Result<()> {
+    let account_info = ctx.accounts.auction.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, AuctionError::InvalidAuctionAccount);
+
+    let new_size = 8 + AuctionState::INIT_SPACE;
+    let current_size = account_info.data_len();
+    if current_size == new_size {
+        msg!("AuctionState already current — no migration needed");
+        return Ok(());
+    }
+
+    let mut body = {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            current_size == ORIGINAL_LEN && data[..8] == *AuctionState::DISCRIMINATOR,
+            AuctionError::InvalidAuctionAccount
+        );
+        data[8..].to_vec()
+    };
+    // Zero padding decodes as current_referrer = None; status is set below.
+    body.resize(AuctionState::INIT_SPACE, 0);
+    let mut auction = AuctionState::deserialize(&mut &body[..])
+        .map_err(|_| error!(AuctionError::InvalidAuctionAccount))?;
+
+    let now = Clock::get()?.unix_timestamp;
+    auction.status = match (auction.settled, auction.reserve_met) {
+        (false, _) if now >= auction.end_time => AuctionStatus::Ended,
+        (false, _) => AuctionStatus::Live,
+        (true, true) => AuctionStatus::SettledSold,
+        (true, false) if auction.current_bidder.is_some() => AuctionStatus::ClosedStale,
+        (true, false) => AuctionStatus::SettledUnsold,
+    };
+    if auction.status == AuctionStatus::ClosedStale {
+        require_keys_eq!(
+            ctx.accounts.current_bidder.key(),
+            auction.current_bidder.unwrap(),
+            AuctionError::BidderMismatch
+        );
+        let (_, refund) = refund_standing_bid(
+            &mut auction,
+            &ctx.accounts.bid_vault.to_account_info(),
+            ctx.bumps.bid_vault,
+            &ctx.accounts.current_bidder.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        msg!("Refunded the standing bid of {} lamports", refund);
+    }
+
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(new_size);
+    let current_lamports = account_info.lamports();
+    if current_lamports < required_lamports {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    account_info.resize(new_size)?;
+    auction.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
+
+    msg!(
+        "Auction #{} migrated with status {:?}",
+        auction.auction_id,
+        auction.status,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(auction_id: u64)]
+pub struct MigrateAuction<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Using AccountInfo directly to avoid borsh decode of the old
+    /// layout. Owner, discriminator and size are verified in the handler;
+    /// the PDA seeds constraint guarantees the address.
+    #[account(
+        mut,
+        seeds = [AuctionState::SEED, &auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub auction: UncheckedAccount<'info>,
+
+    /// The auction's bid vault — refunds the standing bid of a stale-closed auction.
+    #[account(
+        mut,
+        seeds = [b"bid-vault", &auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_vault: SystemAccount<'info>,
+
+    /// Standing bidder of a stale-closed auction — receives the refund.
+    /// Any account otherwise.
+    /// CHECK: matched against the decoded auction.current_bidder in the handler.
+    #[account(mut)]
+    pub current_bidder: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,53 +1,196 @@
 use anchor_lang::prelude::*;
-use crate::state::ProgramConfig;
+use anchor_lang::Discriminator;
+use crate::state::{DiscountTier, FeeRecipient, ProgramConfig};
+use crate::errors::AuctionError;
 
-/// One-time migration: extends the ProgramConfig account to accommodate the
-/// new `required_collection: Option<Pubkey>` field added at the end.
+/// Body length (after the discriminator) of the original, unversioned
+/// layout at its largest, `required_collection` included. Versioned layouts
+/// are always longer.
+const V1_BODY_LEN: usize = 191;
+
+/// Offset of `version` within the body, right after `admin`.
+const VERSION_OFFSET: usize = 32;
+
+/// Rewrites a raw body of one layout version into the next.
+type Upgrade = fn(&mut Vec<u8>) -> Result<()>;
+
+/// One upgrade per layout version: `UPGRADES[n - 1]` rewrites a body of
+/// version n into version n + 1. A layout change appends one entry here and
+/// bumps ProgramConfig::VERSION.
+const UPGRADES: [Upgrade; ProgramConfig::VERSION as usize - 1] = [v1_to_v2];
+
+/// Admin-only migration of ProgramConfig to the current layout version.
 ///
-/// The existing account data is unchanged; realloc zero-initializes the new
-/// 33 bytes, which encodes `required_collection = None` in borsh.
+/// The account is read as raw bytes so any earlier layout can be handled:
+///   1. The stored admin is parsed from the fixed header and must match the
+///      signer — nobody else can rewrite the config.
+///   2. The stored version is detected: by length for the unversioned
+///      original layout, from the version byte after it.
+///   3. The registered upgrades run one version at a time, each rewriting
+///      the serialized fields and giving new ones their defaults.
+///   4. The account is resized, rent topped up by the admin, and rewritten.
 ///
-/// Safe to call multiple times — realloc to the same size is a no-op.
+/// Safe to call multiple times — a current account is left unchanged.
 pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
-    let new_size = 8 + ProgramConfig::INIT_SPACE;
     let account_info = ctx.accounts.config.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, AuctionError::InvalidConfigAccount);
+
+    let (from_version, mut body) = {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + VERSION_OFFSET && data[..8] == *ProgramConfig::DISCRIMINATOR,
+            AuctionError::InvalidConfigAccount
+        );
+        let body = data[8..].to_vec();
+
+        // Every layout starts with the admin pubkey.
+        let stored_admin = Pubkey::try_from(&body[..VERSION_OFFSET])
+            .map_err(|_| error!(AuctionError::InvalidConfigAccount))?;
+        require_keys_eq!(stored_admin, ctx.accounts.admin.key(), AuctionError::Unauthorized);
+
+        (stored_version(&body)?, body)
+    };
+    require!(
+        (1..=ProgramConfig::VERSION).contains(&from_version),
+        AuctionError::UnsupportedConfigVersion
+    );
+
+    if from_version == ProgramConfig::VERSION {
+        msg!("ProgramConfig already at version {} — no migration needed", from_version);
+        return Ok(());
+    }
+
+    for version in from_version..ProgramConfig::VERSION {
+        UPGRADES[version as usize - 1](&mut body)?;
+    }
+    body[VERSION_OFFSET] = ProgramConfig::VERSION;
+    body.resize(ProgramConfig::INIT_SPACE, 0);
+    let config = ProgramConfig::deserialize(&mut &body[..])
+        .map_err(|_| error!(AuctionError::InvalidConfigAccount))?;
+
     let current_size = account_info.data_len();
+    let new_size = 8 + ProgramConfig::INIT_SPACE;
+    // Fund the account if it needs more lamports for rent exemption.
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(new_size);
+    let current_lamports = account_info.lamports();
+    if current_lamports < required_lamports {
+        let diff = required_lamports.saturating_sub(current_lamports);
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            diff,
+        )?;
+    }
+    account_info.resize(new_size)?;
+    config.try_serialize(&mut &mut account_info.try_borrow_mut_data()?[..])?;
 
-    if current_size < new_size {
-        // Fund the account if it needs more lamports for rent exemption.
-        let rent = Rent::get()?;
-        let required_lamports = rent.minimum_balance(new_size);
-        let current_lamports = account_info.lamports();
-        if current_lamports < required_lamports {
-            let diff = required_lamports.saturating_sub(current_lamports);
-            anchor_lang::system_program::transfer(
-                CpiContext::new(
-                    ctx.accounts.system_program.to_account_info(),
-                    anchor_lang::system_program::Transfer {
-                        from: ctx.accounts.admin.to_account_info(),
-                        to: account_info.clone(),
-                    },
-                ),
-                diff,
-            )?;
-        }
-        account_info.realloc(new_size, false)?;
-        msg!("ProgramConfig extended from {} to {} bytes", current_size, new_size);
-    } else {
-        msg!("ProgramConfig already at {} bytes — no migration needed", current_size);
+    msg!(
+        "ProgramConfig migrated from version {} to {} ({} → {} bytes)",
+        from_version,
+        ProgramConfig::VERSION,
+        current_size,
+        new_size,
+    );
+    Ok(())
+}
+
+/// Identify the layout version of a raw ProgramConfig body.
+fn stored_version(body: &[u8]) -> Result<u8> {
+    if body.len() <= V1_BODY_LEN {
+        return Ok(1);
     }
+    Ok(body[VERSION_OFFSET])
+}
+
+/// 1 → 2: the original layout rewritten into the versioned one. Its economics,
+/// collection gate and bump are kept; every field it lacked starts at the
+/// value initialize_program gives it, roles held by the admin and governance
+/// at its defaults.
+///
+/// The stored treasury wallet is dropped: fees go to the program-owned
+/// treasury PDA that execute_proposal spends from. It is logged, so the admin
+/// can list it in fee_recipients if it should keep a share.
+///
+/// The original account only holds `required_collection` if the old
+/// migrate_config extended it, or if a None common_token_mint left room for
+/// it — spare bytes are zeroed, so they read as None.
+///
+/// Builds the ProgramConfig struct, so the next layout change must first pin
+/// this upgrade to the version 2 fields.
+fn v1_to_v2(body: &mut Vec<u8>) -> Result<()> {
+    let mut data = &body[..];
+    let (admin, old_treasury, default_fee_bps, bid_increment_bps) =
+        <(Pubkey, Pubkey, u16, u16)>::deserialize(&mut data)
+            .map_err(|_| error!(AuctionError::InvalidConfigAccount))?;
+    let (time_buffer_secs, min_reserve_lamports) = <(i64, u64)>::deserialize(&mut data)
+        .map_err(|_| error!(AuctionError::InvalidConfigAccount))?;
+    let (common_token_mint, discount_tiers, bump) =
+        <(Option<Pubkey>, [DiscountTier; 4], u8)>::deserialize(&mut data)
+            .map_err(|_| error!(AuctionError::InvalidConfigAccount))?;
+    let required_collection = if data.is_empty() {
+        None
+    } else {
+        Option::<Pubkey>::deserialize(&mut data)
+            .map_err(|_| error!(AuctionError::InvalidConfigAccount))?
+    };
+
+    msg!(
+        "Treasury {} dropped — fees go to the program-owned PDA {}",
+        old_treasury,
+        ProgramConfig::treasury_address(),
+    );
 
+    let config = ProgramConfig {
+        admin,
+        version: 2,
+        default_fee_bps,
+        bid_increment_bps,
+        time_buffer_secs,
+        min_reserve_lamports,
+        common_token_mint,
+        discount_tiers,
+        bump,
+        required_collection,
+        royalties_enabled: false,
+        royalty_cap_bps: None,
+        referral_bps: 0,
+        fee_recipients: [FeeRecipient::default(); 4],
+        settler_reward_lamports: 0,
+        pending_admin: None,
+        paused: 0,
+        crank_authority: admin,
+        proposal_manager: admin,
+        param_update_delay_secs: ProgramConfig::DEFAULT_PARAM_UPDATE_DELAY_SECS,
+        commoner_collection: None,
+        quorum_bps: ProgramConfig::DEFAULT_QUORUM_BPS,
+        approval_threshold_bps: ProgramConfig::DEFAULT_APPROVAL_THRESHOLD_BPS,
+        execution_delay_secs: ProgramConfig::DEFAULT_EXECUTION_DELAY_SECS,
+        proposal_deposit_lamports: ProgramConfig::DEFAULT_PROPOSAL_DEPOSIT_LAMPORTS,
+        proposal_approval_required: false,
+        next_proposal_id: 0,
+        max_vote_weight: ProgramConfig::DEFAULT_MAX_VOTE_WEIGHT,
+        keeper_reward_lamports: 0,
+    };
+    body.clear();
+    config.serialize(body)?;
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct MigrateConfig<'info> {
+    /// Must match the admin stored in the config, parsed from raw bytes.
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    /// CHECK: Using AccountInfo directly to avoid borsh decode of old layout.
-    /// We verify admin authority via the discriminator + admin field manually
-    /// and rely on the PDA seeds constraint for authenticity.
+    /// CHECK: Using AccountInfo directly to avoid borsh decode of old layouts.
+    /// Owner, discriminator and admin are verified from the raw bytes in the
+    /// handler; the PDA seeds constraint guarantees the address.
     #[account(
         mut,
         seeds = [ProgramConfig::SEED],
@@ -57,3 +200,95 @@ pub struct MigrateConfig<'info> {
 
     pub system_program: Program<'info, System>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADMIN: Pubkey = Pubkey::new_from_array([1; 32]);
+    const COMMON_MINT: Pubkey = Pubkey::new_from_array([2; 32]);
+    const COLLECTION: Pubkey = Pubkey::new_from_array([3; 32]);
+
+    /// A version 1 body: the original 158 bytes, plus `required_collection`
+    /// when the old migrate_config extended the account to 191.
+    fn v1_body(common_token_mint: Option<Pubkey>, extended: Option<Option<Pubkey>>) -> Vec<u8> {
+        let tiers = [DiscountTier { min_balance: 1_000, fee_bps: 250 }; 4];
+        let mut body = Vec::new();
+        (ADMIN, Pubkey::new_from_array([9; 32]), 500u16, 1_000u16).serialize(&mut body).unwrap();
+        (300i64, 100_000_000u64, common_token_mint, tiers, 254u8).serialize(&mut body).unwrap();
+        let len = match extended {
+            Some(required_collection) => {
+                required_collection.serialize(&mut body).unwrap();
+                V1_BODY_LEN
+            }
+            None => 158,
+        };
+        body.resize(len, 0);
+        body
+    }
+
+    fn upgraded(mut body: Vec<u8>) -> ProgramConfig {
+        v1_to_v2(&mut body).unwrap();
+        assert!(body.len() <= ProgramConfig::INIT_SPACE);
+        ProgramConfig::deserialize(&mut &body[..]).unwrap()
+    }
+
+    #[test]
+    fn v1_to_v2_keeps_the_original_fields() {
+        let config = upgraded(v1_body(Some(COMMON_MINT), None));
+        assert_eq!(config.admin, ADMIN);
+        assert_eq!(config.version, 2);
+        assert_eq!((config.default_fee_bps, config.bid_increment_bps), (500, 1_000));
+        assert_eq!((config.time_buffer_secs, config.min_reserve_lamports), (300, 100_000_000));
+        assert_eq!(config.common_token_mint, Some(COMMON_MINT));
+        assert_eq!(config.discount_tiers[3].min_balance, 1_000);
+        assert_eq!(config.discount_tiers[3].fee_bps, 250);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.required_collection, None);
+    }
+
+    #[test]
+    fn v1_to_v2_gives_new_fields_their_defaults() {
+        let config = upgraded(v1_body(Some(COMMON_MINT), None));
+        assert_eq!(config.crank_authority, ADMIN);
+        assert_eq!(config.proposal_manager, ADMIN);
+        assert_eq!(config.fee_recipient_count(), 0);
+        assert_eq!(config.quorum_bps, ProgramConfig::DEFAULT_QUORUM_BPS);
+        assert_eq!(config.max_vote_weight, ProgramConfig::DEFAULT_MAX_VOTE_WEIGHT);
+        assert_eq!((config.paused, config.next_proposal_id), (0, 0));
+        assert_eq!(config.pending_admin, None);
+    }
+
+    #[test]
+    fn v1_to_v2_keeps_an_extended_required_collection() {
+        let config = upgraded(v1_body(Some(COMMON_MINT), Some(Some(COLLECTION))));
+        assert_eq!(config.required_collection, Some(COLLECTION));
+
+        let config = upgraded(v1_body(None, Some(Some(COLLECTION))));
+        assert_eq!(config.common_token_mint, None);
+        assert_eq!(config.required_collection, Some(COLLECTION));
+    }
+
+    #[test]
+    fn v1_to_v2_reads_spare_zeroed_bytes_as_no_collection() {
+        let config = upgraded(v1_body(None, None));
+        assert_eq!(config.common_token_mint, None);
+        assert_eq!(config.required_collection, None);
+    }
+
+    #[test]
+    fn stored_version_detects_the_original_layout_by_length() {
+        assert_eq!(stored_version(&v1_body(Some(COMMON_MINT), None)).unwrap(), 1);
+        assert_eq!(stored_version(&v1_body(Some(COMMON_MINT), Some(None))).unwrap(), 1);
+    }
+
+    #[test]
+    fn stored_version_reads_the_version_byte_after_it() {
+        let mut body = v1_body(Some(COMMON_MINT), None);
+        v1_to_v2(&mut body).unwrap();
+        assert_eq!(stored_version(&body).unwrap(), 2);
+
+        body[VERSION_OFFSET] = 3;
+        assert_eq!(stored_version(&body).unwrap(), 3);
+    }
+}
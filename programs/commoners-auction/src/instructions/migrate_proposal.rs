@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::GovernanceProposal;
+use crate::errors::AuctionError;
+
+/// Permissionless migration of a GovernanceProposal created before
+/// `content_uri` and `content_hash` were appended.
+///
+/// Fields are only ever appended after the length-prefixed strings, so an old
+/// account already decodes when the unused string capacity leaves enough
+/// zeroed bytes at its end. One that doesn't is grown to the full inline
+/// layout; the new bytes are zero, which decodes as an empty URI and a zero
+/// hash — an inline proposal. The payer tops up rent for the extra bytes.
+/// A proposal that already decodes is left unchanged.
+pub fn migrate_proposal(ctx: Context<MigrateProposal>, _proposal_id: u64) -> Result<()> {
+    let account_info = ctx.accounts.proposal.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, AuctionError::InvalidProposalAccount);
+    {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == *GovernanceProposal::DISCRIMINATOR,
+            AuctionError::InvalidProposalAccount
+        );
+        if GovernanceProposal::try_deserialize(&mut &data[..]).is_ok() {
+            msg!("GovernanceProposal already current — no migration needed");
+            return Ok(());
+        }
+    }
+
+    let new_size = 8 + GovernanceProposal::INIT_SPACE - GovernanceProposal::MAX_URI_LEN;
+    let current_size = account_info.data_len();
+    require!(current_size < new_size, AuctionError::InvalidProposalAccount);
+
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(new_size);
+    let current_lamports = account_info.lamports();
+    if current_lamports < required_lamports {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    account_info.resize(new_size)?;
+
+    let proposal = GovernanceProposal::try_deserialize(&mut &account_info.try_borrow_data()?[..])
+        .map_err(|_| error!(AuctionError::InvalidProposalAccount))?;
+
+    msg!(
+        "Proposal {} migrated ({} → {} bytes)",
+        proposal.proposal_id,
+        current_size,
+        new_size,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct MigrateProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Using AccountInfo directly to avoid borsh decode of the old
+    /// layout. Owner and discriminator are verified in the handler; the PDA
+    /// seeds constraint guarantees the address.
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
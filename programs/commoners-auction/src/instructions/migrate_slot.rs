@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::SlotRegistration;
+use crate::errors::AuctionError;
+
+/// Bytes `auto_relist` and `reserve_decay_bps` added to SlotRegistration.
+const AUTO_RELIST_FIELDS_LEN: usize = 1 + 2;
+
+/// Permissionless migration of a SlotRegistration created before
+/// `auto_relist` and `reserve_decay_bps` were appended. The new bytes are
+/// zero, so the slot decodes with auto-relist off and no reserve decay —
+/// exactly how it behaved before. The payer tops up rent for the extra bytes.
+/// A current account is left unchanged.
+pub fn migrate_slot(
+    ctx: Context<MigrateSlot>,
+    _nft_mint: Pubkey,
+    _scheduled_date: i64,
+) -> Result<()> {
+    let account_info = ctx.accounts.slot.to_account_info();
+    require_keys_eq!(*account_info.owner, crate::ID, AuctionError::InvalidSlotAccount);
+
+    let new_size = 8 + SlotRegistration::INIT_SPACE;
+    let current_size = account_info.data_len();
+    if current_size == new_size {
+        msg!("SlotRegistration already current — no migration needed");
+        return Ok(());
+    }
+
+    {
+        let data = account_info.try_borrow_data()?;
+        require!(
+            current_size == new_size - AUTO_RELIST_FIELDS_LEN
+                && data[..8] == *SlotRegistration::DISCRIMINATOR,
+            AuctionError::InvalidSlotAccount
+        );
+    }
+
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(new_size);
+    let current_lamports = account_info.lamports();
+    if current_lamports < required_lamports {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    account_info.resize(new_size)?;
+
+    let slot = SlotRegistration::try_deserialize(&mut &account_info.try_borrow_data()?[..])
+        .map_err(|_| error!(AuctionError::InvalidSlotAccount))?;
+
+    msg!(
+        "Slot for {} on {} migrated ({} → {} bytes)",
+        slot.nft_mint,
+        slot.scheduled_date,
+        current_size,
+        new_size,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nft_mint: Pubkey, scheduled_date: i64)]
+pub struct MigrateSlot<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Using AccountInfo directly to avoid borsh decode of the old
+    /// layout. Owner, discriminator and size are verified in the handler;
+    /// the PDA seeds constraint guarantees the address.
+    #[account(
+        mut,
+        seeds = [SlotRegistration::SEED, nft_mint.as_ref(), &scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub slot: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,25 +1,73 @@
 pub mod initialize_program;
 pub mod list_slot;
+pub mod set_auto_relist;
 pub mod create_auction;
 pub mod place_bid;
 pub mod settle_auction;
-pub mod update_params;
+pub mod queue_param_update;
+pub mod execute_param_update;
+pub mod cancel_param_update;
 pub mod create_proposal;
+pub mod submit_proposal;
+pub mod approve_proposal;
+pub mod reject_proposal;
 pub mod cast_vote;
+pub mod update_vote;
+pub mod withdraw_vote;
+pub mod delegate_votes;
+pub mod revoke_delegation;
 pub mod finalize_proposal;
+pub mod veto_proposal;
+pub mod execute_proposal;
 pub mod close_stale_auction;
-pub mod set_required_collection;
+pub mod cancel_auction;
+pub mod close_auction;
 pub mod migrate_config;
+pub mod migrate_auction;
+pub mod migrate_slot;
+pub mod migrate_proposal;
+pub mod make_offer;
+pub mod cancel_offer;
+pub mod accept_offer;
+pub mod reclaim_expired_slot;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod set_pause;
+pub mod set_roles;
 
 pub use initialize_program::*;
 pub use list_slot::*;
+pub use set_auto_relist::*;
 pub use create_auction::*;
 pub use place_bid::*;
 pub use settle_auction::*;
-pub use update_params::*;
+pub use queue_param_update::*;
+pub use execute_param_update::*;
+pub use cancel_param_update::*;
 pub use create_proposal::*;
+pub use submit_proposal::*;
+pub use approve_proposal::*;
+pub use reject_proposal::*;
 pub use cast_vote::*;
+pub use update_vote::*;
+pub use withdraw_vote::*;
+pub use delegate_votes::*;
+pub use revoke_delegation::*;
 pub use finalize_proposal::*;
+pub use veto_proposal::*;
+pub use execute_proposal::*;
 pub use close_stale_auction::*;
-pub use set_required_collection::*;
+pub use cancel_auction::*;
+pub use close_auction::*;
 pub use migrate_config::*;
+pub use migrate_auction::*;
+pub use migrate_slot::*;
+pub use migrate_proposal::*;
+pub use make_offer::*;
+pub use cancel_offer::*;
+pub use accept_offer::*;
+pub use reclaim_expired_slot::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use set_pause::*;
+pub use set_roles::*;
@@ -1,29 +1,38 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{ProgramConfig, AuctionState};
+use crate::state::{ProgramConfig, AuctionState, BidLog};
 use crate::errors::AuctionError;
+use crate::events::{AuctionExtended, BidPlaced};
 
 /// Called by any wallet to place a bid on the active auction.
 ///
 /// Rules enforced:
-/// - Auction must be active (started, not ended, not settled)
+/// - Auction must be Live (started, not ended, not settled or cancelled)
 /// - Bid must meet minimum: max(reserve_price, current_bid * (1 + increment_bps))
 /// - Previous bidder is immediately refunded their full bid
 /// - If bid lands within time_buffer_secs of end, extend end_time
-pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
+///
+/// `referrer` credits the frontend that drove this bid; if the bid wins,
+/// the referrer receives referral_bps of the protocol fee at settlement.
+/// A bidder cannot name themselves.
+pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64, referrer: Option<Pubkey>) -> Result<()> {
     let config = &ctx.accounts.config;
     let auction = &mut ctx.accounts.auction;
     let now = Clock::get()?.unix_timestamp;
 
-    require!(now >= auction.start_time, AuctionError::AuctionNotStarted);
-    require!(!auction.settled, AuctionError::AlreadySettled);
-    require!(now < auction.end_time, AuctionError::AuctionEnded);
+    auction.require_live(now)?;
+    require!(
+        referrer != Some(ctx.accounts.bidder.key()),
+        AuctionError::SelfReferral
+    );
 
     let min_bid = auction.min_next_bid(config.bid_increment_bps);
     require!(bid_amount >= min_bid, AuctionError::BidTooLow);
 
     // Refund the previous bidder if one exists.
-    if let Some(prev_bidder) = auction.current_bidder {
+    let refunded_bidder = auction.current_bidder;
+    let refund_amount = auction.current_bid;
+    if let Some(prev_bidder) = refunded_bidder {
         let prev_bid = auction.current_bid;
         if prev_bid > 0 {
             // bid_vault is a system-owned PDA — use CPI with PDA signer seeds.
@@ -60,10 +69,17 @@ pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
 
     // Anti-sniping: extend auction if bid is within the time buffer.
     let time_remaining = auction.end_time.checked_sub(now).unwrap_or(0);
-    if time_remaining < config.time_buffer_secs {
+    let extended = time_remaining < config.time_buffer_secs;
+    if extended {
+        let previous_end_time = auction.end_time;
         auction.end_time = now
             .checked_add(config.time_buffer_secs)
             .ok_or(AuctionError::Overflow)?;
+        emit!(AuctionExtended {
+            auction_id: auction.auction_id,
+            previous_end_time,
+            end_time: auction.end_time,
+        });
         msg!(
             "Anti-snipe: auction extended to {}",
             auction.end_time
@@ -73,7 +89,27 @@ pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
     // Record the bid.
     auction.current_bid = bid_amount;
     auction.current_bidder = Some(ctx.accounts.bidder.key());
-
+    auction.current_referrer = referrer;
+
+    // Auctions created before bid logs get theirs with their first bid.
+    let mut bid_log = if ctx.accounts.bid_log.load().is_err() {
+        let mut bid_log = ctx.accounts.bid_log.load_init()?;
+        bid_log.auction_id = auction.auction_id;
+        bid_log.payer = ctx.accounts.bidder.key();
+        bid_log
+    } else {
+        ctx.accounts.bid_log.load_mut()?
+    };
+    bid_log.append(ctx.accounts.bidder.key(), bid_amount, now, extended);
+
+    emit!(BidPlaced {
+        auction_id: auction.auction_id,
+        bidder: ctx.accounts.bidder.key(),
+        amount: bid_amount,
+        referrer,
+        refunded_bidder,
+        refund_amount,
+    });
     msg!(
         "Bid placed: {} lamports by {} (auction #{}, ends {})",
         bid_amount,
@@ -93,6 +129,7 @@ pub struct PlaceBid<'info> {
     #[account(
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_BIDDING) @ AuctionError::ProgramPaused,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -112,12 +149,22 @@ pub struct PlaceBid<'info> {
     )]
     pub bid_vault: SystemAccount<'info>,
 
+    /// Created here for auctions opened before bid logs existed.
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = BidLog::SPACE,
+        seeds = [BidLog::SEED, &auction.auction_id.to_le_bytes()],
+        bump,
+    )]
+    pub bid_log: AccountLoader<'info, BidLog>,
+
     /// Previous bidder account — required for the refund transfer.
     /// Validated at runtime: must match auction.current_bidder if set.
     /// CHECK: we verify this matches auction.current_bidder below via constraint.
     #[account(
         mut,
-        constraint = auction.current_bidder.map_or(true, |pb| pb == prev_bidder.key()),
+        constraint = auction.current_bidder.is_none_or(|pb| pb == prev_bidder.key()),
     )]
     pub prev_bidder: UncheckedAccount<'info>,
 
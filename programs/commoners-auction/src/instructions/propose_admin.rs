@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ProgramConfig;
+use crate::errors::AuctionError;
+use crate::events::AdminProposed;
+
+/// Step one of the admin handoff: the current admin nominates a successor.
+/// Nothing changes until the nominee signs accept_admin, so a mistyped
+/// address can't lock the multisig out. Pass None to cancel a pending handoff.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: config.admin,
+        pending_admin: new_admin,
+    });
+    match new_admin {
+        Some(nominee) => msg!("Admin handoff proposed to {}", nominee),
+        None => msg!("Pending admin handoff cancelled"),
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, ParamUpdate, PendingParamUpdate};
+use crate::errors::AuctionError;
+use crate::events::ParamUpdateQueued;
+
+/// Admin queues a config change. It can be applied by anyone via
+/// execute_param_update once `config.param_update_delay_secs` has passed,
+/// giving users time to react before fees or rules change.
+pub fn queue_param_update(ctx: Context<QueueParamUpdate>, update: ParamUpdate) -> Result<()> {
+    update.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(ctx.accounts.config.param_update_delay_secs)
+        .ok_or(AuctionError::Overflow)?;
+
+    let pending = &mut ctx.accounts.pending;
+    pending.update = update.clone();
+    pending.proposer = ctx.accounts.admin.key();
+    pending.queued_at = now;
+    pending.eta = eta;
+    pending.bump = ctx.bumps.pending;
+
+    emit!(ParamUpdateQueued { update, eta });
+    msg!("Param update queued. ETA: {}", eta);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct QueueParamUpdate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Fails to init while another update is pending — cancel it first.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PendingParamUpdate::INIT_SPACE,
+        seeds = [PendingParamUpdate::SEED],
+        bump,
+    )]
+    pub pending: Account<'info, PendingParamUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use crate::state::{DateReservation, ProgramConfig, SlotRegistration};
+use crate::errors::AuctionError;
+use crate::events::SlotReclaimed;
+use crate::pda::close_escrow_paying_keeper;
+use crate::instructions::close_stale_auction::STALE_THRESHOLD_SECS;
+
+/// Permissionless expired slot cleanup — callable by anyone.
+/// Returns the escrowed NFT to the slot owner when the scheduled date passed
+/// 3+ days ago and no auction was ever created for it (e.g. the crank missed
+/// the day). The slot and its emptied escrow are closed and their rent goes
+/// back to the slot owner, less config.keeper_reward_lamports of the escrow's
+/// rent to the payer; the date's reservation is closed to whoever paid for it.
+pub fn reclaim_expired_slot(ctx: Context<ReclaimExpiredSlot>) -> Result<()> {
+    let slot = &ctx.accounts.slot;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(slot.escrowed, AuctionError::NotEscrowed);
+    require!(!slot.consumed, AuctionError::SlotConsumed);
+    require!(
+        now >= slot.scheduled_date
+            .checked_add(STALE_THRESHOLD_SECS)
+            .ok_or(AuctionError::Overflow)?,
+        AuctionError::SlotNotExpired
+    );
+
+    // Return NFT to owner via slot PDA as escrow authority.
+    let nft_mint_key = ctx.accounts.nft_mint.key();
+    let scheduled_date_bytes = slot.scheduled_date.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        SlotRegistration::SEED,
+        nft_mint_key.as_ref(),
+        &scheduled_date_bytes,
+        &[slot.bump],
+    ]];
+
+    let nft_return_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.slot.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token::transfer(nft_return_ctx, 1)?;
+
+    // Escrow is now empty — its rent rewards the keeper, the rest goes back
+    // to the owner who paid it.
+    let keeper_reward = close_escrow_paying_keeper(
+        &ctx.accounts.escrow_token_account.to_account_info(),
+        &ctx.accounts.slot.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.accounts.config.keeper_reward_lamports,
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer_seeds,
+    )?;
+
+    DateReservation::release(
+        &ctx.accounts.date_reservation.to_account_info(),
+        &ctx.accounts.slot.nft_mint,
+        &ctx.accounts.date_reservation_payer.to_account_info(),
+    )?;
+
+    let slot = &ctx.accounts.slot;
+    emit!(SlotReclaimed {
+        nft_mint: slot.nft_mint,
+        owner: slot.owner,
+        scheduled_date: slot.scheduled_date,
+        keeper_reward,
+    });
+    msg!(
+        "Expired slot reclaimed: mint={} date={} — NFT returned to {}, keeper paid {}",
+        slot.nft_mint,
+        slot.scheduled_date,
+        slot.owner,
+        keeper_reward,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimExpiredSlot<'info> {
+    /// Anyone can trigger reclaim. Pays tx fee + ATA rent if needed, and
+    /// receives the keeper reward.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [SlotRegistration::SEED, nft_mint.key().as_ref(), &slot.scheduled_date.to_le_bytes()],
+        bump = slot.bump,
+        has_one = owner @ AuctionError::SellerMismatch,
+        close = owner,
+    )]
+    pub slot: Account<'info, SlotRegistration>,
+
+    /// The slot's booking of its date. Slots listed before date reservations
+    /// existed have none.
+    /// CHECK: address checked by the seeds, contents by DateReservation::release.
+    #[account(
+        mut,
+        seeds = [DateReservation::SEED, &slot.scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub date_reservation: UncheckedAccount<'info>,
+
+    /// CHECK: receives the date reservation's rent; must be the payer it records.
+    #[account(mut)]
+    pub date_reservation_payer: UncheckedAccount<'info>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    /// Escrow token account holding the NFT (owned by slot PDA).
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = slot,
+    )]
+    pub escrow_token_account: Account<'info, TokenAccount>,
+
+    /// Owner's token account — receives the NFT back.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: validated by slot has_one = owner. Receives the rent of the
+    /// closed slot and escrow.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, GovernanceProposal};
+use crate::errors::AuctionError;
+use crate::events::{ProposalDepositSettled, ProposalRejected};
+
+/// Closes a submitted proposal that never opened for voting, refunding the
+/// proposer's deposit — unlike a veto, rejection is not a penalty.
+/// The proposal manager can reject it at any time; once
+/// GovernanceProposal::APPROVAL_WINDOW_SECS have passed since submission,
+/// anyone can expire it so the deposit is never stuck.
+pub fn reject_proposal(ctx: Context<RejectProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        proposal.status == GovernanceProposal::STATUS_PENDING_APPROVAL,
+        AuctionError::ProposalNotPending
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let caller = ctx.accounts.caller.key();
+    let expired = now
+        >= proposal
+            .created_at
+            .checked_add(GovernanceProposal::APPROVAL_WINDOW_SECS)
+            .ok_or(AuctionError::Overflow)?;
+    require!(
+        expired || caller == ctx.accounts.config.proposal_manager,
+        AuctionError::ApprovalWindowOpen
+    );
+
+    proposal.status = GovernanceProposal::STATUS_REJECTED;
+
+    let proposer = ctx.accounts.proposer.to_account_info();
+    let amount = GovernanceProposal::release_deposit(proposal, &proposer)?;
+    if amount > 0 {
+        emit!(ProposalDepositSettled {
+            proposal_id: proposal.proposal_id,
+            amount,
+            refunded: true,
+        });
+    }
+
+    emit!(ProposalRejected {
+        proposal_id: proposal.proposal_id,
+        rejected_by: caller,
+        expired,
+    });
+    msg!(
+        "Proposal {} rejected by {}, {} lamports deposit refunded",
+        proposal.proposal_id,
+        caller,
+        amount,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct RejectProposal<'info> {
+    /// The proposal manager, or anyone once the approval window has passed.
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// CHECK: the proposal's proposer, validated by has_one. Receives the deposit.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::Delegation;
+use crate::events::DelegationRevoked;
+
+/// Holder revokes their delegation. Votes the delegate already cast with the
+/// holder's NFTs stand until the holder votes directly, which takes those
+/// NFTs over (see cast_vote); future delegate votes can't count them.
+/// Never paused, so holders can always take their votes back.
+pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+    emit!(DelegationRevoked {
+        holder: delegation.holder,
+        delegate: delegation.delegate,
+    });
+    msg!("Delegation from {} to {} revoked", delegation.holder, delegation.delegate);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [Delegation::SEED, holder.key().as_ref()],
+        bump = delegation.bump,
+        has_one = holder,
+        close = holder,
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
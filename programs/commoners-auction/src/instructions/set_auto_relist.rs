@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::SlotRegistration;
+use crate::errors::AuctionError;
+use crate::events::AutoRelistUpdated;
+
+/// Seller switches automatic relisting on or off for one of their slots.
+/// Switching it off on a relisted slot ends the cycle: if that auction
+/// doesn't sell either, settlement returns the NFT instead of relisting it.
+/// Never paused, so a seller can always get their NFT back out.
+pub fn set_auto_relist(ctx: Context<SetAutoRelist>, auto_relist: bool) -> Result<()> {
+    let slot = &mut ctx.accounts.slot;
+    slot.auto_relist = auto_relist;
+
+    emit!(AutoRelistUpdated {
+        nft_mint: slot.nft_mint,
+        owner: slot.owner,
+        scheduled_date: slot.scheduled_date,
+        auto_relist,
+    });
+    msg!(
+        "Auto-relist {} for mint {} on {}",
+        if auto_relist { "enabled" } else { "disabled" },
+        slot.nft_mint,
+        slot.scheduled_date,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAutoRelist<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SlotRegistration::SEED, slot.nft_mint.as_ref(), &slot.scheduled_date.to_le_bytes()],
+        bump = slot.bump,
+        has_one = owner @ AuctionError::SellerMismatch,
+    )]
+    pub slot: Account<'info, SlotRegistration>,
+}
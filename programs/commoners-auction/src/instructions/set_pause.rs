@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ProgramConfig;
+use crate::errors::AuctionError;
+use crate::events::PauseUpdated;
+
+/// Admin sets the emergency pause bitflags. Each PAUSE_* bit halts one
+/// capability (listing, bidding, auction creation, governance, offers);
+/// pass 0 to resume everything.
+pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+    require!(
+        paused & !ProgramConfig::PAUSE_ALL == 0,
+        AuctionError::InvalidPauseFlags
+    );
+
+    let config = &mut ctx.accounts.config;
+    let previous = config.paused;
+    config.paused = paused;
+
+    emit!(PauseUpdated { previous, paused });
+    msg!("Pause flags updated from {:#07b} to {:#07b}", previous, paused);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::ProgramConfig;
+use crate::errors::AuctionError;
+use crate::events::RolesUpdated;
+
+/// Admin assigns the narrower operational roles, so the hot crank key and
+/// the governance keys don't carry the multisig's full authority.
+pub fn set_roles(
+    ctx: Context<SetRoles>,
+    crank_authority: Pubkey,
+    proposal_manager: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.crank_authority = crank_authority;
+    config.proposal_manager = proposal_manager;
+
+    emit!(RolesUpdated {
+        crank_authority,
+        proposal_manager,
+    });
+    msg!(
+        "Roles updated: crank authority {}, proposal manager {}",
+        crank_authority,
+        proposal_manager,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+}
@@ -1,29 +1,68 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
+    associated_token::{self, AssociatedToken},
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
 };
-use crate::state::{ProgramConfig, AuctionState, SlotRegistration};
+use crate::state::{ProgramConfig, AuctionState, AuctionStatus, DateReservation, SlotRegistration};
 use crate::errors::AuctionError;
+use crate::metadata::{is_metadata_account, parse_metadata};
+use crate::pda::{close_pda_account, create_pda_account};
+use crate::events::{AuctionSettled, SlotListed};
 
 /// Permissionless settlement — callable by anyone after auction.end_time.
 ///
 /// The winning bidder is most incentivized to call this (they want their NFT),
-/// but any wallet can trigger it. The payer covers tx fees and any ATA rent.
+/// but any wallet can trigger it. The payer covers tx fees and any ATA rent,
+/// and, when sold, is rewarded with up to settler_reward_lamports of the
+/// protocol fee. An unsold settlement pays no reward: nothing funds it, and a
+/// seller behind an unreachable reserve could crank it daily. The rent of the
+/// emptied escrow token account goes back to the seller, and that of the
+/// date's reservation to whoever paid it. On auto-relist the seller's old
+/// slot and escrow are closed to the payer instead, refunding the rent of
+/// the new ones it created.
 ///
 /// Settlement logic:
-/// - If reserve_price was met: NFT → winner, SOL bid → seller (minus fee), fee → treasury
+/// - If reserve_price was met: NFT → winner, SOL bid → seller (minus fee and
+///   royalties), fee → treasury or fee recipients (minus referral_bps →
+///   referrer, if any — or → treasury if the referrer can't receive it),
+///   royalties → verified creators (when enabled)
 /// - If no bids or reserve not met: NFT → seller, no SOL moves
-pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
-    let auction = &mut ctx.accounts.auction;
+/// - If no bids or reserve not met and the slot opted into auto-relist:
+///   NFT stays in escrow under a new slot, no SOL moves. The new date is the
+///   first free date within SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS of
+///   tomorrow, or `relist_date` when the seller settles and picks one.
+///   Either way the date must not be booked.
+///
+/// Remaining accounts when sold (all writable), in order:
+/// - one per used entry of config.fee_recipients, in table order
+/// - when royalties are enabled, the verified creators in metadata order
+///
+/// Remaining accounts when relisting without `relist_date`: the
+/// DateReservations of the booked dates from tomorrow on, in date order,
+/// up to the first free date.
+pub fn settle_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+    relist_date: Option<i64>,
+) -> Result<()> {
     let now = Clock::get()?.unix_timestamp;
 
-    require!(auction.is_ended(now), AuctionError::AuctionNotEnded);
-    require!(!auction.settled, AuctionError::AlreadySettled);
+    let reserve_met = ctx.accounts.auction.current_bid >= ctx.accounts.auction.reserve_price
+        && ctx.accounts.auction.current_bidder.is_some();
+    let status = if reserve_met {
+        AuctionStatus::SettledSold
+    } else {
+        AuctionStatus::SettledUnsold
+    };
+    ctx.accounts.auction.transition(status, now)?;
+    let auction = &ctx.accounts.auction;
 
-    let reserve_met = auction.current_bid >= auction.reserve_price
-        && auction.current_bidder.is_some();
+    // Sold or not, the slot's date is used up.
+    DateReservation::release(
+        &ctx.accounts.date_reservation.to_account_info(),
+        &auction.nft_mint,
+        &ctx.accounts.date_reservation_payer.to_account_info(),
+    )?;
 
     if reserve_met {
         // --- Transfer NFT from escrow to winner ---
@@ -48,13 +87,12 @@ pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         );
         token::transfer(nft_transfer_ctx, 1)?;
 
-        // --- Split bid: seller gets (bid - fee), treasury gets fee ---
+        // --- Split bid: seller gets (bid - fee - royalties), treasury gets fee ---
         let bid = auction.current_bid;
         let fee = bid
             .checked_mul(auction.fee_bps as u64)
             .ok_or(AuctionError::Overflow)?
             / 10_000;
-        let seller_proceeds = bid.checked_sub(fee).ok_or(AuctionError::Overflow)?;
 
         // bid_vault is a system-owned PDA — use CPI with PDA signer seeds.
         let auction_id_bytes = auction.auction_id.to_le_bytes();
@@ -62,6 +100,57 @@ pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         let seeds: &[&[u8]] = &[b"bid-vault", &auction_id_bytes, &[vault_bump]];
         let signer_seeds = &[seeds];
 
+        let fee_recipient_count = ctx.accounts.config.fee_recipient_count();
+        require!(
+            ctx.remaining_accounts.len() >= fee_recipient_count,
+            AuctionError::FeeRecipientMismatch
+        );
+        let (fee_accounts, creator_accounts) = ctx.remaining_accounts.split_at(fee_recipient_count);
+
+        let royalties = if ctx.accounts.config.royalties_enabled {
+            let available = bid.checked_sub(fee).ok_or(AuctionError::Overflow)?;
+            ctx.accounts.pay_royalties(creator_accounts, bid, available, signer_seeds)?
+        } else {
+            0
+        };
+        let seller_proceeds = bid
+            .checked_sub(fee)
+            .and_then(|p| p.checked_sub(royalties))
+            .ok_or(AuctionError::Overflow)?;
+
+        // --- Referral: referrer takes referral_bps of the fee ---
+        let mut referral = match auction.current_referrer {
+            Some(_) => fee
+                .checked_mul(ctx.accounts.config.referral_bps as u64)
+                .ok_or(AuctionError::Overflow)?
+                / 10_000,
+            None => 0,
+        };
+        let treasury_fee = fee.checked_sub(referral).ok_or(AuctionError::Overflow)?;
+
+        // A referrer that can't take the lamports (executable, or still below
+        // rent exemption afterwards) would fail the whole settlement — its
+        // share is paid out with the rest of the protocol fee instead.
+        let mut forfeited_referral = 0;
+        if referral > 0 {
+            let referrer = ctx
+                .accounts
+                .referrer
+                .as_ref()
+                .ok_or(AuctionError::ReferrerMismatch)?;
+            if !can_receive(referrer, referral)? {
+                forfeited_referral = referral;
+                referral = 0;
+            }
+        }
+
+        // --- Settler reward: capped bounty carved out of the treasury's share ---
+        let settler_reward = treasury_fee.min(ctx.accounts.config.settler_reward_lamports);
+        let treasury_fee = treasury_fee - settler_reward;
+        let protocol_fee = treasury_fee
+            .checked_add(forfeited_referral)
+            .ok_or(AuctionError::Overflow)?;
+
         system_program::transfer(
             CpiContext::new_with_signer(
                 ctx.accounts.system_program.to_account_info(),
@@ -74,29 +163,83 @@ pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
             seller_proceeds,
         )?;
 
-        if fee > 0 {
+        if protocol_fee > 0 {
+            ctx.accounts.pay_protocol_fee(fee_accounts, protocol_fee, signer_seeds)?;
+        }
+
+        if settler_reward > 0 {
             system_program::transfer(
                 CpiContext::new_with_signer(
                     ctx.accounts.system_program.to_account_info(),
                     system_program::Transfer {
                         from: ctx.accounts.bid_vault.to_account_info(),
-                        to: ctx.accounts.treasury.to_account_info(),
+                        to: ctx.accounts.payer.to_account_info(),
                     },
                     signer_seeds,
                 ),
-                fee,
+                settler_reward,
             )?;
         }
 
-        auction.reserve_met = true;
+        if referral > 0 {
+            let referrer = ctx
+                .accounts
+                .referrer
+                .as_ref()
+                .ok_or(AuctionError::ReferrerMismatch)?;
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.bid_vault.to_account_info(),
+                        to: referrer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                referral,
+            )?;
+        }
 
+        emit!(AuctionSettled {
+            auction_id: auction.auction_id,
+            nft_mint: auction.nft_mint,
+            seller: auction.seller,
+            winner: auction.current_bidder,
+            price: bid,
+            seller_proceeds,
+            protocol_fee,
+            referral,
+            royalties,
+            settler_reward,
+            relist_date: None,
+        });
         msg!(
-            "Settled auction #{}: NFT → {}, {} lamports → seller, {} lamports → treasury",
+            "Settled auction #{}: NFT → {}, {} lamports → seller, {} lamports → fee recipients, {} lamports → referrer, {} lamports → creators, {} lamports → settler",
             auction.auction_id,
             auction.current_bidder.unwrap(),
             seller_proceeds,
-            fee,
+            protocol_fee,
+            referral,
+            royalties,
+            settler_reward,
         );
+    } else if ctx.accounts.slot.auto_relist {
+        // --- No reserve met, seller opted in: relist for a later date ---
+        // Only the seller may pick the date — a keeper-chosen date far in the
+        // future would lock the NFT in escrow until then.
+        let relist_date = match relist_date {
+            Some(date) => {
+                require_keys_eq!(
+                    ctx.accounts.payer.key(),
+                    ctx.accounts.slot.owner,
+                    AuctionError::RelistDateNotAllowed
+                );
+                date
+            }
+            None => next_free_date(now, ctx.remaining_accounts)?,
+        };
+        ctx.accounts.relist(ctx.program_id, relist_date, now)?;
+        return ctx.accounts.close_relisted_slot();
     } else {
         // --- No reserve met: return NFT to seller ---
         let slot = &ctx.accounts.slot;
@@ -120,6 +263,7 @@ pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         );
         token::transfer(nft_return_ctx, 1)?;
 
+        emit!(unsold_event(&ctx.accounts.auction, None));
         msg!(
             "Settled auction #{}: reserve not met, NFT returned to seller {}",
             auction.auction_id,
@@ -127,8 +271,340 @@ pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         );
     }
 
-    auction.settled = true;
-    Ok(())
+    // Both paths above empty the escrow — its rent goes back to the seller.
+    ctx.accounts.close_escrow(&ctx.accounts.seller.to_account_info())
+}
+
+/// Whether `account` can take `amount` lamports: a transfer to an executable
+/// account, or one leaving it below rent exemption, fails the transaction.
+fn can_receive(account: &AccountInfo, amount: u64) -> Result<bool> {
+    let balance = account
+        .lamports()
+        .checked_add(amount)
+        .ok_or(AuctionError::Overflow)?;
+    Ok(!account.executable && Rent::get()?.is_exempt(balance, account.data_len()))
+}
+
+/// First date from tomorrow on without a DateReservation. `booked` must be
+/// the reservations of every date before it, in date order; relist then
+/// creates the returned date's reservation, which fails if it is booked too.
+/// Gives up past SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS booked dates.
+fn next_free_date(now: i64, booked: &[AccountInfo]) -> Result<i64> {
+    require!(
+        booked.len() <= SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS,
+        AuctionError::NoFreeRelistDate
+    );
+    let mut date = (now / 86_400 + 1)
+        .checked_mul(86_400)
+        .ok_or(AuctionError::Overflow)?;
+    for reservation in booked {
+        require_keys_eq!(
+            reservation.key(),
+            DateReservation::address(date),
+            AuctionError::BookedDateMismatch
+        );
+        require!(
+            *reservation.owner == crate::ID && !reservation.data_is_empty(),
+            AuctionError::BookedDateMismatch
+        );
+        date = date.checked_add(86_400).ok_or(AuctionError::Overflow)?;
+    }
+    Ok(date)
+}
+
+/// Settlement event for an auction that did not meet its reserve.
+fn unsold_event(auction: &AuctionState, relist_date: Option<i64>) -> AuctionSettled {
+    AuctionSettled {
+        auction_id: auction.auction_id,
+        nft_mint: auction.nft_mint,
+        seller: auction.seller,
+        winner: None,
+        price: 0,
+        seller_proceeds: 0,
+        protocol_fee: 0,
+        referral: 0,
+        royalties: 0,
+        settler_reward: 0,
+        relist_date,
+    }
+}
+
+impl<'info> SettleAuction<'info> {
+    /// Close the now-empty escrow token account, sending its rent to
+    /// `destination`.
+    fn close_escrow(&self, destination: &AccountInfo<'info>) -> Result<()> {
+        let nft_mint_key = self.nft_mint.key();
+        let scheduled_date_bytes = self.slot.scheduled_date.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SlotRegistration::SEED,
+            nft_mint_key.as_ref(),
+            &scheduled_date_bytes,
+            &[self.slot.bump],
+        ]];
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.escrow_token_account.to_account_info(),
+                destination: destination.clone(),
+                authority: self.slot.to_account_info(),
+            },
+            signer_seeds,
+        ))
+    }
+
+    /// After a relist, close the seller's old escrow and consumed slot to the
+    /// payer. Their rent is what the seller paid at list_slot, and it refunds
+    /// the payer for the new slot and escrow, which the seller gets back when
+    /// those close.
+    fn close_relisted_slot(&self) -> Result<()> {
+        self.close_escrow(&self.payer.to_account_info())?;
+        close_pda_account(&self.slot.to_account_info(), &self.payer.to_account_info())
+    }
+
+    /// Pay the protocol fee out of the bid vault: split across the fee
+    /// distribution table when configured, otherwise all to `treasury`.
+    fn pay_protocol_fee(
+        &self,
+        fee_accounts: &[AccountInfo<'info>],
+        fee: u64,
+        vault_signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        if fee_accounts.is_empty() {
+            return system_program::transfer(
+                CpiContext::new_with_signer(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.bid_vault.to_account_info(),
+                        to: self.treasury.to_account_info(),
+                    },
+                    vault_signer_seeds,
+                ),
+                fee,
+            );
+        }
+
+        for ((recipient, amount), account) in self.config.split_fee(fee).into_iter().zip(fee_accounts) {
+            require_keys_eq!(account.key(), recipient, AuctionError::FeeRecipientMismatch);
+            if amount > 0 {
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.bid_vault.to_account_info(),
+                            to: account.clone(),
+                        },
+                        vault_signer_seeds,
+                    ),
+                    amount,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pay creator royalties out of the bid vault. The royalty is the
+    /// metadata's seller_fee_basis_points (capped per config) of `bid`,
+    /// limited to `available`, and split across verified creators by share.
+    /// Unverified creators' shares stay with the seller, as do the shares of
+    /// creators that can't receive them (see `can_receive`).
+    /// Returns the total lamports paid.
+    fn pay_royalties(
+        &self,
+        creator_accounts: &[AccountInfo<'info>],
+        bid: u64,
+        available: u64,
+        vault_signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let metadata_info = self.nft_metadata.to_account_info();
+        require!(
+            is_metadata_account(&metadata_info, &self.nft_mint.key()),
+            AuctionError::InvalidMetadata
+        );
+        let metadata = parse_metadata(&metadata_info)?;
+
+        let royalty_bps = self.config.effective_royalty_bps(metadata.seller_fee_basis_points);
+        let pool = bid
+            .checked_mul(royalty_bps as u64)
+            .ok_or(AuctionError::Overflow)?
+            / 10_000;
+        let pool = pool.min(available);
+
+        let mut accounts = creator_accounts.iter();
+        let mut paid: u64 = 0;
+        for creator in metadata.creators.iter().filter(|c| c.verified) {
+            let account = accounts.next().ok_or(AuctionError::CreatorMismatch)?;
+            require_keys_eq!(account.key(), creator.address, AuctionError::CreatorMismatch);
+
+            let share = pool
+                .checked_mul(creator.share as u64)
+                .ok_or(AuctionError::Overflow)?
+                / 100;
+            if share > 0 && can_receive(account, share)? {
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        self.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: self.bid_vault.to_account_info(),
+                            to: account.clone(),
+                        },
+                        vault_signer_seeds,
+                    ),
+                    share,
+                )?;
+                paid = paid.checked_add(share).ok_or(AuctionError::Overflow)?;
+            }
+        }
+
+        Ok(paid)
+    }
+
+    /// Re-register an unsold NFT for `relist_date`: books the date, creates
+    /// the new slot PDA and its escrow ATA, then moves the NFT across escrows.
+    /// The payer funds the new accounts, exactly as the seller would have
+    /// with `list_slot`; see `close_relisted_slot` for the refund.
+    fn relist(
+        &self,
+        program_id: &Pubkey,
+        relist_date: i64,
+        now: i64,
+    ) -> Result<()> {
+        require!(relist_date > now, AuctionError::DateInPast);
+        require!(relist_date % 86_400 == 0, AuctionError::InvalidScheduledDate);
+
+        let relist_slot = self
+            .relist_slot
+            .as_ref()
+            .ok_or(AuctionError::RelistDateRequired)?;
+        let relist_escrow = self
+            .relist_escrow_token_account
+            .as_ref()
+            .ok_or(AuctionError::RelistDateRequired)?;
+        let relist_reservation = self
+            .relist_date_reservation
+            .as_ref()
+            .ok_or(AuctionError::RelistDateRequired)?;
+
+        let nft_mint_key = self.nft_mint.key();
+        let relist_date_bytes = relist_date.to_le_bytes();
+        let (relist_slot_key, relist_bump) = Pubkey::find_program_address(
+            &[SlotRegistration::SEED, nft_mint_key.as_ref(), &relist_date_bytes],
+            program_id,
+        );
+        require_keys_eq!(relist_slot.key(), relist_slot_key, AuctionError::RelistSlotMismatch);
+        require!(
+            *relist_slot.owner != *program_id && relist_slot.data_is_empty(),
+            AuctionError::SlotTaken
+        );
+
+        // --- Book the date: fails if another NFT already holds it ---
+        let (reservation_key, reservation_bump) = Pubkey::find_program_address(
+            &[DateReservation::SEED, &relist_date_bytes],
+            program_id,
+        );
+        require_keys_eq!(relist_reservation.key(), reservation_key, AuctionError::RelistSlotMismatch);
+        require!(
+            *relist_reservation.owner != *program_id && relist_reservation.data_is_empty(),
+            AuctionError::SlotTaken
+        );
+        create_pda_account(
+            &self.payer.to_account_info(),
+            &relist_reservation.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + DateReservation::INIT_SPACE,
+            program_id,
+            &[&[DateReservation::SEED, &relist_date_bytes, &[reservation_bump]]],
+        )?;
+        let reservation = DateReservation {
+            scheduled_date: relist_date,
+            nft_mint: nft_mint_key,
+            payer: self.payer.key(),
+            bump: reservation_bump,
+        };
+        reservation.try_serialize(&mut &mut relist_reservation.try_borrow_mut_data()?[..])?;
+
+        // --- Create the new SlotRegistration PDA ---
+        let relist_signer_seeds: &[&[&[u8]]] = &[&[
+            SlotRegistration::SEED,
+            nft_mint_key.as_ref(),
+            &relist_date_bytes,
+            &[relist_bump],
+        ]];
+        create_pda_account(
+            &self.payer.to_account_info(),
+            &relist_slot.to_account_info(),
+            &self.system_program.to_account_info(),
+            8 + SlotRegistration::INIT_SPACE,
+            program_id,
+            relist_signer_seeds,
+        )?;
+
+        let slot = &self.slot;
+        let reserve_price = slot.decayed_reserve(self.config.min_reserve_lamports);
+        let relisted = SlotRegistration {
+            nft_mint: nft_mint_key,
+            owner: slot.owner,
+            scheduled_date: relist_date,
+            reserve_price,
+            escrowed: true,
+            consumed: false,
+            bump: relist_bump,
+            auto_relist: true,
+            reserve_decay_bps: slot.reserve_decay_bps,
+        };
+        relisted.try_serialize(&mut &mut relist_slot.try_borrow_mut_data()?[..])?;
+
+        // --- Create the new escrow ATA, owned by the new slot PDA ---
+        // Idempotent: anyone can create an ATA, so it may already exist.
+        associated_token::create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.payer.to_account_info(),
+                associated_token: relist_escrow.to_account_info(),
+                authority: relist_slot.to_account_info(),
+                mint: self.nft_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+
+        // --- Move the NFT from the old escrow into the new one ---
+        let scheduled_date_bytes = slot.scheduled_date.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            SlotRegistration::SEED,
+            nft_mint_key.as_ref(),
+            &scheduled_date_bytes,
+            &[slot.bump],
+        ]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                Transfer {
+                    from: self.escrow_token_account.to_account_info(),
+                    to: relist_escrow.to_account_info(),
+                    authority: self.slot.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        emit!(SlotListed {
+            nft_mint: nft_mint_key,
+            owner: slot.owner,
+            scheduled_date: relist_date,
+            reserve_price,
+            auto_relist: true,
+            reserve_decay_bps: slot.reserve_decay_bps,
+        });
+        emit!(unsold_event(&self.auction, Some(relist_date)));
+        msg!(
+            "Settled auction #{}: reserve not met, NFT relisted for {} with reserve {} lamports",
+            self.auction.auction_id,
+            relist_date,
+            reserve_price,
+        );
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -140,7 +616,6 @@ pub struct SettleAuction<'info> {
     #[account(
         seeds = [ProgramConfig::SEED],
         bump = config.bump,
-        has_one = treasury,
     )]
     pub config: Account<'info, ProgramConfig>,
 
@@ -152,7 +627,9 @@ pub struct SettleAuction<'info> {
     )]
     pub auction: Account<'info, AuctionState>,
 
+    /// Closed to the payer when the NFT is relisted.
     #[account(
+        mut,
         seeds = [SlotRegistration::SEED, nft_mint.key().as_ref(), &slot.scheduled_date.to_le_bytes()],
         bump = slot.bump,
         constraint = slot.owner == auction.seller @ AuctionError::SellerMismatch,
@@ -162,6 +639,24 @@ pub struct SettleAuction<'info> {
     #[account(constraint = nft_mint.key() == auction.nft_mint @ AuctionError::MintMismatch)]
     pub nft_mint: Account<'info, Mint>,
 
+    /// Reservation of the slot's date, closed here. Slots listed before
+    /// date reservations existed have none; the PDA is passed regardless.
+    /// CHECK: address checked by the seeds, contents by DateReservation::release.
+    #[account(
+        mut,
+        seeds = [DateReservation::SEED, &slot.scheduled_date.to_le_bytes()],
+        bump,
+    )]
+    pub date_reservation: UncheckedAccount<'info>,
+
+    /// CHECK: receives the date reservation's rent; must be the payer it records.
+    #[account(mut)]
+    pub date_reservation_payer: UncheckedAccount<'info>,
+
+    /// Metaplex metadata account — read for creator royalties when enabled.
+    /// CHECK: Validated in instruction via metadata::is_metadata_account.
+    pub nft_metadata: UncheckedAccount<'info>,
+
     /// Escrow token account holding the NFT (owned by slot PDA).
     #[account(
         mut,
@@ -203,15 +698,45 @@ pub struct SettleAuction<'info> {
     /// CHECK: winner identity — validated at runtime against auction.current_bidder.
     #[account(
         mut,
-        constraint = auction.current_bidder.map_or(true, |w| w == winner.key()),
+        constraint = auction.current_bidder.is_none_or(|w| w == winner.key()),
     )]
     pub winner: UncheckedAccount<'info>,
 
-    /// Treasury wallet — validated by config has_one constraint.
-    /// CHECK: validated via config.has_one
-    #[account(mut)]
+    /// Treasury PDA — receives the protocol fee when no fee recipients are set.
+    /// CHECK: validated by the PDA seeds.
+    #[account(
+        mut,
+        seeds = [ProgramConfig::TREASURY_SEED],
+        bump,
+    )]
     pub treasury: UncheckedAccount<'info>,
 
+    /// Referrer credited with the winning bid. Required when the auction has
+    /// a referrer and referral_bps is non-zero.
+    /// CHECK: validated against auction.current_referrer.
+    #[account(
+        mut,
+        constraint = auction.current_referrer == Some(referrer.key()) @ AuctionError::ReferrerMismatch,
+    )]
+    pub referrer: Option<UncheckedAccount<'info>>,
+
+    /// New slot registration for an auto-relisted NFT. Only required when the
+    /// slot opted into auto-relist and reserve was not met.
+    /// CHECK: created in the handler at the PDA derived from relist_date.
+    #[account(mut)]
+    pub relist_slot: Option<UncheckedAccount<'info>>,
+
+    /// Escrow ATA for `relist_slot`. Only required alongside it.
+    /// CHECK: created in the handler via the associated token program,
+    /// which validates the address against (relist_slot, nft_mint).
+    #[account(mut)]
+    pub relist_escrow_token_account: Option<UncheckedAccount<'info>>,
+
+    /// Date reservation for `relist_slot`. Only required alongside it.
+    /// CHECK: created in the handler at the PDA derived from relist_date.
+    #[account(mut)]
+    pub relist_date_reservation: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::TokenAccount;
+use crate::state::{ProgramConfig, GovernanceProposal, ProposalContent, ProposalParamUpdate};
+use crate::errors::AuctionError;
+use crate::metadata::verify_collection_membership;
+use crate::events::ProposalCreated;
+use super::create_proposal::validate_proposal;
+
+/// Called by a Commoner holder to put a proposal on-chain without going
+/// through the proposal manager.
+///
+/// The proposer proves one Commoner like cast_vote does and locks
+/// config.proposal_deposit_lamports in the proposal account. finalize_proposal
+/// refunds it if the vote reaches quorum; otherwise, or if the admin vetoes
+/// the proposal as spam, it goes to the treasury.
+///
+/// With config.proposal_approval_required set, the proposal waits in
+/// STATUS_PENDING_APPROVAL and voting opens on approve_proposal; a rejected
+/// or expired one gets its deposit back through reject_proposal.
+///
+/// The proposal ID is config.next_proposal_id, so the proposal account must
+/// be the PDA for that ID.
+#[allow(clippy::too_many_arguments)]
+pub fn submit_proposal(
+    ctx: Context<SubmitProposal>,
+    title: String,
+    content: ProposalContent,
+    proposal_type: String,
+    treasury_sol: u64,
+    recipient: Pubkey,
+    param_update: Option<ProposalParamUpdate>,
+    duration_secs: i64,
+) -> Result<()> {
+    validate_proposal(&title, &content, &proposal_type, &param_update, duration_secs)?;
+
+    let config = &mut ctx.accounts.config;
+    let proposal_id = config.next_proposal_id;
+    config.next_proposal_id = proposal_id.checked_add(1).ok_or(AuctionError::Overflow)?;
+    let collection = config
+        .commoner_collection
+        .ok_or(AuctionError::CommonerCollectionNotSet)?;
+    verify_collection_membership(
+        &ctx.accounts.proposer_metadata.to_account_info(),
+        &ctx.accounts.proposer_token_account.mint,
+        collection,
+    )?;
+
+    let deposit = config.proposal_deposit_lamports;
+    let approval_required = config.proposal_approval_required;
+    if deposit > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.proposer.to_account_info(),
+                    to: ctx.accounts.proposal.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let (status, ends_at) = if approval_required {
+        (GovernanceProposal::STATUS_PENDING_APPROVAL, 0)
+    } else {
+        let ends_at = now.checked_add(duration_secs).ok_or(AuctionError::Overflow)?;
+        (GovernanceProposal::STATUS_ACTIVE, ends_at)
+    };
+
+    let proposer = ctx.accounts.proposer.key();
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = proposer;
+    proposal.title = title;
+    proposal.set_content(content);
+    proposal.proposal_type = proposal_type;
+    proposal.treasury_sol = treasury_sol;
+    proposal.recipient = recipient;
+    proposal.executed_at = 0;
+    proposal.param_update = param_update;
+    proposal.deposit_lamports = deposit;
+    proposal.voting_duration_secs = duration_secs;
+    proposal.created_at = now;
+    proposal.ends_at = ends_at;
+    proposal.yes = 0;
+    proposal.no = 0;
+    proposal.abstain = 0;
+    proposal.status = status;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(ProposalCreated {
+        proposal_id,
+        proposer,
+        proposal_type: proposal.proposal_type.clone(),
+        treasury_sol,
+        recipient,
+        param_update: proposal.param_update.clone(),
+        ends_at,
+        content_uri: proposal.content_uri.clone(),
+        content_hash: proposal.content_hash,
+        deposit_lamports: deposit,
+    });
+    msg!(
+        "Governance proposal {} submitted by {} with {} lamports deposit",
+        proposal_id,
+        proposer,
+        deposit
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, content: ProposalContent)]
+pub struct SubmitProposal<'info> {
+    /// Commoner holder submitting the proposal. Pays rent and the deposit.
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Proposer's token account holding a Commoner.
+    #[account(
+        constraint = proposer_token_account.owner == proposer.key()
+            && proposer_token_account.amount == 1 @ AuctionError::InvalidCommonerTokenAccount,
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata of the Commoner, verified in the handler.
+    pub proposer_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::space(&content),
+        seeds = [GovernanceProposal::SEED, &config.next_proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, GovernanceProposal, VoteRecord};
+use crate::errors::AuctionError;
+use crate::events::VoteUpdated;
+
+/// Voter replaces their allocation on an active proposal before `ends_at`.
+///
+/// The old split is taken out of the proposal tallies and the new one added.
+/// Weight is what cast_vote proved and isn't re-checked here — the NFT
+/// markers keep those Commoners bound to this record.
+pub fn update_vote(
+    ctx: Context<UpdateVote>,
+    _proposal_id: u64,
+    yes: u64,
+    no: u64,
+    abstain: u64,
+) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let record = &mut ctx.accounts.vote_record;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        proposal.status == GovernanceProposal::STATUS_ACTIVE,
+        AuctionError::ProposalNotActive
+    );
+    require!(now < proposal.ends_at, AuctionError::VotingEnded);
+
+    let total = yes
+        .checked_add(no)
+        .ok_or(AuctionError::Overflow)?
+        .checked_add(abstain)
+        .ok_or(AuctionError::Overflow)?;
+
+    require!(total > 0, AuctionError::NoVotesAllocated);
+    require!(total <= record.weight, AuctionError::AllocationExceedsWeight);
+
+    proposal.yes = proposal.yes.checked_sub(record.yes).ok_or(AuctionError::Overflow)?;
+    proposal.no = proposal.no.checked_sub(record.no).ok_or(AuctionError::Overflow)?;
+    proposal.abstain = proposal.abstain.checked_sub(record.abstain).ok_or(AuctionError::Overflow)?;
+
+    // Ensure global tally cannot exceed the max vote weight.
+    let accumulated = proposal.yes
+        .checked_add(proposal.no)
+        .and_then(|s| s.checked_add(proposal.abstain))
+        .ok_or(AuctionError::Overflow)?;
+    require!(
+        accumulated.checked_add(total).ok_or(AuctionError::Overflow)? <= ctx.accounts.config.max_vote_weight,
+        AuctionError::TallyOverflow
+    );
+
+    proposal.yes = proposal.yes.checked_add(yes).ok_or(AuctionError::Overflow)?;
+    proposal.no = proposal.no.checked_add(no).ok_or(AuctionError::Overflow)?;
+    proposal.abstain = proposal.abstain.checked_add(abstain).ok_or(AuctionError::Overflow)?;
+
+    record.yes = yes;
+    record.no = no;
+    record.abstain = abstain;
+
+    emit!(VoteUpdated {
+        proposal_id: proposal.proposal_id,
+        voter: record.voter,
+        yes,
+        no,
+        abstain,
+    });
+    msg!(
+        "Vote updated: voter={} yes={} no={} abstain={}",
+        record.voter,
+        yes,
+        no,
+        abstain,
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct UpdateVote<'info> {
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        seeds = [VoteRecord::SEED, &proposal_id.to_le_bytes(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
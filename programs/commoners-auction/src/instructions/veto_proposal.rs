@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, GovernanceProposal};
+use crate::errors::AuctionError;
+use crate::events::{ProposalDepositSettled, ProposalVetoed};
+
+/// Admin veto — the only admin override left in governance. Meant for
+/// proposals that are malicious or would break the program, and usable while
+/// awaiting approval, while voting is open or after a proposal passed, but
+/// never to pass one. A deposit still held is forfeited to the treasury.
+pub fn veto_proposal(ctx: Context<VetoProposal>, _proposal_id: u64) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let previous_status = proposal.status;
+    require!(
+        matches!(
+            previous_status,
+            GovernanceProposal::STATUS_ACTIVE
+                | GovernanceProposal::STATUS_PENDING_APPROVAL
+                | GovernanceProposal::STATUS_PASSED
+                | GovernanceProposal::STATUS_QUEUED
+        ),
+        AuctionError::ProposalNotVetoable
+    );
+
+    proposal.status = GovernanceProposal::STATUS_VETOED;
+
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let amount = GovernanceProposal::release_deposit(proposal, &treasury)?;
+    if amount > 0 {
+        emit!(ProposalDepositSettled {
+            proposal_id: proposal.proposal_id,
+            amount,
+            refunded: false,
+        });
+    }
+
+    emit!(ProposalVetoed {
+        proposal_id: proposal.proposal_id,
+        previous_status,
+    });
+    msg!("Proposal {} vetoed by admin", proposal.proposal_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct VetoProposal<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        has_one = admin @ AuctionError::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Program-owned treasury PDA; receives a forfeited deposit.
+    #[account(
+        mut,
+        seeds = [ProgramConfig::TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProgramConfig, GovernanceProposal, NftVoteMarker, VoteRecord};
+use crate::errors::AuctionError;
+use crate::events::VoteWithdrawn;
+
+/// Voter takes their vote back on an active proposal before `ends_at`.
+///
+/// The allocation is removed from the proposal tallies and the VoteRecord is
+/// closed to the voter. Remaining accounts must list every NftVoteMarker PDA
+/// this vote still holds — one per Commoner in the record's weight — which
+/// are closed too, refunding their rent and letting those Commoners vote
+/// again. No marker can outlive the vote that counted it.
+pub fn withdraw_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawVote<'info>>,
+    proposal_id: u64,
+) -> Result<()> {
+    let voter = ctx.accounts.voter.key();
+    require!(
+        ctx.remaining_accounts.len() as u64 == ctx.accounts.vote_record.weight,
+        AuctionError::VoteMarkerMismatch
+    );
+    // A marker closed earlier in the loop no longer deserializes, so each
+    // one is counted once.
+    for info in ctx.remaining_accounts {
+        let marker = Account::<NftVoteMarker>::try_from(info)
+            .map_err(|_| error!(AuctionError::VoteMarkerMismatch))?;
+        require!(
+            marker.proposal_id == proposal_id && marker.voter == voter,
+            AuctionError::VoteMarkerMismatch
+        );
+        marker.close(ctx.accounts.voter.to_account_info())?;
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    let record = &ctx.accounts.vote_record;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        proposal.status == GovernanceProposal::STATUS_ACTIVE,
+        AuctionError::ProposalNotActive
+    );
+    require!(now < proposal.ends_at, AuctionError::VotingEnded);
+
+    proposal.yes = proposal.yes.checked_sub(record.yes).ok_or(AuctionError::Overflow)?;
+    proposal.no = proposal.no.checked_sub(record.no).ok_or(AuctionError::Overflow)?;
+    proposal.abstain = proposal.abstain.checked_sub(record.abstain).ok_or(AuctionError::Overflow)?;
+
+    emit!(VoteWithdrawn {
+        proposal_id,
+        voter,
+        weight: record.weight,
+    });
+    msg!("Vote withdrawn: voter={} proposal={}", voter, proposal_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct WithdrawVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [ProgramConfig::SEED],
+        bump = config.bump,
+        constraint = !config.is_paused(ProgramConfig::PAUSE_GOVERNANCE) @ AuctionError::ProgramPaused,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [GovernanceProposal::SEED, &proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        seeds = [VoteRecord::SEED, &proposal_id.to_le_bytes(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter,
+        close = voter,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
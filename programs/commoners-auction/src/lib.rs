@@ -1,56 +1,92 @@
+// Anchor 0.31's #[program] emits its IDL instructions at the crate root, and
+// they still call the deprecated `AccountInfo::realloc`. A lint level can't be
+// attached to macro output, so it is allowed here and turned back on for
+// every hand-written module, including the program module itself.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
+#[warn(deprecated)]
 pub mod errors;
+#[warn(deprecated)]
+pub mod events;
+#[warn(deprecated)]
 pub mod instructions;
+#[warn(deprecated)]
+pub mod metadata;
+#[warn(deprecated)]
+pub mod pda;
+#[warn(deprecated)]
 pub mod state;
 
 use instructions::*;
-use state::DiscountTier;
+use state::{ParamUpdate, ProposalContent, ProposalParamUpdate};
 
 declare_id!("EWXiRHrYNtMy6wXQsy2oZhops6Dsw5M4GT59Bqb3xPjC");
 
+#[warn(deprecated)]
 #[program]
 pub mod commoners_auction {
     use super::*;
 
     /// One-time setup. Called once by the admin after deployment.
+    /// The signer must be the program's upgrade authority.
     /// Default values:
     ///   fee_bps          = 900  (9%)
     ///   bid_increment_bps = 500  (5%)
     ///   time_buffer_secs = 600  (10 minutes anti-snipe window)
     ///   min_reserve      = 420_000_000 lamports (0.42 SOL)
+    ///   param_update_delay_secs = 172_800 (48 hours)
+    /// Protocol fees go to the program-owned treasury PDA until a fee
+    /// distribution table is configured, then to its recipients.
     pub fn initialize_program(
         ctx: Context<InitializeProgram>,
-        treasury: Pubkey,
         default_fee_bps: u16,
         bid_increment_bps: u16,
         time_buffer_secs: i64,
         min_reserve_lamports: u64,
         required_collection: Option<Pubkey>,
+        param_update_delay_secs: i64,
     ) -> Result<()> {
         instructions::initialize_program::initialize_program(
             ctx,
-            treasury,
             default_fee_bps,
             bid_increment_bps,
             time_buffer_secs,
             min_reserve_lamports,
             required_collection,
+            param_update_delay_secs,
         )
     }
 
     /// Called by an NFT holder to lock their MidEvil into escrow
     /// and reserve a future auction date.
+    /// `auto_relist` keeps an unsold NFT in escrow for another date,
+    /// lowering the reserve by `reserve_decay_bps` each time.
     pub fn list_slot(
         ctx: Context<ListSlot>,
         scheduled_date: i64,
         reserve_price: u64,
+        auto_relist: bool,
+        reserve_decay_bps: u16,
     ) -> Result<()> {
-        instructions::list_slot::list_slot(ctx, scheduled_date, reserve_price)
+        instructions::list_slot::list_slot(
+            ctx,
+            scheduled_date,
+            reserve_price,
+            auto_relist,
+            reserve_decay_bps,
+        )
     }
 
-    /// Called by the backend crank at the start of each auction day
-    /// to open bidding for the scheduled NFT.
+    /// Seller switches automatic relisting on or off for one of their slots.
+    /// Turning it off makes an unsold NFT go back to the seller at settlement.
+    pub fn set_auto_relist(ctx: Context<SetAutoRelist>, auto_relist: bool) -> Result<()> {
+        instructions::set_auto_relist::set_auto_relist(ctx, auto_relist)
+    }
+
+    /// Called by the backend crank (crank_authority) at the start of each
+    /// auction day to open bidding for the scheduled NFT.
     /// `end_time` is the explicit Unix timestamp for auction close —
     /// pass the next midnight UTC so the auction ends deterministically
     /// regardless of when the crank actually runs.
@@ -65,106 +101,308 @@ pub mod commoners_auction {
     /// Called by any wallet to place a bid.
     /// Automatically refunds the previous bidder and extends
     /// the auction if within the anti-sniping window.
-    pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
-        instructions::place_bid::place_bid(ctx, bid_amount)
+    /// `referrer` is the partner frontend credited if this bid wins.
+    pub fn place_bid(
+        ctx: Context<PlaceBid>,
+        bid_amount: u64,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::place_bid::place_bid(ctx, bid_amount, referrer)
     }
 
     /// Permissionless — callable by anyone after auction end_time.
     /// Sends NFT to winner + SOL to seller if reserve met,
     /// or returns NFT to seller if reserve not met.
-    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
-        instructions::settle_auction::settle_auction(ctx)
-    }
-
-    /// Update global config parameters.
-    /// Only the admin (Squads multisig) can call this.
-    pub fn update_params(
-        ctx: Context<UpdateParams>,
-        new_fee_bps: Option<u16>,
-        new_bid_increment_bps: Option<u16>,
-        new_time_buffer_secs: Option<i64>,
-        new_min_reserve_lamports: Option<u64>,
-        new_common_token_mint: Option<Pubkey>,
-        new_discount_tiers: Option<[DiscountTier; 4]>,
+    /// For auto-relist slots, an unsold NFT is instead re-registered for the
+    /// first free date within SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS of
+    /// tomorrow, or for `relist_date` when the seller settles. The payer's
+    /// rent for the new slot and escrow is refunded from the old ones.
+    /// Remaining accounts when sold: the fee distribution recipients (if
+    /// configured), then — with royalties enabled — the verified creators.
+    /// When relisting without `relist_date`: the DateReservations of the
+    /// booked dates from tomorrow up to the first free one.
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+        relist_date: Option<i64>,
     ) -> Result<()> {
-        instructions::update_params::update_params(
-            ctx,
-            new_fee_bps,
-            new_bid_increment_bps,
-            new_time_buffer_secs,
-            new_min_reserve_lamports,
-            new_common_token_mint,
-            new_discount_tiers,
-        )
+        instructions::settle_auction::settle_auction(ctx, relist_date)
+    }
+
+    /// Admin queues a config change behind the governed timelock.
+    /// Applied values stay on ProgramConfig; pending ones on PendingParamUpdate.
+    pub fn queue_param_update(ctx: Context<QueueParamUpdate>, update: ParamUpdate) -> Result<()> {
+        instructions::queue_param_update::queue_param_update(ctx, update)
+    }
+
+    /// Permissionless — applies the queued config change after its eta.
+    pub fn execute_param_update(ctx: Context<ExecuteParamUpdate>) -> Result<()> {
+        instructions::execute_param_update::execute_param_update(ctx)
+    }
+
+    /// Admin cancels the queued config change.
+    pub fn cancel_param_update(ctx: Context<CancelParamUpdate>) -> Result<()> {
+        instructions::cancel_param_update::cancel_param_update(ctx)
     }
 
     // ── Governance ───────────────────────────────────────────────────────────
 
-    /// Admin creates an on-chain governance proposal after off-chain review.
-    /// Opens the voting window immediately for `duration_secs`.
+    /// Proposal manager creates an on-chain governance proposal after off-chain review.
+    /// Opens the voting window immediately for `duration_secs`, numbered from
+    /// config.next_proposal_id like submitted proposals.
+    /// `content` is an inline description or a hosted document's URI and SHA-256.
+    /// `param_update` makes it a parameter proposal, applied on execution —
+    /// limited to the fee, bid increment, time buffer, min reserve, discount
+    /// tiers, required collection and max vote weight.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_proposal(
         ctx: Context<CreateProposal>,
-        proposal_id: u64,
         proposer: Pubkey,
         title: String,
-        description: String,
+        content: ProposalContent,
         proposal_type: String,
         treasury_sol: u64,
+        recipient: Pubkey,
+        param_update: Option<ProposalParamUpdate>,
         duration_secs: i64,
     ) -> Result<()> {
         instructions::create_proposal::create_proposal(
             ctx,
-            proposal_id,
             proposer,
             title,
-            description,
+            content,
+            proposal_type,
+            treasury_sol,
+            recipient,
+            param_update,
+            duration_secs,
+        )
+    }
+
+    /// Commoner holder submits a proposal directly, locking
+    /// config.proposal_deposit_lamports until it is finalized, rejected or
+    /// vetoed. Voting opens immediately unless config.proposal_approval_required.
+    /// The proposal takes the ID config.next_proposal_id.
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_proposal(
+        ctx: Context<SubmitProposal>,
+        title: String,
+        content: ProposalContent,
+        proposal_type: String,
+        treasury_sol: u64,
+        recipient: Pubkey,
+        param_update: Option<ProposalParamUpdate>,
+        duration_secs: i64,
+    ) -> Result<()> {
+        instructions::submit_proposal::submit_proposal(
+            ctx,
+            title,
+            content,
             proposal_type,
             treasury_sol,
+            recipient,
+            param_update,
             duration_secs,
         )
     }
 
+    /// Proposal manager opens voting on a submitted proposal.
+    pub fn approve_proposal(ctx: Context<ApproveProposal>, proposal_id: u64) -> Result<()> {
+        instructions::approve_proposal::approve_proposal(ctx, proposal_id)
+    }
+
+    /// Proposal manager turns down a submitted proposal awaiting approval —
+    /// or anyone expires it after the approval window — refunding the deposit.
+    pub fn reject_proposal(ctx: Context<RejectProposal>, proposal_id: u64) -> Result<()> {
+        instructions::reject_proposal::reject_proposal(ctx, proposal_id)
+    }
+
     /// Commoner NFT holder casts a split vote on an active proposal.
-    /// Requires voter + admin co-signature (admin verified NFT holdings off-chain).
-    pub fn cast_vote(
-        ctx: Context<CastVote>,
+    /// Weight is the number of Commoners proven on-chain.
+    /// Remaining accounts: `delegation_count` Delegation PDAs naming the voter,
+    /// then (token account, metadata, vote marker) triples, one per Commoner
+    /// held by the voter or a delegating holder. Each NFT votes at most once
+    /// per proposal. Between the two, `overridden_count` VoteRecords of
+    /// delegates whose earlier vote counted the voter's own NFTs — the
+    /// holder's vote takes those NFTs over. Calling again adds more NFTs
+    /// and allocation to the same vote.
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
         proposal_id: u64,
-        weight: u64,
         yes: u64,
         no: u64,
         abstain: u64,
+        delegation_count: u8,
+        overridden_count: u8,
     ) -> Result<()> {
-        instructions::cast_vote::cast_vote(ctx, proposal_id, weight, yes, no, abstain)
+        instructions::cast_vote::cast_vote(
+            ctx,
+            proposal_id,
+            yes,
+            no,
+            abstain,
+            delegation_count,
+            overridden_count,
+        )
     }
 
-    /// Admin finalizes a proposal after the voting window closes.
-    /// Status: 1 = passed, 2 = failed, 3 = queued (awaiting treasury execution).
-    pub fn finalize_proposal(
-        ctx: Context<FinalizeProposal>,
+    /// Voter changes their yes/no/abstain split before voting ends.
+    pub fn update_vote(
+        ctx: Context<UpdateVote>,
         proposal_id: u64,
-        status: u8,
+        yes: u64,
+        no: u64,
+        abstain: u64,
     ) -> Result<()> {
-        instructions::finalize_proposal::finalize_proposal(ctx, proposal_id, status)
+        instructions::update_vote::update_vote(ctx, proposal_id, yes, no, abstain)
     }
 
-    /// Admin closes a stale auction (ended 3+ days ago, still unsettled).
-    /// Returns the escrowed NFT to the original seller.
+    /// Voter withdraws their vote before voting ends, closing the record.
+    /// Remaining accounts: every NFT marker this vote holds, closed as well.
+    pub fn withdraw_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawVote<'info>>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        instructions::withdraw_vote::withdraw_vote(ctx, proposal_id)
+    }
+
+    /// Holder delegates their Commoners' votes to `delegate` until revoked.
+    pub fn delegate_votes(ctx: Context<DelegateVotes>, delegate: Pubkey) -> Result<()> {
+        instructions::delegate_votes::delegate_votes(ctx, delegate)
+    }
+
+    /// Holder revokes their delegation, recovering its rent.
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::revoke_delegation::revoke_delegation(ctx)
+    }
+
+    /// Permissionless — finalizes a proposal after the voting window closes,
+    /// marking it passed or failed by the configured quorum and approval threshold,
+    /// and settles any proposal deposit.
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>, proposal_id: u64) -> Result<()> {
+        instructions::finalize_proposal::finalize_proposal(ctx, proposal_id)
+    }
+
+    /// Permissionless — pays a passed proposal's treasury_sol from the treasury
+    /// PDA to its recipient and applies its param_update, once
+    /// config.execution_delay_secs has passed since voting ended.
+    /// Runs at most once per proposal.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+        instructions::execute_proposal::execute_proposal(ctx, proposal_id)
+    }
+
+    /// Admin vetoes a pending, active or passed proposal, forfeiting any
+    /// deposit it still holds to the treasury.
+    pub fn veto_proposal(ctx: Context<VetoProposal>, proposal_id: u64) -> Result<()> {
+        instructions::veto_proposal::veto_proposal(ctx, proposal_id)
+    }
+
+    /// Permissionless — closes a stale auction (ended 3+ days ago, still
+    /// unsettled). Returns the escrowed NFT to the original seller and
+    /// refunds the standing bid; the keeper reward comes out of the escrow's
+    /// rent.
     pub fn close_stale_auction(ctx: Context<CloseStaleAuction>) -> Result<()> {
         instructions::close_stale_auction::close_stale_auction(ctx)
     }
 
-    /// Admin sets (or clears) the required NFT collection for list_slot.
-    /// Set to the MidEvils collection mint before mainnet launch.
-    pub fn set_required_collection(
-        ctx: Context<SetRequiredCollection>,
-        required_collection: Option<Pubkey>,
+    /// Admin cancels an auction that has no bids yet.
+    /// Returns the escrowed NFT to the original seller.
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        instructions::cancel_auction::cancel_auction(ctx)
+    }
+
+    /// Crank closes a settled, stale-closed or cancelled auction together
+    /// with its bid log, recovering the auction's rent; the log's goes back
+    /// to whoever paid it. A standing bid an unsold auction
+    /// never refunded goes back to its bidder; lamports sent to the bid vault
+    /// after the auction finished are swept to the crank.
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        instructions::close_auction::close_auction(ctx)
+    }
+
+    /// Permissionless — returns the NFT from a slot whose date passed 3+ days
+    /// ago without an auction being created, closing the slot. The keeper
+    /// reward comes out of the escrow's rent.
+    pub fn reclaim_expired_slot(ctx: Context<ReclaimExpiredSlot>) -> Result<()> {
+        instructions::reclaim_expired_slot::reclaim_expired_slot(ctx)
+    }
+
+    // ── Offers ───────────────────────────────────────────────────────────────
+
+    /// Buyer escrows a standing SOL offer on any NFT from the required
+    /// collection, scheduled for auction or not.
+    pub fn make_offer(ctx: Context<MakeOffer>, amount: u64) -> Result<()> {
+        instructions::make_offer::make_offer(ctx, amount)
+    }
+
+    /// Buyer withdraws their offer; escrowed lamports and rent are returned.
+    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+        instructions::cancel_offer::cancel_offer(ctx)
+    }
+
+    /// Holder accepts an offer: NFT → buyer, SOL → holder (minus fee), fee → treasury.
+    /// `expected_amount` guards against the offer being replaced with a lower one.
+    /// Remaining accounts: the fee distribution recipients, if configured.
+    pub fn accept_offer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptOffer<'info>>,
+        expected_amount: u64,
     ) -> Result<()> {
-        instructions::set_required_collection::set_required_collection(ctx, required_collection)
+        instructions::accept_offer::accept_offer(ctx, expected_amount)
+    }
+
+    /// Admin nominates a successor (None cancels). Takes effect only once
+    /// the nominee calls accept_admin.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Option<Pubkey>) -> Result<()> {
+        instructions::propose_admin::propose_admin(ctx, new_admin)
     }
 
-    /// One-time migration: extends ProgramConfig to add required_collection field.
-    /// Safe to call multiple times — realloc to current size is a no-op.
+    /// Nominated admin accepts the handoff and becomes admin.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin(ctx)
+    }
+
+    /// Admin sets the emergency pause bitflags (ProgramConfig::PAUSE_*).
+    /// Settlement and refund paths stay available while paused.
+    pub fn set_pause(ctx: Context<SetPause>, paused: u8) -> Result<()> {
+        instructions::set_pause::set_pause(ctx, paused)
+    }
+
+    /// Admin assigns the crank and proposal manager roles.
+    pub fn set_roles(
+        ctx: Context<SetRoles>,
+        crank_authority: Pubkey,
+        proposal_manager: Pubkey,
+    ) -> Result<()> {
+        instructions::set_roles::set_roles(ctx, crank_authority, proposal_manager)
+    }
+
+    /// Admin upgrades ProgramConfig from any earlier layout version to the
+    /// current one, one registered upgrade at a time, giving new fields their
+    /// defaults.
+    /// Safe to call multiple times — a current account is left unchanged.
     pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
         instructions::migrate_config::migrate_config(ctx)
     }
+
+    /// Permissionless — appends an empty referrer and the lifecycle status,
+    /// derived from the legacy flags and dates, to an AuctionState in the
+    /// original layout.
+    pub fn migrate_auction(ctx: Context<MigrateAuction>, auction_id: u64) -> Result<()> {
+        instructions::migrate_auction::migrate_auction(ctx, auction_id)
+    }
+
+    /// Permissionless — appends the auto-relist fields, switched off, to a
+    /// SlotRegistration created before they existed.
+    pub fn migrate_slot(
+        ctx: Context<MigrateSlot>,
+        nft_mint: Pubkey,
+        scheduled_date: i64,
+    ) -> Result<()> {
+        instructions::migrate_slot::migrate_slot(ctx, nft_mint, scheduled_date)
+    }
+
+    /// Permissionless — grows a GovernanceProposal created before the content
+    /// URI and hash were appended, so it decodes as an inline proposal.
+    pub fn migrate_proposal(ctx: Context<MigrateProposal>, proposal_id: u64) -> Result<()> {
+        instructions::migrate_proposal::migrate_proposal(ctx, proposal_id)
+    }
 }
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use borsh::BorshDeserialize;
+use crate::errors::AuctionError;
+
+/// Metaplex Token Metadata program ID.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// The parts of a Metaplex metadata account this program acts on.
+pub struct NftMetadata {
+    /// Royalty rate requested by the creators, in basis points.
+    pub seller_fee_basis_points: u16,
+    /// Creator list in metadata order. Shares sum to 100.
+    pub creators: Vec<MetadataCreator>,
+    pub collection: Option<MetadataCollection>,
+}
+
+#[derive(BorshDeserialize)]
+pub struct MetadataCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    /// Percentage (0–100) of the royalty owed to this creator.
+    pub share: u8,
+}
+
+#[derive(BorshDeserialize)]
+pub struct MetadataCollection {
+    pub verified: bool,
+    pub key: Pubkey,
+}
+
+/// Whether `metadata_info` is the genuine Metaplex metadata account for
+/// `nft_mint`: the expected PDA, owned by the Metaplex program.
+pub fn is_metadata_account(metadata_info: &AccountInfo, nft_mint: &Pubkey) -> bool {
+    let tmid = TOKEN_METADATA_PROGRAM_ID;
+    let (metadata_pda, _) = Pubkey::find_program_address(
+        &[b"metadata", tmid.as_ref(), nft_mint.as_ref()],
+        &tmid,
+    );
+    metadata_info.key() == metadata_pda && *metadata_info.owner == tmid
+}
+
+/// Verify that `metadata_info` is the genuine Metaplex metadata account for
+/// `nft_mint` and that the NFT is a verified member of `required_collection`.
+pub fn verify_collection_membership(
+    metadata_info: &AccountInfo,
+    nft_mint: &Pubkey,
+    required_collection: Pubkey,
+) -> Result<()> {
+    require!(
+        is_metadata_account(metadata_info, nft_mint),
+        AuctionError::CollectionNotVerified
+    );
+    verify_nft_collection(metadata_info, required_collection)
+}
+
+/// Verify that the NFT belongs to the required Metaplex collection.
+///
+/// Checks, on top of `parse_metadata`:
+///   - collection.verified == true
+///   - collection.key == required_collection
+pub fn verify_nft_collection(
+    metadata_info: &AccountInfo,
+    required_collection: Pubkey,
+) -> Result<()> {
+    let metadata = parse_metadata(metadata_info)
+        .map_err(|_| error!(AuctionError::CollectionNotVerified))?;
+
+    let col = metadata
+        .collection
+        .ok_or_else(|| error!(AuctionError::CollectionNotVerified))?;
+    require!(col.verified, AuctionError::CollectionNotVerified);
+    require!(col.key == required_collection, AuctionError::CollectionMismatch);
+
+    Ok(())
+}
+
+/// Deserialize a Metaplex metadata account (borsh v0.10), requiring
+/// key == 4 (MetadataV1). The caller is responsible for checking that the
+/// account is the metadata PDA for the expected mint.
+///
+/// Metaplex on-chain layout (borsh-encoded):
+///   key: u8, update_authority: [u8;32], mint: [u8;32],
+///   name: String, symbol: String, uri: String,
+///   seller_fee_basis_points: u16,
+///   creators: Option<Vec<{addr:[u8;32], verified:bool, share:u8}>>,
+///   primary_sale_happened: bool, is_mutable: bool,
+///   edition_nonce: Option<u8>, token_standard: Option<u8>,
+///   collection: Option<{verified:bool, key:[u8;32]}>
+pub fn parse_metadata(metadata_info: &AccountInfo) -> Result<NftMetadata> {
+    let data = metadata_info.try_borrow_data()?;
+    let buf = &mut &data[..];
+
+    let key: u8 = u8::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?;
+    require!(key == 4, AuctionError::InvalidMetadata);
+    let _: [u8; 32] = <[u8; 32]>::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // update_authority
+    let _: [u8; 32] = <[u8; 32]>::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // mint
+    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // name
+    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // symbol
+    let _: String = String::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // uri
+    let seller_fee_basis_points: u16 =
+        u16::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?;
+    let creators: Option<Vec<MetadataCreator>> = Option::<Vec<MetadataCreator>>::deserialize(buf)
+        .map_err(|_| error!(AuctionError::InvalidMetadata))?;
+    let _: bool = bool::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // primary_sale_happened
+    let _: bool = bool::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // is_mutable
+    let _: Option<u8> = Option::<u8>::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // edition_nonce
+    let _: Option<u8> = Option::<u8>::deserialize(buf).map_err(|_| error!(AuctionError::InvalidMetadata))?; // token_standard
+    let collection: Option<MetadataCollection> = Option::<MetadataCollection>::deserialize(buf)
+        .map_err(|_| error!(AuctionError::InvalidMetadata))?;
+
+    Ok(NftMetadata {
+        seller_fee_basis_points,
+        creators: creators.unwrap_or_default(),
+        collection,
+    })
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount};
+use crate::state::AuctionState;
+
+/// Create a program-owned PDA at `account`, paid by `payer`.
+///
+/// Anyone can send lamports to an address before it exists, and
+/// create_account fails on a funded address. So a pre-funded PDA is topped up
+/// to rent exemption, then allocated and assigned separately.
+/// The caller checks that `account` is the expected, still unowned PDA.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount { from: payer.clone(), to: account.clone() },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer.clone(), to: account.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
+/// Close a program-owned account the way Anchor's `close` constraint does:
+/// move its lamports to `destination`, hand it back to the system program
+/// and free its data.
+pub fn close_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(crate::errors::AuctionError::Overflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+    Ok(())
+}
+
+/// Refund an auction's standing bid from its bid vault and clear it, so a
+/// terminal auction that didn't sell only keeps a bidder while it still owes
+/// them. Returns the refunded bidder and amount — None and zero without bids.
+/// The caller checks that `bidder` is auction.current_bidder.
+pub fn refund_standing_bid<'info>(
+    auction: &mut AuctionState,
+    bid_vault: &AccountInfo<'info>,
+    vault_bump: u8,
+    bidder: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(Option<Pubkey>, u64)> {
+    let Some(refunded_bidder) = auction.current_bidder else {
+        return Ok((None, 0));
+    };
+    let refund = auction.current_bid;
+    if refund > 0 {
+        let auction_id_bytes = auction.auction_id.to_le_bytes();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::Transfer { from: bid_vault.clone(), to: bidder.clone() },
+                &[&[b"bid-vault", &auction_id_bytes, &[vault_bump]]],
+            ),
+            refund,
+        )?;
+    }
+    auction.current_bid = 0;
+    auction.current_bidder = None;
+    auction.current_referrer = None;
+    Ok((Some(refunded_bidder), refund))
+}
+
+/// Close an emptied escrow token account, paying up to `reward` of its rent
+/// to `keeper` and the rest back to `owner`, who paid it at list_slot.
+/// `authority` is the slot PDA that owns the escrow, signing with
+/// `signer_seeds`. Returns the reward paid — none when the owner cranks.
+#[allow(clippy::too_many_arguments)]
+pub fn close_escrow_paying_keeper<'info>(
+    escrow: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    keeper: &AccountInfo<'info>,
+    reward: u64,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let rent = escrow.lamports();
+    let reward = if keeper.key() == owner.key() { 0 } else { reward.min(rent) };
+    // The keeper signs, so the escrow closes to them and they forward the
+    // owner's part.
+    token::close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: escrow.clone(),
+            destination: keeper.clone(),
+            authority: authority.clone(),
+        },
+        signer_seeds,
+    ))?;
+    if rent > reward && keeper.key() != owner.key() {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: keeper.clone(), to: owner.clone() },
+            ),
+            rent - reward,
+        )?;
+    }
+    Ok(reward)
+}
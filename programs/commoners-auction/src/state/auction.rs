@@ -1,4 +1,51 @@
 use anchor_lang::prelude::*;
+use crate::errors::AuctionError;
+
+/// Lifecycle of an auction.
+///
+/// Scheduled → Live → Ended are time-driven: the stored value may lag the
+/// clock, so read it through AuctionState::status_at. The remaining states
+/// are terminal and only reached through AuctionState::transition.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub enum AuctionStatus {
+    /// Created, bidding not yet open.
+    #[default]
+    Scheduled,
+    /// Accepting bids.
+    Live,
+    /// Past end_time, awaiting settlement.
+    Ended,
+    /// Settled with the reserve met — NFT to winner, proceeds paid out.
+    SettledSold,
+    /// Settled without a winning bid — NFT returned or relisted.
+    SettledUnsold,
+    /// Never settled; NFT returned by close_stale_auction.
+    ClosedStale,
+    /// Cancelled by the admin before any bid.
+    Cancelled,
+}
+
+impl AuctionStatus {
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            Self::SettledSold | Self::SettledUnsold | Self::ClosedStale | Self::Cancelled
+        )
+    }
+
+    /// The instruction-driven transitions. Time-driven ones are applied by
+    /// AuctionState::status_at.
+    fn can_transition_to(self, to: Self) -> bool {
+        matches!(
+            (self, to),
+            (Self::Ended, Self::SettledSold)
+                | (Self::Ended, Self::SettledUnsold)
+                | (Self::Ended, Self::ClosedStale)
+                | (Self::Scheduled, Self::Cancelled)
+                | (Self::Live, Self::Cancelled)
+        )
+    }
+}
 
 /// Live auction state for a single day's auction.
 #[account]
@@ -24,33 +71,83 @@ pub struct AuctionState {
     pub current_bid: u64,
 
     /// Wallet that placed the current highest bid.
-    /// Refunded automatically when outbid.
+    /// Refunded automatically when outbid. Cleared, with the bid, once it is
+    /// refunded by an unsold close.
     pub current_bidder: Option<Pubkey>,
 
     /// Fee bps applied at settlement (resolved from seller's COMMON balance).
     pub fee_bps: u16,
 
-    /// Whether this auction has been settled.
+    /// Whether this auction reached a terminal status.
+    /// Mirrors `status` for clients that predate it.
     pub settled: bool,
 
-    /// Whether the reserve price was met at settlement.
+    /// Whether the auction settled as sold. Mirrors `status`.
     pub reserve_met: bool,
 
     /// Auction sequence number (day index, 1-based).
     pub auction_id: u64,
 
     pub bump: u8,
+
+    /// Frontend/partner credited with the current highest bid.
+    /// Receives referral_bps of the protocol fee at settlement.
+    pub current_referrer: Option<Pubkey>,
+
+    /// Lifecycle status as last written. See status_at for the current one.
+    pub status: AuctionStatus,
 }
 
 impl AuctionState {
     pub const SEED: &'static [u8] = b"auction";
 
-    pub fn is_active(&self, now: i64) -> bool {
-        now >= self.start_time && now < self.end_time && !self.settled
+    /// Current status, applying the time-driven transitions to the stored one.
+    pub fn status_at(&self, now: i64) -> AuctionStatus {
+        match self.status {
+            AuctionStatus::Scheduled | AuctionStatus::Live => {
+                if now >= self.end_time {
+                    AuctionStatus::Ended
+                } else if now >= self.start_time {
+                    AuctionStatus::Live
+                } else {
+                    AuctionStatus::Scheduled
+                }
+            }
+            status => status,
+        }
     }
 
-    pub fn is_ended(&self, now: i64) -> bool {
-        now >= self.end_time
+    /// Require the auction to be accepting bids, persisting the
+    /// time-driven status.
+    pub fn require_live(&mut self, now: i64) -> Result<()> {
+        let status = self.status_at(now);
+        match status {
+            AuctionStatus::Live => {}
+            AuctionStatus::Scheduled => return err!(AuctionError::AuctionNotStarted),
+            AuctionStatus::Ended => return err!(AuctionError::AuctionEnded),
+            _ => return err!(AuctionError::AlreadySettled),
+        }
+        self.status = status;
+        Ok(())
+    }
+
+    /// Move to `to`, rejecting any transition not allowed from the current
+    /// status. Every instruction that ends an auction goes through here.
+    pub fn transition(&mut self, to: AuctionStatus, now: i64) -> Result<()> {
+        let from = self.status_at(now);
+        require!(!from.is_terminal(), AuctionError::AlreadySettled);
+        if matches!(
+            to,
+            AuctionStatus::SettledSold | AuctionStatus::SettledUnsold | AuctionStatus::ClosedStale
+        ) {
+            require!(from == AuctionStatus::Ended, AuctionError::AuctionNotEnded);
+        }
+        require!(from.can_transition_to(to), AuctionError::InvalidStatusTransition);
+
+        self.status = to;
+        self.settled = to.is_terminal();
+        self.reserve_met = to == AuctionStatus::SettledSold;
+        Ok(())
     }
 
     /// Compute minimum next bid given current bid and increment bps.
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Number of bids kept per auction. Older entries are overwritten once full.
+pub const MAX_LOGGED_BIDS: usize = 64;
+
+/// One accepted bid.
+#[zero_copy]
+pub struct BidEntry {
+    pub bidder: Pubkey,
+    /// Bid amount in lamports.
+    pub amount: u64,
+    /// Unix timestamp when the bid was placed.
+    pub timestamp: i64,
+    /// 1 if this bid triggered the anti-snipe extension.
+    pub caused_extension: u8,
+    pub _padding: [u8; 7],
+}
+
+/// Bid history for one auction, appended by place_bid.
+/// A ring buffer: the last MAX_LOGGED_BIDS bids are kept, and `total_bids`
+/// counts every bid ever placed. Closed together with the auction, refunding
+/// its rent to `payer`.
+/// PDA seeds: ["bid-log", auction_id]
+#[account(zero_copy)]
+pub struct BidLog {
+    pub auction_id: u64,
+
+    /// Bids placed so far. The next entry is written at
+    /// `total_bids % MAX_LOGGED_BIDS`.
+    pub total_bids: u64,
+
+    /// Wallet that paid the rent — the crank at create_auction, or the first
+    /// bidder on an auction created before bid logs.
+    pub payer: Pubkey,
+
+    pub entries: [BidEntry; MAX_LOGGED_BIDS],
+}
+
+impl BidLog {
+    pub const SEED: &'static [u8] = b"bid-log";
+    pub const SPACE: usize = 8 + std::mem::size_of::<BidLog>();
+
+    pub fn append(&mut self, bidder: Pubkey, amount: u64, timestamp: i64, caused_extension: bool) {
+        let index = (self.total_bids % MAX_LOGGED_BIDS as u64) as usize;
+        self.entries[index] = BidEntry {
+            bidder,
+            amount,
+            timestamp,
+            caused_extension: caused_extension as u8,
+            _padding: [0; 7],
+        };
+        self.total_bids += 1;
+    }
+}
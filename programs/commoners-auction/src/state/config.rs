@@ -1,15 +1,25 @@
 use anchor_lang::prelude::*;
+use crate::errors::AuctionError;
 
 /// Global program configuration. One per deployment, stored at a PDA.
 /// Controlled by admin (Squads multisig in production).
+///
+/// Layout is versioned: `admin` and `version` always lead, so migrate_config
+/// can authenticate and identify any stored layout from raw bytes.
+/// Every layout change — appends included — bumps VERSION and registers an
+/// upgrade from the layout it replaces in migrate_config.
+///
+/// The treasury is not stored: it is the program-owned PDA at
+/// treasury_address(), which execute_proposal spends from, so it can't be
+/// repointed. Protocol fees are rerouted through `fee_recipients` instead.
 #[account]
 #[derive(InitSpace)]
 pub struct ProgramConfig {
     /// Admin authority — Squads multisig in production.
     pub admin: Pubkey,
 
-    /// SubDAO treasury wallet that receives auction fees.
-    pub treasury: Pubkey,
+    /// Layout version of this account. See ProgramConfig::VERSION.
+    pub version: u8,
 
     /// Default auction fee in basis points (900 = 9%).
     pub default_fee_bps: u16,
@@ -40,8 +50,84 @@ pub struct ProgramConfig {
     /// Metaplex metadata and rejects any mint not from this collection.
     /// Set to the MidEvils collection mint before mainnet launch.
     /// None on devnet allows testing with arbitrary mints.
-    /// MUST be last field — allows zero-byte realloc migration from pre-collection accounts.
     pub required_collection: Option<Pubkey>,
+
+    /// When true, settle_auction pays verified creators the royalty in the
+    /// NFT's Metaplex metadata out of the winning bid, before the seller.
+    pub royalties_enabled: bool,
+
+    /// Upper bound on the royalty rate honoured at settlement, in basis points.
+    /// None honours the metadata's seller_fee_basis_points as-is.
+    pub royalty_cap_bps: Option<u16>,
+
+    /// Share of the protocol fee paid to the winning bid's referrer,
+    /// in basis points of the fee (2000 = 20% of the fee). Zero disables.
+    pub referral_bps: u16,
+
+    /// Protocol fee distribution table: each entry receives `bps` of the fee.
+    /// Entries with bps == 0 are unused. Used entries sum to 10_000.
+    /// An empty table sends the whole fee to the treasury PDA. Forfeited referral
+    /// shares follow the same route as the rest of the fee.
+    pub fee_recipients: [FeeRecipient; 4],
+
+    /// Maximum bounty, in lamports, paid to whoever cranks a successful
+    /// settle_auction. Carved out of the treasury's share of the fee.
+    /// At most MAX_SETTLER_REWARD_LAMPORTS.
+    pub settler_reward_lamports: u64,
+
+    /// Admin nominated via propose_admin, awaiting accept_admin.
+    pub pending_admin: Option<Pubkey>,
+
+    /// Emergency pause bitflags (PAUSE_* constants). A set bit halts that
+    /// capability; settlement, stale closes, reclaims and offer cancellation
+    /// are never paused so users can always get their assets out.
+    pub paused: u8,
+
+    /// Hot key used by the daily crank to open auctions (create_auction).
+    pub crank_authority: Pubkey,
+
+    /// Key that creates governance proposals.
+    pub proposal_manager: Pubkey,
+
+    /// Timelock between queue_param_update and execute_param_update, in seconds.
+    pub param_update_delay_secs: i64,
+
+    /// Commoner collection mint. cast_vote counts only NFTs verified as
+    /// members of it; voting is closed while unset.
+    pub commoner_collection: Option<Pubkey>,
+
+    /// Share of max_vote_weight, in bps, that must vote (abstain
+    /// included) for a proposal to be valid.
+    pub quorum_bps: u16,
+
+    /// Share of yes + no votes, in bps, that yes must exceed to pass.
+    pub approval_threshold_bps: u16,
+
+    /// Seconds after voting ends before a passed proposal can be executed.
+    pub execution_delay_secs: i64,
+
+    /// Lamports a holder locks in their proposal when calling submit_proposal.
+    /// Refunded if the proposal reaches quorum, otherwise sent to the treasury.
+    pub proposal_deposit_lamports: u64,
+
+    /// When true, submitted proposals wait for approve_proposal before
+    /// voting opens.
+    pub proposal_approval_required: bool,
+
+    /// ID given to the next created or submitted proposal, so neither the
+    /// proposal manager nor holders pick IDs.
+    pub next_proposal_id: u64,
+
+    /// Total voting weight in existence — the Commoner supply while weight is
+    /// one vote per NFT. Caps a single vote and a proposal's cumulative
+    /// tally, and is the base quorum_bps is measured against.
+    pub max_vote_weight: u64,
+
+    /// Bounty, in lamports, paid to whoever cranks a stale close or an
+    /// expired slot reclaim, out of the rent of the escrow it closes — the
+    /// rest of that rent still goes back to the seller.
+    /// At most MAX_KEEPER_REWARD_LAMPORTS. Zero disables.
+    pub keeper_reward_lamports: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
@@ -53,9 +139,97 @@ pub struct DiscountTier {
     pub fee_bps: u16,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct FeeRecipient {
+    /// Wallet receiving this share of the protocol fee.
+    pub recipient: Pubkey,
+    /// Share of the protocol fee in basis points.
+    /// Zero means this entry is unused.
+    pub bps: u16,
+}
+
+impl FeeRecipient {
+    /// Check a fee distribution table: used entries (bps > 0) sum to exactly
+    /// 10_000 — or none are used — and name distinct, non-default wallets.
+    pub fn validate_table(table: &[FeeRecipient; 4]) -> Result<()> {
+        let total: u32 = table.iter().map(|r| r.bps as u32).sum();
+        require!(total == 0 || total == 10_000, AuctionError::InvalidFeeSplit);
+
+        let used: Vec<&Pubkey> = table.iter().filter(|r| r.bps > 0).map(|r| &r.recipient).collect();
+        for (i, recipient) in used.iter().enumerate() {
+            require!(**recipient != Pubkey::default(), AuctionError::InvalidFeeRecipient);
+            require!(!used[..i].contains(recipient), AuctionError::InvalidFeeRecipient);
+        }
+        Ok(())
+    }
+}
+
 impl ProgramConfig {
     pub const SEED: &'static [u8] = b"program-config";
 
+    /// Current layout version written by initialize_program and migrate_config.
+    ///   1 — original unversioned layout, ending at `bump` or, once
+    ///       extended for it, `required_collection`
+    ///   2 — `version` inserted after `admin`, `treasury` removed for the
+    ///       treasury PDA, `royalties_enabled` to `keeper_reward_lamports`
+    ///       appended
+    pub const VERSION: u8 = 2;
+
+    /// Seeds of the program-owned treasury PDA (a system account holding SOL).
+    pub const TREASURY_SEED: &'static [u8] = b"treasury";
+
+    /// Timelock given to migrated configs.
+    pub const DEFAULT_PARAM_UPDATE_DELAY_SECS: i64 = 48 * 60 * 60;
+    /// Execution delay given to new and migrated configs.
+    pub const DEFAULT_EXECUTION_DELAY_SECS: i64 = 48 * 60 * 60;
+    /// Governance thresholds given to new and migrated configs.
+    pub const DEFAULT_QUORUM_BPS: u16 = 2_000;
+    pub const DEFAULT_APPROVAL_THRESHOLD_BPS: u16 = 5_000;
+    /// Proposal deposit given to new and migrated configs (0.5 SOL).
+    pub const DEFAULT_PROPOSAL_DEPOSIT_LAMPORTS: u64 = 500_000_000;
+    /// Vote weight given to new and migrated configs: the 120 Commoner NFTs.
+    pub const DEFAULT_MAX_VOTE_WEIGHT: u64 = 120;
+    /// Upper bound on settler_reward_lamports (0.01 SOL).
+    pub const MAX_SETTLER_REWARD_LAMPORTS: u64 = 10_000_000;
+    /// Upper bound on keeper_reward_lamports (0.001 SOL), about half the rent
+    /// of the escrow token account it is carved out of.
+    pub const MAX_KEEPER_REWARD_LAMPORTS: u64 = 1_000_000;
+    /// Lower bound on the timelock, so a queued update can never be executed
+    /// in the same slot it was queued in.
+    pub const MIN_PARAM_UPDATE_DELAY_SECS: i64 = 1;
+    /// Upper bound on the timelock, so it can't be used to freeze parameters.
+    pub const MAX_PARAM_UPDATE_DELAY_SECS: i64 = 30 * 24 * 60 * 60;
+
+    /// list_slot
+    pub const PAUSE_LISTING: u8 = 1 << 0;
+    /// place_bid
+    pub const PAUSE_BIDDING: u8 = 1 << 1;
+    /// create_auction
+    pub const PAUSE_AUCTION_CREATION: u8 = 1 << 2;
+    /// create_proposal, submit_proposal, approve_proposal, cast_vote,
+    /// update_vote, withdraw_vote, delegate_votes, finalize_proposal,
+    /// execute_proposal
+    pub const PAUSE_GOVERNANCE: u8 = 1 << 3;
+    /// make_offer, accept_offer
+    pub const PAUSE_OFFERS: u8 = 1 << 4;
+    pub const PAUSE_ALL: u8 = Self::PAUSE_LISTING
+        | Self::PAUSE_BIDDING
+        | Self::PAUSE_AUCTION_CREATION
+        | Self::PAUSE_GOVERNANCE
+        | Self::PAUSE_OFFERS;
+
+    /// Address of the program-owned treasury PDA. It receives protocol fees
+    /// only while `fee_recipients` is empty; a configured table gets the
+    /// whole fee, so list the treasury PDA there to keep a share in it.
+    pub fn treasury_address() -> Pubkey {
+        Pubkey::find_program_address(&[Self::TREASURY_SEED], &crate::ID).0
+    }
+
+    /// Whether the capability behind `flag` is currently paused.
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+
     /// Resolve the effective fee bps for a seller given their COMMON balance.
     /// Returns the lowest qualifying tier, or default_fee_bps if none match.
     pub fn resolve_fee_bps(&self, common_balance: u64) -> u16 {
@@ -70,4 +244,34 @@ impl ProgramConfig {
         }
         best
     }
+
+    /// Number of used entries in the fee distribution table.
+    /// Settlement expects this many recipient accounts.
+    pub fn fee_recipient_count(&self) -> usize {
+        self.fee_recipients.iter().filter(|r| r.bps > 0).count()
+    }
+
+    /// Split `fee` across the used fee recipients, in table order.
+    /// Rounding dust goes to the last recipient so the full fee is paid out.
+    pub fn split_fee(&self, fee: u64) -> Vec<(Pubkey, u64)> {
+        let mut shares: Vec<(Pubkey, u64)> = self
+            .fee_recipients
+            .iter()
+            .filter(|r| r.bps > 0)
+            .map(|r| (r.recipient, (fee as u128 * r.bps as u128 / 10_000) as u64))
+            .collect();
+        let paid: u64 = shares.iter().map(|(_, amount)| amount).sum();
+        if let Some(last) = shares.last_mut() {
+            last.1 = last.1.saturating_add(fee.saturating_sub(paid));
+        }
+        shares
+    }
+
+    /// Royalty rate actually paid for an NFT requesting `seller_fee_bps`.
+    pub fn effective_royalty_bps(&self, seller_fee_bps: u16) -> u16 {
+        match self.royalty_cap_bps {
+            Some(cap) => seller_fee_bps.min(cap),
+            None => seller_fee_bps,
+        }
+    }
 }
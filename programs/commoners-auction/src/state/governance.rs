@@ -1,24 +1,28 @@
 use anchor_lang::prelude::*;
+use super::ProposalParamUpdate;
 
-/// On-chain governance proposal. Created by admin after off-chain review.
+/// On-chain governance proposal. Created by the proposal manager after
+/// off-chain review, or submitted directly by a holder with a deposit.
 /// PDA seeds: ["proposal", proposal_id.to_le_bytes()]
 #[account]
 #[derive(InitSpace)]
 pub struct GovernanceProposal {
-    /// Numeric ID, milliseconds since epoch at creation time.
+    /// Numeric ID: config.next_proposal_id when created or submitted.
     pub proposal_id: u64,
 
     /// Original submitter (Commoner NFT holder who proposed it).
     pub proposer: Pubkey,
 
-    /// Admin who created this on-chain record after off-chain approval.
+    /// Proposal manager who created or approved this record. Unset for a
+    /// submitted proposal that hasn't been approved or didn't need to be.
     pub admin: Pubkey,
 
     /// Short title (max 100 chars).
     #[max_len(100)]
     pub title: String,
 
-    /// Full description (max 800 chars).
+    /// Full description (max 800 chars). Empty for a hosted proposal,
+    /// whose document lives at `content_uri`.
     #[max_len(800)]
     pub description: String,
 
@@ -26,13 +30,14 @@ pub struct GovernanceProposal {
     #[max_len(50)]
     pub proposal_type: String,
 
-    /// SOL amount requested from treasury (0 for non-treasury proposals).
+    /// Lamports paid from the treasury PDA to `recipient` when executed
+    /// (0 for non-treasury proposals).
     pub treasury_sol: u64,
 
     /// Unix timestamp when this record was created on-chain.
     pub created_at: i64,
 
-    /// Unix timestamp when voting closes.
+    /// Unix timestamp when voting closes, 0 while awaiting approval.
     pub ends_at: i64,
 
     /// Accumulated yes votes (NFT-weighted).
@@ -44,17 +49,134 @@ pub struct GovernanceProposal {
     /// Accumulated abstain votes.
     pub abstain: u64,
 
-    /// 0 = active, 1 = passed, 2 = failed, 3 = queued
+    /// See GovernanceProposal::STATUS_*.
     pub status: u8,
 
     pub bump: u8,
+
+    /// Wallet paid `treasury_sol` by execute_proposal.
+    pub recipient: Pubkey,
+
+    /// Unix timestamp when the proposal was executed, 0 until then.
+    pub executed_at: i64,
+
+    /// Config change applied by execute_proposal, for parameter proposals.
+    pub param_update: Option<ProposalParamUpdate>,
+
+    /// Proposer's deposit, held in this account on top of its rent until
+    /// finalize_proposal or veto_proposal settles it. 0 once settled.
+    pub deposit_lamports: u64,
+
+    /// Length of the voting window, started by approve_proposal when the
+    /// proposal waits for approval.
+    pub voting_duration_secs: i64,
+
+    /// Where a hosted proposal's full document lives (max 200 chars).
+    /// Empty for an inline proposal.
+    #[max_len(200)]
+    pub content_uri: String,
+
+    /// SHA-256 of the document at `content_uri`, for clients to verify it.
+    /// Zero for an inline proposal.
+    pub content_hash: [u8; 32],
+}
+
+/// How a proposal's body is stored, chosen at creation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProposalContent {
+    /// Description kept in the account (max 800 chars).
+    Inline { description: String },
+    /// Full document kept off-chain at `uri`, pinned by its SHA-256.
+    /// The account is allocated without the description's 800 bytes.
+    Hosted { uri: String, content_hash: [u8; 32] },
 }
 
 impl GovernanceProposal {
     pub const SEED: &'static [u8] = b"proposal";
+
+    pub const STATUS_ACTIVE: u8 = 0;
+    pub const STATUS_PASSED: u8 = 1;
+    pub const STATUS_FAILED: u8 = 2;
+    /// Set by the old admin-chosen finalize_proposal; no longer written.
+    pub const STATUS_QUEUED: u8 = 3;
+    pub const STATUS_VETOED: u8 = 4;
+    pub const STATUS_EXECUTED: u8 = 5;
+    /// Submitted by a holder, waiting for approve_proposal to open voting.
+    pub const STATUS_PENDING_APPROVAL: u8 = 6;
+    /// Rejected or expired while awaiting approval; deposit refunded.
+    pub const STATUS_REJECTED: u8 = 7;
+
+    /// How long a submitted proposal may wait for approval before anyone
+    /// can expire it with reject_proposal.
+    pub const APPROVAL_WINDOW_SECS: i64 = 14 * 24 * 60 * 60;
+
+    pub const MAX_DESCRIPTION_LEN: usize = 800;
+    pub const MAX_URI_LEN: usize = 200;
+
+    /// Account space for a proposal with `content`: the full layout less
+    /// the string capacity that form never uses.
+    pub fn space(content: &ProposalContent) -> usize {
+        let full = 8 + Self::INIT_SPACE;
+        match content {
+            ProposalContent::Inline { .. } => full - Self::MAX_URI_LEN,
+            ProposalContent::Hosted { uri, .. } => {
+                full - Self::MAX_DESCRIPTION_LEN - Self::MAX_URI_LEN + uri.len()
+            }
+        }
+    }
+
+    /// Store `content` in the description or the URI and hash fields.
+    pub fn set_content(&mut self, content: ProposalContent) {
+        match content {
+            ProposalContent::Inline { description } => {
+                self.description = description;
+                self.content_uri = String::new();
+                self.content_hash = [0; 32];
+            }
+            ProposalContent::Hosted { uri, content_hash } => {
+                self.description = String::new();
+                self.content_uri = uri;
+                self.content_hash = content_hash;
+            }
+        }
+    }
+
+    /// Whether the tallies pass under the given rules:
+    ///   - quorum: yes + no + abstain must reach quorum_bps of `supply`
+    ///   - approval: yes must exceed approval_threshold_bps of yes + no
+    pub fn outcome(&self, supply: u64, quorum_bps: u16, approval_threshold_bps: u16) -> u8 {
+        let quorum_met = self.quorum_met(supply, quorum_bps);
+
+        let decided = self.yes as u128 + self.no as u128;
+        let approved = self.yes as u128 * 10_000 > decided * approval_threshold_bps as u128;
+
+        if quorum_met && approved {
+            Self::STATUS_PASSED
+        } else {
+            Self::STATUS_FAILED
+        }
+    }
+
+    /// Whether yes + no + abstain reach quorum_bps of `supply`.
+    pub fn quorum_met(&self, supply: u64, quorum_bps: u16) -> bool {
+        let turnout = self.yes as u128 + self.no as u128 + self.abstain as u128;
+        turnout * 10_000 >= supply as u128 * quorum_bps as u128
+    }
+
+    /// Move the held deposit out of the proposal account to `to`.
+    /// Returns the amount moved; a no-op once the deposit is settled.
+    pub fn release_deposit(proposal: &mut Account<Self>, to: &AccountInfo) -> Result<u64> {
+        let amount = proposal.deposit_lamports;
+        if amount > 0 {
+            proposal.deposit_lamports = 0;
+            proposal.sub_lamports(amount)?;
+            to.add_lamports(amount)?;
+        }
+        Ok(amount)
+    }
 }
 
-/// Per-voter vote record. Existence of this PDA prevents double-voting.
+/// Per-voter vote record, added to by each cast_vote call of the voter.
 /// PDA seeds: ["vote", proposal_id.to_le_bytes(), voter_pubkey.as_ref()]
 #[account]
 #[derive(InitSpace)]
@@ -65,7 +187,7 @@ pub struct VoteRecord {
     /// The wallet that cast the vote.
     pub voter: Pubkey,
 
-    /// Total voting weight (number of Commoner NFTs held, verified off-chain).
+    /// Total voting weight (number of Commoner NFTs counted).
     pub weight: u64,
 
     /// Votes allocated to yes.
@@ -78,8 +200,209 @@ pub struct VoteRecord {
     pub abstain: u64,
 
     pub bump: u8,
+
+    /// Part of `weight` counted from NFTs delegated to this voter.
+    pub delegated_weight: u64,
 }
 
 impl VoteRecord {
     pub const SEED: &'static [u8] = b"vote";
+
+    /// Take `weight` delegated Commoners out of this vote, trimming the
+    /// allocation to fit what is left. The excess comes out of yes, no and
+    /// abstain in proportion, any rounding remainder from the largest.
+    /// Returns the (yes, no, abstain) removed.
+    pub fn remove_delegated_weight(&mut self, weight: u64) -> (u64, u64, u64) {
+        self.weight = self.weight.saturating_sub(weight);
+        self.delegated_weight = self.delegated_weight.saturating_sub(weight);
+
+        let buckets = [self.yes, self.no, self.abstain];
+        let total: u64 = buckets.iter().sum();
+        let excess = total.saturating_sub(self.weight);
+        if excess == 0 {
+            return (0, 0, 0);
+        }
+
+        let mut cut = buckets.map(|b| (b as u128 * excess as u128 / total as u128) as u64);
+        let mut remainder = excess - cut.iter().sum::<u64>();
+        while remainder > 0 {
+            let largest = (0..3).max_by_key(|&i| (buckets[i] - cut[i], 3 - i)).unwrap_or(0);
+            cut[largest] += 1;
+            remainder -= 1;
+        }
+
+        self.yes -= cut[0];
+        self.no -= cut[1];
+        self.abstain -= cut[2];
+        (cut[0], cut[1], cut[2])
+    }
+}
+
+/// Marks an NFT as counted for a proposal, whichever wallet held it.
+/// Existence of this PDA stops the NFT voting again after a transfer.
+/// PDA seeds: ["nft-vote", proposal_id.to_le_bytes(), nft_mint.as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct NftVoteMarker {
+    pub proposal_id: u64,
+
+    pub nft_mint: Pubkey,
+
+    /// Wallet whose vote this NFT counted toward.
+    pub voter: Pubkey,
+
+    pub bump: u8,
+
+    /// Wallet holding the NFT when it was counted. When that was a delegate
+    /// voting for it, this holder may take the vote over in cast_vote.
+    pub holder: Pubkey,
+}
+
+impl NftVoteMarker {
+    pub const SEED: &'static [u8] = b"nft-vote";
+}
+
+/// A holder's standing delegation of their Commoners' votes.
+/// The delegate can count the holder's NFTs in cast_vote; delegations are
+/// one hop — a delegate's own delegation does not forward them.
+/// PDA seeds: ["delegation", holder.as_ref()]
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub holder: Pubkey,
+
+    pub delegate: Pubkey,
+
+    /// Unix timestamp when the delegation was made.
+    pub created_at: i64,
+
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const SEED: &'static [u8] = b"delegation";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSED: u8 = GovernanceProposal::STATUS_PASSED;
+    const FAILED: u8 = GovernanceProposal::STATUS_FAILED;
+
+    fn proposal(yes: u64, no: u64, abstain: u64) -> GovernanceProposal {
+        GovernanceProposal {
+            proposal_id: 0,
+            proposer: Pubkey::default(),
+            admin: Pubkey::default(),
+            title: String::new(),
+            description: String::new(),
+            proposal_type: String::new(),
+            treasury_sol: 0,
+            created_at: 0,
+            ends_at: 0,
+            yes,
+            no,
+            abstain,
+            status: GovernanceProposal::STATUS_ACTIVE,
+            bump: 0,
+            recipient: Pubkey::default(),
+            executed_at: 0,
+            param_update: None,
+            deposit_lamports: 0,
+            voting_duration_secs: 0,
+            content_uri: String::new(),
+            content_hash: [0; 32],
+        }
+    }
+
+    #[test]
+    fn quorum_is_met_at_exactly_quorum_bps() {
+        // 10% of 120 Commoners is 12 votes.
+        assert!(proposal(6, 3, 3).quorum_met(120, 1_000));
+        assert!(!proposal(6, 3, 2).quorum_met(120, 1_000));
+    }
+
+    #[test]
+    fn quorum_counts_abstentions() {
+        assert!(proposal(0, 0, 12).quorum_met(120, 1_000));
+    }
+
+    #[test]
+    fn quorum_is_met_without_supply() {
+        assert!(proposal(0, 0, 0).quorum_met(0, 1_000));
+    }
+
+    #[test]
+    fn outcome_needs_yes_strictly_above_the_threshold() {
+        // 50% of 20 decided votes is 10.
+        assert_eq!(proposal(10, 10, 0).outcome(120, 1_000, 5_000), FAILED);
+        assert_eq!(proposal(11, 9, 0).outcome(120, 1_000, 5_000), PASSED);
+    }
+
+    #[test]
+    fn outcome_ignores_abstentions_for_approval() {
+        assert_eq!(proposal(2, 1, 20).outcome(120, 1_000, 5_000), PASSED);
+        assert_eq!(proposal(0, 0, 20).outcome(120, 1_000, 5_000), FAILED);
+    }
+
+    #[test]
+    fn outcome_fails_short_of_quorum() {
+        assert_eq!(proposal(11, 0, 0).outcome(120, 1_000, 5_000), FAILED);
+    }
+
+    #[test]
+    fn outcome_without_supply_still_needs_a_yes_majority() {
+        assert_eq!(proposal(0, 0, 0).outcome(0, 1_000, 5_000), FAILED);
+        assert_eq!(proposal(1, 0, 0).outcome(0, 1_000, 5_000), PASSED);
+    }
+
+    fn vote(delegated_weight: u64, yes: u64, no: u64, abstain: u64) -> VoteRecord {
+        VoteRecord {
+            proposal_id: 0,
+            voter: Pubkey::default(),
+            weight: yes + no + abstain,
+            yes,
+            no,
+            abstain,
+            bump: 0,
+            delegated_weight,
+        }
+    }
+
+    #[test]
+    fn remove_delegated_weight_cuts_in_proportion() {
+        let mut record = vote(5, 6, 4, 0);
+        assert_eq!(record.remove_delegated_weight(5), (3, 2, 0));
+        assert_eq!((record.weight, record.delegated_weight), (5, 0));
+        assert_eq!((record.yes, record.no, record.abstain), (3, 2, 0));
+    }
+
+    #[test]
+    fn remove_delegated_weight_takes_the_remainder_from_the_largest() {
+        // 4 of 10 is 2.4 yes, 1.2 no and 0.4 abstain: the odd vote is a yes.
+        let mut record = vote(4, 6, 3, 1);
+        assert_eq!(record.remove_delegated_weight(4), (3, 1, 0));
+
+        // On a tie the earlier bucket gives it up.
+        let mut record = vote(1, 1, 1, 1);
+        assert_eq!(record.remove_delegated_weight(1), (1, 0, 0));
+    }
+
+    #[test]
+    fn remove_delegated_weight_keeps_an_allocation_that_still_fits() {
+        let mut record = vote(4, 3, 2, 0);
+        record.weight = 10;
+        assert_eq!(record.remove_delegated_weight(4), (0, 0, 0));
+        assert_eq!((record.weight, record.delegated_weight), (6, 0));
+        assert_eq!((record.yes, record.no, record.abstain), (3, 2, 0));
+    }
+
+    #[test]
+    fn remove_delegated_weight_saturates() {
+        let mut record = vote(3, 2, 1, 0);
+        assert_eq!(record.remove_delegated_weight(5), (2, 1, 0));
+        assert_eq!((record.weight, record.delegated_weight), (0, 0));
+        assert_eq!((record.yes, record.no, record.abstain), (0, 0, 0));
+    }
 }
@@ -2,8 +2,14 @@ pub mod config;
 pub mod auction;
 pub mod slot;
 pub mod governance;
+pub mod offer;
+pub mod bid_log;
+pub mod param_update;
 
 pub use config::*;
 pub use auction::*;
 pub use slot::*;
 pub use governance::*;
+pub use offer::*;
+pub use bid_log::*;
+pub use param_update::*;
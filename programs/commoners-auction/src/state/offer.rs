@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// A buyer's standing SOL offer on a specific NFT, listed or not.
+/// The offered lamports are held in this account on top of its rent.
+/// PDA seeds: ["offer", nft_mint, buyer]
+#[account]
+#[derive(InitSpace)]
+pub struct Offer {
+    /// NFT mint the offer is for.
+    pub nft_mint: Pubkey,
+
+    /// Wallet that made the offer (receives the NFT on acceptance).
+    pub buyer: Pubkey,
+
+    /// Offered amount in lamports, escrowed in this account.
+    pub amount: u64,
+
+    /// Unix timestamp when the offer was made.
+    pub created_at: i64,
+
+    pub bump: u8,
+}
+
+impl Offer {
+    pub const SEED: &'static [u8] = b"offer";
+}
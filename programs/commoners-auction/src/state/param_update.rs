@@ -0,0 +1,238 @@
+use anchor_lang::prelude::*;
+use crate::errors::AuctionError;
+use super::{DiscountTier, FeeRecipient, ProgramConfig};
+
+/// A set of config parameter changes. `None` leaves a parameter unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct ParamUpdate {
+    pub fee_bps: Option<u16>,
+    pub bid_increment_bps: Option<u16>,
+    pub time_buffer_secs: Option<i64>,
+    pub min_reserve_lamports: Option<u64>,
+    pub common_token_mint: Option<Pubkey>,
+    pub discount_tiers: Option<[DiscountTier; 4]>,
+    pub referral_bps: Option<u16>,
+    pub settler_reward_lamports: Option<u64>,
+    pub keeper_reward_lamports: Option<u64>,
+    /// `Some(None)` clears the collection restriction.
+    pub required_collection: Option<Option<Pubkey>>,
+    pub param_update_delay_secs: Option<i64>,
+    /// `Some(None)` clears the Commoner collection, closing voting.
+    pub commoner_collection: Option<Option<Pubkey>>,
+    pub quorum_bps: Option<u16>,
+    pub approval_threshold_bps: Option<u16>,
+    pub execution_delay_secs: Option<i64>,
+    pub proposal_deposit_lamports: Option<u64>,
+    pub proposal_approval_required: Option<bool>,
+    pub max_vote_weight: Option<u64>,
+    /// Used entries must sum to 10_000; all-empty routes fees to the treasury PDA.
+    pub fee_recipients: Option<[FeeRecipient; 4]>,
+    pub royalties_enabled: Option<bool>,
+    /// `Some(None)` honours the metadata's royalty rate uncapped.
+    pub royalty_cap_bps: Option<Option<u16>>,
+}
+
+impl ParamUpdate {
+    /// Reject out-of-range values at queue time, so a queued update can
+    /// always be executed.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(fee) = self.fee_bps {
+            require!(fee <= 10_000, AuctionError::FeeTooHigh);
+        }
+        if let Some(inc) = self.bid_increment_bps {
+            require!(inc <= 5_000, AuctionError::InvalidBidIncrement);
+        }
+        if let Some(buf) = self.time_buffer_secs {
+            require!(buf >= 0, AuctionError::InvalidTimeBuffer);
+        }
+        if let Some(bps) = self.referral_bps {
+            require!(bps <= 10_000, AuctionError::FeeTooHigh);
+        }
+        if let Some(bps) = self.quorum_bps {
+            require!(bps <= 10_000, AuctionError::InvalidGovernanceThreshold);
+        }
+        if let Some(bps) = self.approval_threshold_bps {
+            require!(bps < 10_000, AuctionError::InvalidGovernanceThreshold);
+        }
+        if let Some(weight) = self.max_vote_weight {
+            require!(weight > 0, AuctionError::InvalidMaxVoteWeight);
+        }
+        if let Some(reward) = self.settler_reward_lamports {
+            require!(
+                reward <= ProgramConfig::MAX_SETTLER_REWARD_LAMPORTS,
+                AuctionError::SettlerRewardTooHigh
+            );
+        }
+        if let Some(reward) = self.keeper_reward_lamports {
+            require!(
+                reward <= ProgramConfig::MAX_KEEPER_REWARD_LAMPORTS,
+                AuctionError::KeeperRewardTooHigh
+            );
+        }
+        if let Some(fee_recipients) = &self.fee_recipients {
+            FeeRecipient::validate_table(fee_recipients)?;
+        }
+        if let Some(Some(cap)) = self.royalty_cap_bps {
+            require!(cap <= 10_000, AuctionError::RoyaltyCapTooHigh);
+        }
+        if let Some(delay) = self.param_update_delay_secs {
+            require!(
+                (ProgramConfig::MIN_PARAM_UPDATE_DELAY_SECS
+                    ..=ProgramConfig::MAX_PARAM_UPDATE_DELAY_SECS)
+                    .contains(&delay),
+                AuctionError::InvalidParamUpdateDelay
+            );
+        }
+        if let Some(delay) = self.execution_delay_secs {
+            require!(
+                (0..=ProgramConfig::MAX_PARAM_UPDATE_DELAY_SECS).contains(&delay),
+                AuctionError::InvalidParamUpdateDelay
+            );
+        }
+        Ok(())
+    }
+
+    /// Write every set parameter into `config`.
+    pub fn apply(&self, config: &mut ProgramConfig) {
+        if let Some(fee) = self.fee_bps {
+            config.default_fee_bps = fee;
+            msg!("Fee updated to {}bps", fee);
+        }
+        if let Some(inc) = self.bid_increment_bps {
+            config.bid_increment_bps = inc;
+            msg!("Bid increment updated to {}bps", inc);
+        }
+        if let Some(buf) = self.time_buffer_secs {
+            config.time_buffer_secs = buf;
+            msg!("Time buffer updated to {}s", buf);
+        }
+        if let Some(min) = self.min_reserve_lamports {
+            config.min_reserve_lamports = min;
+            msg!("Min reserve updated to {} lamports", min);
+        }
+        if let Some(mint) = self.common_token_mint {
+            config.common_token_mint = Some(mint);
+            msg!("COMMON token mint set to {}", mint);
+        }
+        if let Some(tiers) = self.discount_tiers {
+            config.discount_tiers = tiers;
+            msg!("Discount tiers updated");
+        }
+        if let Some(bps) = self.referral_bps {
+            config.referral_bps = bps;
+            msg!("Referral share updated to {}bps of fee", bps);
+        }
+        if let Some(reward) = self.settler_reward_lamports {
+            config.settler_reward_lamports = reward;
+            msg!("Settler reward cap updated to {} lamports", reward);
+        }
+        if let Some(reward) = self.keeper_reward_lamports {
+            config.keeper_reward_lamports = reward;
+            msg!("Keeper reward updated to {} lamports", reward);
+        }
+        if let Some(collection) = self.required_collection {
+            config.required_collection = collection;
+            msg!("Required collection updated to {:?}", collection);
+        }
+        if let Some(delay) = self.param_update_delay_secs {
+            config.param_update_delay_secs = delay;
+            msg!("Param update delay updated to {}s", delay);
+        }
+        if let Some(collection) = self.commoner_collection {
+            config.commoner_collection = collection;
+            msg!("Commoner collection updated to {:?}", collection);
+        }
+        if let Some(bps) = self.quorum_bps {
+            config.quorum_bps = bps;
+            msg!("Quorum updated to {}bps", bps);
+        }
+        if let Some(bps) = self.approval_threshold_bps {
+            config.approval_threshold_bps = bps;
+            msg!("Approval threshold updated to {}bps", bps);
+        }
+        if let Some(delay) = self.execution_delay_secs {
+            config.execution_delay_secs = delay;
+            msg!("Proposal execution delay updated to {}s", delay);
+        }
+        if let Some(deposit) = self.proposal_deposit_lamports {
+            config.proposal_deposit_lamports = deposit;
+            msg!("Proposal deposit updated to {} lamports", deposit);
+        }
+        if let Some(required) = self.proposal_approval_required {
+            config.proposal_approval_required = required;
+            msg!("Proposal approval required: {}", required);
+        }
+        if let Some(weight) = self.max_vote_weight {
+            config.max_vote_weight = weight;
+            msg!("Max vote weight updated to {}", weight);
+        }
+        if let Some(fee_recipients) = self.fee_recipients {
+            config.fee_recipients = fee_recipients;
+            msg!("Fee recipients updated: {} entries", config.fee_recipient_count());
+        }
+        if let Some(enabled) = self.royalties_enabled {
+            config.royalties_enabled = enabled;
+            msg!("Royalties enabled: {}", enabled);
+        }
+        if let Some(cap) = self.royalty_cap_bps {
+            config.royalty_cap_bps = cap;
+            msg!("Royalty cap updated to {:?}", cap);
+        }
+    }
+}
+
+/// The parameters a governance proposal may change: the auction economics,
+/// the collection gate and the vote weight cap. Roles, the other governance
+/// rules, fee routing and the timelock stay with the admin's
+/// queue_param_update.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, InitSpace)]
+pub struct ProposalParamUpdate {
+    pub fee_bps: Option<u16>,
+    pub bid_increment_bps: Option<u16>,
+    pub time_buffer_secs: Option<i64>,
+    pub min_reserve_lamports: Option<u64>,
+    pub discount_tiers: Option<[DiscountTier; 4]>,
+    /// `Some(None)` clears the collection restriction.
+    pub required_collection: Option<Option<Pubkey>>,
+    pub max_vote_weight: Option<u64>,
+}
+
+impl From<ProposalParamUpdate> for ParamUpdate {
+    fn from(update: ProposalParamUpdate) -> Self {
+        ParamUpdate {
+            fee_bps: update.fee_bps,
+            bid_increment_bps: update.bid_increment_bps,
+            time_buffer_secs: update.time_buffer_secs,
+            min_reserve_lamports: update.min_reserve_lamports,
+            discount_tiers: update.discount_tiers,
+            required_collection: update.required_collection,
+            max_vote_weight: update.max_vote_weight,
+            ..Default::default()
+        }
+    }
+}
+
+/// A queued config change waiting out the timelock.
+/// The applied values stay readable on ProgramConfig until execution.
+/// PDA seeds: ["pending-params"] — one pending update at a time.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingParamUpdate {
+    /// The changes to apply.
+    pub update: ParamUpdate,
+
+    /// Admin that queued the update (receives rent back on close).
+    pub proposer: Pubkey,
+
+    /// Unix timestamp when the update was queued.
+    pub queued_at: i64,
+
+    /// Earliest unix timestamp at which execute_param_update may apply it.
+    pub eta: i64,
+
+    pub bump: u8,
+}
+
+impl PendingParamUpdate {
+    pub const SEED: &'static [u8] = b"pending-params";
+}
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::AuctionError;
+use crate::pda::close_pda_account;
 
 /// A holder's reservation of a future auction slot.
 /// Created when a holder locks their NFT into escrow.
@@ -24,8 +26,126 @@ pub struct SlotRegistration {
     pub consumed: bool,
 
     pub bump: u8,
+
+    /// Seller opted in to automatic relisting: if the auction ends without
+    /// meeting reserve, settlement keeps the NFT in escrow and registers it
+    /// for the next free date instead of returning it. The seller can switch
+    /// it off with set_auto_relist.
+    pub auto_relist: bool,
+
+    /// Reserve reduction applied on each relist, in basis points of the
+    /// previous reserve (1000 = 10% lower). Floored at the global minimum.
+    pub reserve_decay_bps: u16,
 }
 
 impl SlotRegistration {
     pub const SEED: &'static [u8] = b"slot";
+
+    /// How many days past tomorrow auto-relist looks for a free date. Each
+    /// booked date costs settle_auction a remaining account, so the search
+    /// stops here rather than at the transaction's account limit.
+    pub const MAX_RELIST_LOOKAHEAD_DAYS: usize = 10;
+
+    /// Reserve for the next relisting: the current reserve reduced by
+    /// reserve_decay_bps, but never below `min_reserve`.
+    pub fn decayed_reserve(&self, min_reserve: u64) -> u64 {
+        let decay = (self.reserve_price as u128)
+            .saturating_mul(self.reserve_decay_bps as u128)
+            / 10_000;
+        let decayed = self.reserve_price.saturating_sub(decay as u64);
+        decayed.max(min_reserve)
+    }
+}
+
+/// Booking of an auction date. create_auction opens one auction per date, so
+/// list_slot and auto-relist both create this PDA for the slot's date and
+/// fail if another NFT already booked it. Closed once the slot's date is used
+/// up: at settlement, stale close, cancellation or expired slot reclaim.
+/// PDA seeds: ["date", scheduled_date]
+#[account]
+#[derive(InitSpace)]
+pub struct DateReservation {
+    /// Booked auction date (start of day UTC).
+    pub scheduled_date: i64,
+
+    /// NFT booked on this date.
+    pub nft_mint: Pubkey,
+
+    /// Wallet that paid the rent — the holder at list_slot, the settler on
+    /// auto-relist. Refunded when the reservation is closed.
+    pub payer: Pubkey,
+
+    pub bump: u8,
+}
+
+impl DateReservation {
+    pub const SEED: &'static [u8] = b"date";
+
+    /// Address of the reservation PDA for `date`.
+    pub fn address(date: i64) -> Pubkey {
+        Pubkey::find_program_address(&[Self::SEED, &date.to_le_bytes()], &crate::ID).0
+    }
+
+    /// Close `account`, the reservation PDA of a date `nft_mint`'s slot has
+    /// used up, refunding its rent to `payer`. Returns false, closing
+    /// nothing, if the date has no reservation or it belongs to another NFT —
+    /// a slot listed before reservations existed may share its date.
+    pub fn release<'info>(
+        account: &AccountInfo<'info>,
+        nft_mint: &Pubkey,
+        payer: &AccountInfo<'info>,
+    ) -> Result<bool> {
+        if *account.owner != crate::ID || account.data_is_empty() {
+            return Ok(false);
+        }
+        let reservation = Self::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        if reservation.nft_mint != *nft_mint {
+            return Ok(false);
+        }
+        require_keys_eq!(payer.key(), reservation.payer, AuctionError::ReservationPayerMismatch);
+        close_pda_account(account, payer)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(reserve_price: u64, reserve_decay_bps: u16) -> SlotRegistration {
+        SlotRegistration {
+            nft_mint: Pubkey::default(),
+            owner: Pubkey::default(),
+            scheduled_date: 0,
+            reserve_price,
+            escrowed: true,
+            consumed: true,
+            bump: 0,
+            auto_relist: true,
+            reserve_decay_bps,
+        }
+    }
+
+    #[test]
+    fn decayed_reserve_takes_the_decay_off_the_reserve() {
+        assert_eq!(slot(1_000_000_000, 1_000).decayed_reserve(0), 900_000_000);
+        assert_eq!(slot(1_000_000_000, 0).decayed_reserve(0), 1_000_000_000);
+    }
+
+    #[test]
+    fn decayed_reserve_floors_at_the_minimum() {
+        assert_eq!(slot(1_000_000_000, 1_000).decayed_reserve(950_000_000), 950_000_000);
+        assert_eq!(slot(1_000_000_000, 10_000).decayed_reserve(1), 1);
+    }
+
+    #[test]
+    fn decayed_reserve_rounds_the_decay_down() {
+        // 1% of 199 lamports is 1.99, so 1 lamport comes off.
+        assert_eq!(slot(199, 100).decayed_reserve(0), 198);
+    }
+
+    #[test]
+    fn decayed_reserve_does_not_overflow() {
+        assert_eq!(slot(u64::MAX, 5_000).decayed_reserve(0), u64::MAX - u64::MAX / 2);
+    }
 }
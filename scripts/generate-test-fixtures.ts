@@ -0,0 +1,743 @@
+/**
+ * generate-test-fixtures.ts
+ *
+ * Writes the accounts the local validator starts with for
+ * tests/commoners-auction.ts (listed under [[test.validator.account]] in
+ * Anchor.toml):
+ *   - program accounts in legacy layouts, for the migration tests
+ *   - program accounts dated in the past, for paths that only open days
+ *     after an auction or vote ends
+ *   - Metaplex metadata for the test NFTs — the Metaplex program isn't
+ *     deployed locally, so metadata can't be created at runtime
+ *
+ * Every address is derived from a fixed label, so the output is
+ * deterministic and the tests derive the same addresses.
+ *
+ * Usage:
+ *   npx tsx scripts/generate-test-fixtures.ts
+ *
+ * Output: tests/fixtures/*.json
+ */
+
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import * as fs from "fs";
+import * as path from "path";
+
+const PROGRAM_ID = new PublicKey("EWXiRHrYNtMy6wXQsy2oZhops6Dsw5M4GT59Bqb3xPjC");
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const SYSTEM_PROGRAM_ID = new PublicKey("11111111111111111111111111111111");
+// Size Metaplex allocates for a MetadataV1 account.
+const METADATA_LEN = 679;
+const OUT_DIR = path.join(__dirname, "../tests/fixtures");
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/** Keypair derived from a label — the tests use the same derivation. */
+function seeded(label: string): Keypair {
+  return Keypair.fromSeed(createHash("sha256").update(label).digest());
+}
+
+function pda(seeds: Buffer[], programId: PublicKey = PROGRAM_ID): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(seeds, programId);
+}
+
+function u64le(value: number): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigUInt64LE(BigInt(value));
+  return buf;
+}
+
+function i64le(value: number): Buffer {
+  const buf = Buffer.alloc(8);
+  buf.writeBigInt64LE(BigInt(value));
+  return buf;
+}
+
+/** Anchor account discriminator. */
+function discriminator(account: string): Buffer {
+  return createHash("sha256").update(`account:${account}`).digest().subarray(0, 8);
+}
+
+/** Minimum balance for rent exemption at the default rent. */
+function rentExempt(len: number): number {
+  return (128 + len) * 3480 * 2;
+}
+
+/** Little-endian borsh writer for the fixed layouts below. */
+class Writer {
+  private parts: Buffer[] = [];
+
+  u8(value: number): this {
+    this.parts.push(Buffer.from([value]));
+    return this;
+  }
+
+  bool(value: boolean): this {
+    return this.u8(value ? 1 : 0);
+  }
+
+  u16(value: number): this {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    this.parts.push(buf);
+    return this;
+  }
+
+  u32(value: number): this {
+    const buf = Buffer.alloc(4);
+    buf.writeUInt32LE(value);
+    this.parts.push(buf);
+    return this;
+  }
+
+  u64(value: number): this {
+    this.parts.push(u64le(value));
+    return this;
+  }
+
+  i64(value: number): this {
+    this.parts.push(i64le(value));
+    return this;
+  }
+
+  pubkey(key: PublicKey): this {
+    this.parts.push(key.toBuffer());
+    return this;
+  }
+
+  /** Optional pubkey: borsh Option<Pubkey>. */
+  optionPubkey(key: PublicKey | null): this {
+    return key ? this.u8(1).pubkey(key) : this.u8(0);
+  }
+
+  string(value: string): this {
+    const bytes = Buffer.from(value, "utf8");
+    this.u32(bytes.length);
+    this.parts.push(bytes);
+    return this;
+  }
+
+  bytes(value: Buffer): this {
+    this.parts.push(value);
+    return this;
+  }
+
+  build(): Buffer {
+    return Buffer.concat(this.parts);
+  }
+}
+
+/** Write one account in the `solana account --output json` format. */
+function writeAccount(
+  name: string,
+  address: PublicKey,
+  owner: PublicKey,
+  data: Buffer,
+  lamports: number = rentExempt(data.length)
+) {
+  const json = {
+    pubkey: address.toBase58(),
+    account: {
+      lamports,
+      data: [data.toString("base64"), "base64"],
+      owner: owner.toBase58(),
+      executable: false,
+      rentEpoch: 0,
+      space: data.length,
+    },
+  };
+  fs.writeFileSync(path.join(OUT_DIR, `${name}.json`), JSON.stringify(json, null, 2) + "\n");
+  console.log(`${name.padEnd(28)} ${address.toBase58()}`);
+}
+
+interface Creator {
+  address: PublicKey;
+  verified: boolean;
+  share: number;
+}
+
+/** Metaplex MetadataV1 account for `mint`, zero-padded like the real ones. */
+function writeMetadata(
+  name: string,
+  mint: PublicKey,
+  opts: {
+    sellerFeeBps?: number;
+    creators?: Creator[];
+    collection?: { key: PublicKey; verified: boolean };
+  }
+) {
+  const [address] = pda(
+    [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+  const w = new Writer()
+    .u8(4) // key: MetadataV1
+    .pubkey(seeded("metadata-update-authority").publicKey)
+    .pubkey(mint)
+    .string(name)
+    .string("TEST")
+    .string(`https://example.com/${name}.json`)
+    .u16(opts.sellerFeeBps ?? 0);
+  if (opts.creators) {
+    w.u8(1).u32(opts.creators.length);
+    for (const c of opts.creators) w.pubkey(c.address).bool(c.verified).u8(c.share);
+  } else {
+    w.u8(0);
+  }
+  w.bool(false) // primary_sale_happened
+    .bool(true) // is_mutable
+    .u8(0) // edition_nonce
+    .u8(0); // token_standard
+  if (opts.collection) {
+    w.u8(1).bool(opts.collection.verified).pubkey(opts.collection.key);
+  } else {
+    w.u8(0);
+  }
+  const data = Buffer.alloc(METADATA_LEN);
+  w.build().copy(data);
+  writeAccount(name, address, TOKEN_METADATA_PROGRAM_ID, data);
+}
+
+/** SPL mint with a supply of one, no decimals and no freeze authority. */
+function writeNftMint(name: string, mint: PublicKey) {
+  const data = new Writer()
+    .u32(1) // mint_authority: Some
+    .pubkey(seeded("fixture-mint-authority").publicKey)
+    .u64(1) // supply
+    .u8(0) // decimals
+    .bool(true) // is_initialized
+    .u32(0) // freeze_authority: None
+    .pubkey(PublicKey.default)
+    .build();
+  writeAccount(name, mint, TOKEN_PROGRAM_ID, data);
+}
+
+/** `owner`'s associated token account for `mint`, holding `amount`. */
+function writeTokenAccount(name: string, mint: PublicKey, owner: PublicKey, amount: number) {
+  const [address] = pda(
+    [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  );
+  const data = new Writer()
+    .pubkey(mint)
+    .pubkey(owner)
+    .u64(amount)
+    .u32(0) // delegate: None
+    .pubkey(PublicKey.default)
+    .u8(1) // state: Initialized
+    .u32(0) // is_native: None
+    .u64(0)
+    .u64(0) // delegated_amount
+    .u32(0) // close_authority: None
+    .pubkey(PublicKey.default)
+    .build();
+  writeAccount(name, address, TOKEN_PROGRAM_ID, data);
+}
+
+/** SlotRegistration in the current layout. */
+function writeSlot(
+  name: string,
+  nftMint: PublicKey,
+  owner: PublicKey,
+  scheduledDate: number,
+  opts: { consumed: boolean }
+) {
+  const [address, bump] = pda([Buffer.from("slot"), nftMint.toBuffer(), i64le(scheduledDate)]);
+  const data = new Writer()
+    .bytes(discriminator("SlotRegistration"))
+    .pubkey(nftMint)
+    .pubkey(owner)
+    .i64(scheduledDate)
+    .u64(500_000_000) // reserve_price
+    .bool(true) // escrowed
+    .bool(opts.consumed)
+    .u8(bump)
+    .bool(false) // auto_relist
+    .u16(0) // reserve_decay_bps
+    .build();
+  writeAccount(name, address, PROGRAM_ID, data);
+  return address;
+}
+
+interface AuctionFixture {
+  auctionId: number;
+  nftMint: PublicKey;
+  seller: PublicKey;
+  /** Defaults to a day before `endTime`. */
+  startTime?: number;
+  endTime: number;
+  currentBid: number;
+  currentBidder: PublicKey | null;
+  settled: boolean;
+  reserveMet: boolean;
+}
+
+/** AuctionState sizes: before current_referrer and status, current. */
+const AUCTION_LEN = { baseline: 150, current: 184 };
+
+/**
+ * AuctionState in one of its layouts, zero-padded to the account size like
+ * an Anchor account whose Options are None. `status` only applies to the
+ * current layout.
+ */
+function writeAuction(
+  name: string,
+  a: AuctionFixture,
+  layout: keyof typeof AUCTION_LEN,
+  status = 0
+) {
+  const [address, bump] = pda([Buffer.from("auction"), u64le(a.auctionId)]);
+  const w = new Writer()
+    .bytes(discriminator("AuctionState"))
+    .pubkey(a.nftMint)
+    .pubkey(a.seller)
+    .u64(500_000_000) // reserve_price
+    .i64(a.startTime ?? a.endTime - 86_400) // start_time
+    .i64(a.endTime)
+    .u64(a.currentBid)
+    .optionPubkey(a.currentBidder)
+    .u16(900) // fee_bps
+    .bool(a.settled)
+    .bool(a.reserveMet)
+    .u64(a.auctionId)
+    .u8(bump);
+  if (layout === "current") w.optionPubkey(null).u8(status); // current_referrer, status
+  const data = Buffer.alloc(AUCTION_LEN[layout]);
+  w.build().copy(data);
+  writeAccount(name, address, PROGRAM_ID, data);
+}
+
+/**
+ * `writeAuction` for an auction whose mint, seller and bidder (with a bid)
+ * are seeded from `name`, as the tests derive them.
+ */
+function writeNamedAuction(
+  name: string,
+  a: Omit<AuctionFixture, "nftMint" | "seller" | "currentBidder">,
+  layout: keyof typeof AUCTION_LEN,
+  status = 0
+) {
+  writeAuction(
+    name,
+    {
+      ...a,
+      nftMint: seeded(`${name}-mint`).publicKey,
+      seller: seeded(`${name}-seller`).publicKey,
+      currentBidder: a.currentBid > 0 ? seeded(`${name}-bidder`).publicKey : null,
+    },
+    layout,
+    status
+  );
+}
+
+/** An auction's bid vault, holding `bid` on top of its rent. */
+function writeBidVault(name: string, auctionId: number, bid: number) {
+  const [vault] = pda([Buffer.from("bid-vault"), u64le(auctionId)]);
+  writeAccount(name, vault, SYSTEM_PROGRAM_ID, Buffer.alloc(0), rentExempt(0) + bid);
+}
+
+interface ProposalFixture {
+  proposalId: number;
+  proposer?: PublicKey;
+  /** Defaults to an hour before `endsAt`. */
+  createdAt?: number;
+  endsAt: number;
+  yes?: number;
+  no?: number;
+  abstain?: number;
+  status: number;
+  treasurySol?: number;
+  recipient?: PublicKey;
+  /** Borsh-encoded ProposalParamUpdate, for parameter proposals. */
+  paramUpdate?: Buffer;
+  depositLamports?: number;
+  title?: string;
+  description?: string;
+  proposalType?: string;
+  /** Written as before content_uri and content_hash were appended. */
+  legacy?: boolean;
+}
+
+/** Account size of an inline GovernanceProposal (GovernanceProposal::space). */
+const PROPOSAL_LEN = 1_293;
+/** Account size before content_uri and content_hash (4 + 32 bytes). */
+const LEGACY_PROPOSAL_LEN = PROPOSAL_LEN - 36;
+
+/**
+ * GovernanceProposal with an inline description, in the current layout.
+ * The deposit is held on top of the rent, as submit_proposal leaves it.
+ */
+function writeProposal(name: string, p: ProposalFixture) {
+  const [address, bump] = pda([Buffer.from("proposal"), u64le(p.proposalId)]);
+  const w = new Writer()
+    .bytes(discriminator("GovernanceProposal"))
+    .u64(p.proposalId)
+    .pubkey(p.proposer ?? seeded("fixture-proposer").publicKey)
+    .pubkey(PublicKey.default) // admin
+    .string(p.title ?? `Fixture ${name}`)
+    .string(p.description ?? "A proposal preloaded by scripts/generate-test-fixtures.ts.")
+    .string(p.proposalType ?? (p.paramUpdate ? "param-update" : "community-initiative"))
+    .u64(p.treasurySol ?? 0)
+    .i64(p.createdAt ?? p.endsAt - 3_600) // created_at
+    .i64(p.endsAt)
+    .u64(p.yes ?? 0)
+    .u64(p.no ?? 0)
+    .u64(p.abstain ?? 0)
+    .u8(p.status)
+    .u8(bump)
+    .pubkey(p.recipient ?? PublicKey.default)
+    .i64(0); // executed_at
+  if (p.paramUpdate) {
+    w.u8(1).bytes(p.paramUpdate);
+  } else {
+    w.u8(0);
+  }
+  w.u64(p.depositLamports ?? 0).i64(3_600); // voting_duration_secs
+  if (!p.legacy) {
+    w.string("") // content_uri
+      .bytes(Buffer.alloc(32)); // content_hash
+  }
+  const len = p.legacy ? LEGACY_PROPOSAL_LEN : PROPOSAL_LEN;
+  const data = Buffer.alloc(len);
+  w.build().copy(data);
+  writeAccount(name, address, PROGRAM_ID, data, rentExempt(len) + (p.depositLamports ?? 0));
+}
+
+// ---------------------------------------------------------------------------
+// Fixtures
+// ---------------------------------------------------------------------------
+
+/** SlotRegistration as created before auto_relist and reserve_decay_bps. */
+function legacySlot() {
+  const nftMint = seeded("legacy-slot-mint").publicKey;
+  const scheduledDate = 1_700_006_400;
+  const [address, bump] = pda([Buffer.from("slot"), nftMint.toBuffer(), i64le(scheduledDate)]);
+  const data = new Writer()
+    .bytes(discriminator("SlotRegistration"))
+    .pubkey(nftMint)
+    .pubkey(seeded("legacy-slot-owner").publicKey)
+    .i64(scheduledDate)
+    .u64(500_000_000) // reserve_price
+    .bool(true) // escrowed
+    .bool(false) // consumed
+    .u8(bump)
+    .build();
+  writeAccount("legacy-slot", address, PROGRAM_ID, data);
+}
+
+/**
+ * Metadata for the royalty test NFTs, both with 5% royalties: one with two
+ * verified creators (60/30) and an unverified one (10), one whose verified
+ * creators can't all be paid — an empty account whose 1% stays below rent
+ * exemption and the (executable) token program at 30%.
+ */
+function royaltyMetadata() {
+  writeMetadata("royalty-metadata", seeded("royalty-mint").publicKey, {
+    sellerFeeBps: 500,
+    creators: [
+      { address: seeded("royalty-creator-0").publicKey, verified: true, share: 60 },
+      { address: seeded("royalty-creator-1").publicKey, verified: true, share: 30 },
+      { address: seeded("royalty-creator-2").publicKey, verified: false, share: 10 },
+    ],
+  });
+  writeMetadata("royalty-unpayable-metadata", seeded("royalty-unpayable-mint").publicKey, {
+    sellerFeeBps: 500,
+    creators: [
+      { address: seeded("royalty-unpayable-creator").publicKey, verified: true, share: 1 },
+      { address: TOKEN_PROGRAM_ID, verified: true, share: 30 },
+      { address: seeded("royalty-creator-0").publicKey, verified: true, share: 69 },
+    ],
+  });
+}
+
+/**
+ * AuctionState from before current_referrer: unsettled with a bid and long
+ * past its end, so migration marks it Ended.
+ */
+function baselineAuction() {
+  writeNamedAuction(
+    "baseline-auction",
+    {
+      auctionId: 9_000_001,
+      endTime: 1_700_000_000,
+      currentBid: 600_000_000,
+      settled: false,
+      reserveMet: false,
+    },
+    "baseline"
+  );
+}
+
+/**
+ * AuctionState from before current_referrer: closed as stale with a
+ * standing bid, which settlement would have sold — migration marks it
+ * ClosedStale and refunds the bid the stale close left in the vault.
+ */
+function staleClosedAuction() {
+  const auctionId = 9_000_002;
+  const bid = 300_000_000;
+  writeNamedAuction(
+    "stale-closed-auction",
+    { auctionId, endTime: 1_700_000_000, currentBid: bid, settled: true, reserveMet: false },
+    "baseline"
+  );
+  writeBidVault("stale-closed-bid-vault", auctionId, bid);
+}
+
+/**
+ * A Live auction nobody settled, ended long ago, with its NFT in escrow and
+ * the standing bid in the vault — ready for close_stale_auction.
+ */
+function staleAuction() {
+  const date = 1_700_092_800;
+  const nftMint = seeded("stale-mint").publicKey;
+  const seller = seeded("stale-seller").publicKey;
+  const bid = 600_000_000;
+
+  writeNftMint("stale-mint", nftMint);
+  const slot = writeSlot("stale-slot", nftMint, seller, date, { consumed: true });
+  writeTokenAccount("stale-escrow", nftMint, slot, 1);
+  writeAuction(
+    "stale-auction",
+    {
+      auctionId: date,
+      nftMint,
+      seller,
+      endTime: date,
+      currentBid: bid,
+      currentBidder: seeded("stale-bidder").publicKey,
+      settled: false,
+      reserveMet: false,
+    },
+    "current",
+    1 // Live
+  );
+  writeBidVault("stale-bid-vault", date, bid);
+}
+
+/**
+ * An auction stale-closed before close_stale_auction refunded bids: already
+ * ClosedStale, with the standing bid still in the vault for close_auction
+ * to refund.
+ */
+function unrefundedAuction() {
+  const auctionId = 9_000_004;
+  const bid = 400_000_000;
+  writeNamedAuction(
+    "unrefunded-auction",
+    { auctionId, endTime: 1_700_000_000, currentBid: bid, settled: true, reserveMet: false },
+    "current",
+    5 // ClosedStale
+  );
+  writeBidVault("unrefunded-bid-vault", auctionId, bid);
+}
+
+/**
+ * A Live auction opened before bid logs existed — no BidLog account — and
+ * running until 2100, for place_bid to create the log on its first bid.
+ */
+function unloggedAuction() {
+  writeNamedAuction(
+    "unlogged-auction",
+    {
+      auctionId: 9_000_003,
+      startTime: 1_700_000_000,
+      endTime: 4_102_444_800,
+      currentBid: 0,
+      settled: false,
+      reserveMet: false,
+    },
+    "current",
+    1 // Live
+  );
+}
+
+/** How many Commoner mints have metadata; each can be minted once per run. */
+const COMMONER_COUNT = 40;
+
+/**
+ * Metadata for the Commoner NFTs the governance tests vote with, verified
+ * members of the test collection — plus one whose membership is unverified.
+ */
+function commonerMetadata() {
+  const collection = seeded("commoner-collection").publicKey;
+  for (let i = 0; i < COMMONER_COUNT; i++) {
+    writeMetadata(`commoner-${i}`, seeded(`commoner-${i}`).publicKey, {
+      collection: { key: collection, verified: true },
+    });
+  }
+  writeMetadata("commoner-unverified", seeded("commoner-unverified").publicKey, {
+    collection: { key: collection, verified: false },
+  });
+}
+
+// GovernanceProposal::STATUS_*
+const STATUS_ACTIVE = 0;
+const STATUS_PASSED = 1;
+const STATUS_PENDING_APPROVAL = 6;
+
+/** November 2023: a proposal whose voting ended then is closed. */
+const VOTING_CLOSED = 1_700_000_000;
+
+/**
+ * Proposals whose voting closed in 2023, for finalize_proposal: one with
+ * quorum and a yes majority, one short of quorum (of 120 Commoners).
+ */
+function finalizableProposals() {
+  writeProposal("proposal-passing", {
+    proposalId: 9_100_001,
+    endsAt: VOTING_CLOSED,
+    yes: 30,
+    no: 5,
+    status: STATUS_ACTIVE,
+  });
+  writeProposal("proposal-no-quorum", {
+    proposalId: 9_100_002,
+    endsAt: VOTING_CLOSED,
+    yes: 1,
+    status: STATUS_ACTIVE,
+  });
+}
+
+/**
+ * Passed treasury proposals paying 0.1 SOL: one whose execution delay ran
+ * out long ago, one still inside it.
+ */
+function treasuryProposals() {
+  const recipient = seeded("treasury-recipient").publicKey;
+  writeProposal("proposal-treasury", {
+    proposalId: 9_100_003,
+    endsAt: VOTING_CLOSED,
+    yes: 30,
+    status: STATUS_PASSED,
+    treasurySol: 100_000_000,
+    recipient,
+  });
+  writeProposal("proposal-treasury-delayed", {
+    proposalId: 9_100_004,
+    endsAt: 4_000_000_000,
+    yes: 30,
+    status: STATUS_PASSED,
+    treasurySol: 100_000_000,
+    recipient,
+  });
+}
+
+/** Fields of ProposalParamUpdate, all Options, in declaration order. */
+const PROPOSAL_PARAM_UPDATE_FIELDS = 7;
+/** Index of `bid_increment_bps` among them. */
+const BID_INCREMENT_FIELD = 1;
+
+/** A passed parameter proposal setting the bid increment to 10%. */
+function paramProposal() {
+  const update = new Writer();
+  for (let i = 0; i < PROPOSAL_PARAM_UPDATE_FIELDS; i++) {
+    if (i === BID_INCREMENT_FIELD) {
+      update.u8(1).u16(1_000);
+    } else {
+      update.u8(0);
+    }
+  }
+  writeProposal("proposal-param", {
+    proposalId: 9_100_005,
+    endsAt: VOTING_CLOSED,
+    yes: 30,
+    status: STATUS_PASSED,
+    paramUpdate: update.build(),
+  });
+}
+
+/** ProgramConfig::DEFAULT_PROPOSAL_DEPOSIT_LAMPORTS */
+const PROPOSAL_DEPOSIT = 500_000_000;
+
+/**
+ * Submitted proposals holding a deposit: one left awaiting approval since
+ * 2023, and two whose voting closed then, with and without quorum.
+ */
+function depositProposals() {
+  const proposer = seeded("deposit-proposer").publicKey;
+  writeProposal("proposal-expired-pending", {
+    proposalId: 9_100_006,
+    proposer,
+    createdAt: 1_700_000_000,
+    endsAt: 0,
+    status: STATUS_PENDING_APPROVAL,
+    depositLamports: PROPOSAL_DEPOSIT,
+  });
+  writeProposal("proposal-deposit-quorum", {
+    proposalId: 9_100_007,
+    proposer,
+    endsAt: VOTING_CLOSED,
+    yes: 30,
+    status: STATUS_ACTIVE,
+    depositLamports: PROPOSAL_DEPOSIT,
+  });
+  writeProposal("proposal-deposit-no-quorum", {
+    proposalId: 9_100_008,
+    proposer,
+    endsAt: VOTING_CLOSED,
+    yes: 1,
+    status: STATUS_ACTIVE,
+    depositLamports: PROPOSAL_DEPOSIT,
+  });
+}
+
+/**
+ * Borsh ProposalParamUpdate with every field set, numbers to zero: the
+ * longest encoding, as a proposal touching every parameter would have.
+ */
+function fullParamUpdate(): Buffer {
+  const w = new Writer();
+  const some = (len: number) => w.u8(1).bytes(Buffer.alloc(len));
+  some(2); // fee_bps
+  some(2); // bid_increment_bps
+  some(8); // time_buffer_secs
+  some(8); // min_reserve_lamports
+  some(4 * 10); // discount_tiers
+  w.u8(1);
+  some(32); // required_collection: Some(Some(_))
+  some(8); // max_vote_weight
+  return w.build();
+}
+
+/**
+ * A proposal created before content_uri and content_hash, with every string
+ * and the parameter update at full length, so its account has no zeroed
+ * capacity left to decode the new fields from and migrate_proposal grows it.
+ */
+function legacyProposal() {
+  writeProposal("proposal-legacy", {
+    proposalId: 9_100_009,
+    endsAt: VOTING_CLOSED,
+    status: STATUS_PASSED,
+    title: "Legacy proposal ".padEnd(100, "."),
+    description: "d".repeat(800),
+    proposalType: "param-update".padEnd(50, "-"),
+    paramUpdate: fullParamUpdate(),
+    legacy: true,
+  });
+}
+
+function main() {
+  fs.mkdirSync(OUT_DIR, { recursive: true });
+  legacySlot();
+  royaltyMetadata();
+  baselineAuction();
+  staleClosedAuction();
+  staleAuction();
+  unrefundedAuction();
+  unloggedAuction();
+  commonerMetadata();
+  finalizableProposals();
+  treasuryProposals();
+  paramProposal();
+  depositProposals();
+  legacyProposal();
+}
+
+main();
@@ -91,9 +91,44 @@ async function main() {
     [Buffer.from("program-config")],
     PROGRAM_ID
   );
+  // Queued behind the param timelock, which is waited out before executing —
+  // initialize devnet with a short PARAM_UPDATE_DELAY_SECS.
+  const [pending] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-params")],
+    PROGRAM_ID
+  );
+  await program.methods
+    .queueParamUpdate({
+      feeBps: null,
+      bidIncrementBps: null,
+      timeBufferSecs: null,
+      minReserveLamports: new BN(10_000_000),
+      commonTokenMint: null,
+      discountTiers: null,
+      referralBps: null,
+      settlerRewardLamports: null,
+      keeperRewardLamports: null,
+      requiredCollection: null,
+      paramUpdateDelaySecs: null,
+      commonerCollection: null,
+      quorumBps: null,
+      approvalThresholdBps: null,
+      executionDelaySecs: null,
+      proposalDepositLamports: null,
+      proposalApprovalRequired: null,
+      maxVoteWeight: null,
+      feeRecipients: null,
+      royaltiesEnabled: null,
+      royaltyCapBps: null,
+    })
+    .accounts({ admin: payer.publicKey, config, pending } as any)
+    .rpc();
+  const { eta } = await program.account.pendingParamUpdate.fetch(pending);
+  const timelockMs = (Math.max(0, eta.toNumber() - Math.floor(Date.now() / 1000)) + 1) * 1000;
+  await new Promise((resolve) => setTimeout(resolve, timelockMs));
   await program.methods
-    .updateParams(null, null, null, new BN(10_000_000), null, null)
-    .accounts({ admin: payer.publicKey, config })
+    .executeParamUpdate()
+    .accounts({ config, pending, proposer: payer.publicKey } as any)
     .rpc();
   console.log("✓ min_reserve_lamports = 10,000,000 (0.01 SOL)\n");
 
@@ -1,16 +1,2404 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { IdlTypes, Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+} from "@solana/web3.js";
+import {
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+  transfer,
+} from "@solana/spl-token";
+import BN from "bn.js";
+import { expect } from "chai";
+import { createHash } from "crypto";
 import { CommonersAuction } from "../target/types/commoners_auction";
 
+const BPF_LOADER_UPGRADEABLE = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+// SlotRegistration::MAX_RELIST_LOOKAHEAD_DAYS in the program.
+const MAX_RELIST_LOOKAHEAD_DAYS = 10;
+
+// Accounts preloaded from tests/fixtures (see Anchor.toml) — keep in sync
+// with scripts/generate-test-fixtures.ts.
+const LEGACY_SLOT_DATE = 1_700_006_400;
+const BASELINE_AUCTION_ID = 9_000_001;
+const STALE_CLOSED_AUCTION_ID = 9_000_002;
+const STALE_AUCTION_DATE = 1_700_092_800;
+const UNLOGGED_AUCTION_ID = 9_000_003;
+const UNREFUNDED_AUCTION_ID = 9_000_004;
+const PASSING_PROPOSAL_ID = 9_100_001;
+const NO_QUORUM_PROPOSAL_ID = 9_100_002;
+const TREASURY_PROPOSAL_ID = 9_100_003;
+const DELAYED_TREASURY_PROPOSAL_ID = 9_100_004;
+const PARAM_PROPOSAL_ID = 9_100_005;
+const EXPIRED_PENDING_PROPOSAL_ID = 9_100_006;
+const DEPOSIT_QUORUM_PROPOSAL_ID = 9_100_007;
+const DEPOSIT_NO_QUORUM_PROPOSAL_ID = 9_100_008;
+const LEGACY_PROPOSAL_ID = 9_100_009;
+
+type ParamUpdate = IdlTypes<CommonersAuction>["paramUpdate"];
+type ProposalParamUpdate = IdlTypes<CommonersAuction>["proposalParamUpdate"];
+
+/** Keypair derived from a label, as in scripts/generate-test-fixtures.ts. */
+function seeded(label: string): Keypair {
+  return Keypair.fromSeed(createHash("sha256").update(label).digest());
+}
+
+function sleep(ms: number) {
+  return new Promise((r) => setTimeout(r, ms));
+}
+
+function nextMidnight(timestamp: number): number {
+  return (Math.floor(timestamp / 86_400) + 1) * 86_400;
+}
+
+/** The error code a call was rejected with, undefined if it succeeded. */
+async function errorCode(call: Promise<unknown>): Promise<string | undefined> {
+  try {
+    await call;
+  } catch (err: any) {
+    return err?.error?.errorCode?.code ?? "UnknownError";
+  }
+  return undefined;
+}
+
 describe("commoners-auction", () => {
   // Configure the client to use the local cluster.
-  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
 
   const program = anchor.workspace.commonersAuction as Program<CommonersAuction>;
 
-  it("Is initialized!", async () => {
-    // Add your test here.
-    const tx = await program.methods.initialize().rpc();
-    console.log("Your transaction signature", tx);
+  const [configAddress] = PublicKey.findProgramAddressSync(
+    [Buffer.from("program-config")],
+    program.programId
+  );
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    BPF_LOADER_UPGRADEABLE
+  );
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  );
+  const paramDelaySecs = 2;
+  const admin = provider.wallet.publicKey;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const connection = provider.connection;
+
+  const [pendingParamsAddress] = PublicKey.findProgramAddressSync(
+    [Buffer.from("pending-params")],
+    program.programId
+  );
+
+  const u64 = (value: number) => new BN(value).toArrayLike(Buffer, "le", 8);
+  const pda = (...seeds: Buffer[]) =>
+    PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+  const auctionAddress = (id: number) => pda(Buffer.from("auction"), u64(id));
+  const bidVaultAddress = (id: number) => pda(Buffer.from("bid-vault"), u64(id));
+  const bidLogAddress = (id: number) => pda(Buffer.from("bid-log"), u64(id));
+  const slotAddress = (mint: PublicKey, date: number) =>
+    pda(Buffer.from("slot"), mint.toBuffer(), u64(date));
+  const dateAddress = (date: number) => pda(Buffer.from("date"), u64(date));
+  const ata = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, owner, true);
+  const metadataAddress = (mint: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("metadata"), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+      TOKEN_METADATA_PROGRAM_ID
+    )[0];
+
+  async function airdrop(to: PublicKey, sol = 10) {
+    const sig = await connection.requestAirdrop(to, sol * LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(sig, "confirmed");
+  }
+
+  async function funded(sol = 10): Promise<Keypair> {
+    const keypair = Keypair.generate();
+    await airdrop(keypair.publicKey, sol);
+    return keypair;
+  }
+
+  /** The validator clock programs see; it can lag wall time. */
+  async function chainTime(): Promise<number> {
+    const clock = await connection.getAccountInfo(SYSVAR_CLOCK_PUBKEY);
+    return Number(clock!.data.readBigInt64LE(32));
+  }
+
+  async function waitUntil(timestamp: number) {
+    while ((await chainTime()) < timestamp) await sleep(500);
+  }
+
+  async function balance(address: PublicKey): Promise<number> {
+    return connection.getBalance(address);
+  }
+
+  async function tokenBalance(account: PublicKey): Promise<number> {
+    return Number((await getAccount(connection, account)).amount);
+  }
+
+  /** Events emitted by a confirmed transaction. */
+  async function eventsOf(signature: string): Promise<{ name: string; data: any }[]> {
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+  }
+
+  /** Data of the `name` event a confirmed transaction emitted. */
+  async function eventOf(signature: string, name: string): Promise<any> {
+    const event = (await eventsOf(signature)).find((e) => e.name === name);
+    expect(event, `${name} event`).to.not.be.undefined;
+    return event!.data;
+  }
+
+  const paramUpdate = (changes: Partial<ParamUpdate>): ParamUpdate => ({
+    feeBps: null,
+    bidIncrementBps: null,
+    timeBufferSecs: null,
+    minReserveLamports: null,
+    commonTokenMint: null,
+    discountTiers: null,
+    referralBps: null,
+    settlerRewardLamports: null,
+    keeperRewardLamports: null,
+    requiredCollection: null,
+    paramUpdateDelaySecs: null,
+    commonerCollection: null,
+    quorumBps: null,
+    approvalThresholdBps: null,
+    executionDelaySecs: null,
+    proposalDepositLamports: null,
+    proposalApprovalRequired: null,
+    maxVoteWeight: null,
+    feeRecipients: null,
+    royaltiesEnabled: null,
+    royaltyCapBps: null,
+    ...changes,
+  });
+
+  const proposalParamUpdate = (changes: Partial<ProposalParamUpdate>): ProposalParamUpdate => ({
+    feeBps: null,
+    bidIncrementBps: null,
+    timeBufferSecs: null,
+    minReserveLamports: null,
+    discountTiers: null,
+    requiredCollection: null,
+    maxVoteWeight: null,
+    ...changes,
+  });
+
+  function queueParams(changes: Partial<ParamUpdate>) {
+    return program.methods
+      .queueParamUpdate(paramUpdate(changes))
+      .accountsPartial({ admin, config: configAddress, pending: pendingParamsAddress });
+  }
+
+  /** Queue `changes`, wait out the timelock and apply them. */
+  async function applyParams(changes: Partial<ParamUpdate>) {
+    await queueParams(changes).rpc();
+    const pending = await program.account.pendingParamUpdate.fetch(pendingParamsAddress);
+    await waitUntil(pending.eta.toNumber());
+    await program.methods
+      .executeParamUpdate()
+      .accountsPartial({ config: configAddress, pending: pendingParamsAddress, proposer: admin })
+      .rpc();
+  }
+
+  /** Apply `changes` for the enclosing describe block, then `restore` after it. */
+  function withParams(changes: Partial<ParamUpdate>, restore: Partial<ParamUpdate>) {
+    before(() => applyParams(changes));
+    after(() => applyParams(restore));
+  }
+
+  async function mintNft(owner: PublicKey, mint?: Keypair): Promise<PublicKey> {
+    const address = await createMint(connection, payer, admin, null, 0, mint);
+    const account = await getOrCreateAssociatedTokenAccount(connection, payer, address, owner);
+    await mintTo(connection, payer, address, account.address, payer, 1);
+    return address;
+  }
+
+  async function moveNft(mint: PublicKey, from: Keypair, to: PublicKey) {
+    const destination = await getOrCreateAssociatedTokenAccount(connection, payer, mint, to);
+    await transfer(connection, payer, ata(mint, from.publicKey), destination.address, from, 1);
+  }
+
+  interface ListingOptions {
+    reserve?: number;
+    autoRelist?: boolean;
+    reserveDecayBps?: number;
+  }
+
+  function listSlot(seller: Keypair, mint: PublicKey, date: number, opts: ListingOptions = {}) {
+    const slot = slotAddress(mint, date);
+    return program.methods
+      .listSlot(
+        new BN(date),
+        new BN(opts.reserve ?? 500_000_000),
+        opts.autoRelist ?? false,
+        opts.reserveDecayBps ?? 0
+      )
+      .accountsPartial({
+        holder: seller.publicKey,
+        config: configAddress,
+        nftMint: mint,
+        nftMetadata: metadataAddress(mint),
+        holderTokenAccount: ata(mint, seller.publicKey),
+        escrowTokenAccount: ata(mint, slot),
+        slot,
+        dateReservation: dateAddress(date),
+      })
+      .signers([seller]);
+  }
+
+  interface TestAuction {
+    id: number;
+    mint: PublicKey;
+    seller: Keypair;
+    address: PublicKey;
+    slot: PublicKey;
+    escrow: PublicKey;
+    bidVault: PublicKey;
+    bidLog: PublicKey;
+    endTime: number;
+  }
+
+  // Auctions are keyed by their scheduled day; tomorrow is left for
+  // auto-relisted slots.
+  let nextAuctionDay = 2;
+
+  /** The next unused auction day, skipping dates auto-relists booked. */
+  async function nextAuctionDate(): Promise<number> {
+    const today = Math.floor((await chainTime()) / 86_400) * 86_400;
+    let date = today + 86_400 * nextAuctionDay++;
+    while (await connection.getAccountInfo(dateAddress(date))) {
+      date = today + 86_400 * nextAuctionDay++;
+    }
+    return date;
+  }
+
+  /**
+   * List a fresh NFT for the next unused day and open its auction straight
+   * away, ending `durationSecs` from now.
+   */
+  async function openAuction(
+    opts: ListingOptions & { seller?: Keypair; mint?: PublicKey; durationSecs?: number } = {}
+  ): Promise<TestAuction> {
+    const seller = opts.seller ?? (await funded());
+    const mint = opts.mint ?? (await mintNft(seller.publicKey));
+    const id = await nextAuctionDate();
+    const slot = slotAddress(mint, id);
+    await listSlot(seller, mint, id, opts).rpc();
+
+    const endTime = (await chainTime()) + (opts.durationSecs ?? 8);
+    await program.methods
+      .createAuction(new BN(id), new BN(endTime))
+      .accountsPartial({
+        crankAuthority: admin,
+        config: configAddress,
+        nftMint: mint,
+        slot,
+        auction: auctionAddress(id),
+        bidLog: bidLogAddress(id),
+      })
+      .rpc();
+
+    return {
+      id,
+      mint,
+      seller,
+      address: auctionAddress(id),
+      slot,
+      escrow: ata(mint, slot),
+      bidVault: bidVaultAddress(id),
+      bidLog: bidLogAddress(id),
+      endTime,
+    };
+  }
+
+  async function placeBid(
+    auction: TestAuction,
+    bidder: Keypair,
+    amount: number,
+    referrer: PublicKey | null = null
+  ) {
+    const state = await program.account.auctionState.fetch(auction.address);
+    return program.methods
+      .placeBid(new BN(amount), referrer)
+      .accountsPartial({
+        bidder: bidder.publicKey,
+        config: configAddress,
+        auction: auction.address,
+        bidVault: auction.bidVault,
+        bidLog: auction.bidLog,
+        prevBidder: state.currentBidder ?? bidder.publicKey,
+      })
+      .signers([bidder])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /**
+   * First date from tomorrow on that no listing has booked, and the
+   * reservations of the booked dates before it.
+   */
+  async function firstFreeDate(): Promise<{ date: number; booked: PublicKey[] }> {
+    let date = nextMidnight(await chainTime());
+    const booked: PublicKey[] = [];
+    while (await connection.getAccountInfo(dateAddress(date))) {
+      booked.push(dateAddress(date));
+      date += 86_400;
+    }
+    return { date, booked };
+  }
+
+  /** The reservation of `date` and the wallet its rent goes back to. */
+  async function reservationAccounts(date: number, fallback: PublicKey) {
+    const dateReservation = dateAddress(date);
+    const reservation = await program.account.dateReservation.fetchNullable(dateReservation);
+    return { dateReservation, dateReservationPayer: reservation?.payer ?? fallback };
+  }
+
+  /**
+   * An auction preloaded by scripts/generate-test-fixtures.ts, whose mint and
+   * seller are seeded from `label`. Only auctions with a slot, dated
+   * `slotDate`, have a slot and escrow.
+   */
+  function preloadedAuction(
+    label: string,
+    id: number,
+    endTime: number,
+    slotDate?: number
+  ): TestAuction {
+    const mint = seeded(`${label}-mint`).publicKey;
+    const slot = slotDate === undefined ? PublicKey.default : slotAddress(mint, slotDate);
+    return {
+      id,
+      mint,
+      seller: seeded(`${label}-seller`),
+      address: auctionAddress(id),
+      slot,
+      escrow: slotDate === undefined ? PublicKey.default : ata(mint, slot),
+      bidVault: bidVaultAddress(id),
+      bidLog: bidLogAddress(id),
+      endTime,
+    };
+  }
+
+  /**
+   * Settle `auction` the way the crank does. The remaining accounts default
+   * to the configured fee recipients followed by `creators` when it sold,
+   * or to the booked dates an auto-relist has to skip when it did not.
+   */
+  async function settle(
+    auction: TestAuction,
+    opts: {
+      payer?: Keypair;
+      relistDate?: number;
+      referrer?: PublicKey | null;
+      creators?: PublicKey[];
+      remaining?: PublicKey[];
+    } = {}
+  ) {
+    const state = await program.account.auctionState.fetch(auction.address);
+    const slot = await program.account.slotRegistration.fetch(auction.slot);
+    const config = await program.account.programConfig.fetch(configAddress);
+    const winner = state.currentBidder ?? admin;
+
+    const sold = state.currentBidder !== null && state.currentBid.gte(state.reservePrice);
+
+    let relistSlot: PublicKey | null = null;
+    let relistEscrowTokenAccount: PublicKey | null = null;
+    let relistDateReservation: PublicKey | null = null;
+    let booked: PublicKey[] = [];
+    if (slot.autoRelist) {
+      let relistDate = opts.relistDate;
+      if (relistDate === undefined) ({ date: relistDate, booked } = await firstFreeDate());
+      relistSlot = slotAddress(auction.mint, relistDate);
+      relistEscrowTokenAccount = ata(auction.mint, relistSlot);
+      relistDateReservation = dateAddress(relistDate);
+    }
+
+    const remaining =
+      opts.remaining ??
+      (sold
+        ? [
+            ...config.feeRecipients.filter((r) => r.bps > 0).map((r) => r.recipient),
+            ...(opts.creators ?? []),
+          ]
+        : booked);
+    const call = program.methods
+      .settleAuction(opts.relistDate === undefined ? null : new BN(opts.relistDate))
+      .accountsPartial({
+        payer: opts.payer?.publicKey ?? admin,
+        config: configAddress,
+        auction: auction.address,
+        slot: auction.slot,
+        nftMint: auction.mint,
+        nftMetadata: metadataAddress(auction.mint),
+        ...(await reservationAccounts(slot.scheduledDate.toNumber(), auction.seller.publicKey)),
+        escrowTokenAccount: auction.escrow,
+        winnerTokenAccount: ata(auction.mint, winner),
+        sellerTokenAccount: ata(auction.mint, auction.seller.publicKey),
+        bidVault: auction.bidVault,
+        seller: auction.seller.publicKey,
+        winner,
+        treasury,
+        referrer: opts.referrer === undefined ? state.currentReferrer : opts.referrer,
+        relistSlot,
+        relistEscrowTokenAccount,
+        relistDateReservation,
+      })
+      .remainingAccounts(
+        remaining.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      );
+    return (opts.payer ? call.signers([opts.payer]) : call).rpc({ commitment: "confirmed" });
+  }
+
+  const COMMONER_COLLECTION = seeded("commoner-collection").publicKey;
+  const proposalAddress = (id: number) => pda(Buffer.from("proposal"), u64(id));
+  const voteRecordAddress = (id: number, voter: PublicKey) =>
+    pda(Buffer.from("vote"), u64(id), voter.toBuffer());
+  const voteMarkerAddress = (id: number, mint: PublicKey) =>
+    pda(Buffer.from("nft-vote"), u64(id), mint.toBuffer());
+  const delegationAddress = (holder: PublicKey) =>
+    pda(Buffer.from("delegation"), holder.toBuffer());
+
+  // Commoner mints with preloaded metadata; each can be minted once.
+  let nextCommoner = 0;
+  const mintCommoner = (owner: PublicKey) =>
+    mintNft(owner, seeded(`commoner-${nextCommoner++}`));
+
+  type ProposalContent = IdlTypes<CommonersAuction>["proposalContent"];
+
+  interface ProposalOptions {
+    content?: ProposalContent;
+    treasurySol?: number;
+    recipient?: PublicKey;
+    paramUpdate?: ProposalParamUpdate | null;
+    durationSecs?: number;
+  }
+
+  /** The ID the next created or submitted proposal takes. */
+  async function nextProposalId(): Promise<number> {
+    const config = await program.account.programConfig.fetch(configAddress);
+    return config.nextProposalId.toNumber();
+  }
+
+  async function createProposal(opts: ProposalOptions = {}) {
+    const id = await nextProposalId();
+    const call = program.methods
+      .createProposal(
+        admin,
+        "Test proposal",
+        opts.content ?? { inline: { description: "A proposal under test." } },
+        opts.paramUpdate ? "param-update" : "community-initiative",
+        new BN(opts.treasurySol ?? 0),
+        opts.recipient ?? admin,
+        opts.paramUpdate ?? null,
+        new BN(opts.durationSecs ?? 3_600)
+      )
+      .accountsPartial({
+        proposalManager: admin,
+        config: configAddress,
+        proposal: proposalAddress(id),
+      });
+    return { id, call };
+  }
+
+  async function openProposal(opts: ProposalOptions = {}): Promise<number> {
+    const { id, call } = await createProposal(opts);
+    await call.rpc();
+    return id;
+  }
+
+  function fetchProposal(id: number) {
+    return program.account.governanceProposal.fetch(proposalAddress(id));
+  }
+
+  async function finalizeProposal(id: number) {
+    const proposal = await fetchProposal(id);
+    return program.methods
+      .finalizeProposal(new BN(id))
+      .accountsPartial({
+        config: configAddress,
+        proposal: proposalAddress(id),
+        proposer: proposal.proposer,
+        treasury,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  async function executeProposal(id: number) {
+    const proposal = await fetchProposal(id);
+    return program.methods
+      .executeProposal(new BN(id))
+      .accountsPartial({
+        config: configAddress,
+        proposal: proposalAddress(id),
+        treasury,
+        recipient: proposal.recipient,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  /** A Commoner counted in a vote: its mint and the wallet holding it. */
+  interface Holding {
+    mint: PublicKey;
+    owner: PublicKey;
+  }
+
+  /**
+   * Cast `voter`'s [yes, no, abstain] with the Commoners in `holdings`,
+   * preceded by the delegations to count and the delegate vote records
+   * being overridden.
+   */
+  function castVote(
+    proposalId: number,
+    voter: Keypair,
+    [yes, no, abstain]: number[],
+    holdings: Holding[],
+    opts: { delegations?: PublicKey[]; overridden?: PublicKey[]; markersOf?: number } = {}
+  ) {
+    const delegations = opts.delegations ?? [];
+    const overridden = opts.overridden ?? [];
+    const markersOf = opts.markersOf ?? proposalId;
+    return program.methods
+      .castVote(
+        new BN(proposalId),
+        new BN(yes),
+        new BN(no),
+        new BN(abstain),
+        delegations.length,
+        overridden.length
+      )
+      .accountsPartial({
+        voter: voter.publicKey,
+        config: configAddress,
+        proposal: proposalAddress(proposalId),
+        voteRecord: voteRecordAddress(proposalId, voter.publicKey),
+      })
+      .remainingAccounts([
+        ...delegations.map((holder) => ({
+          pubkey: delegationAddress(holder),
+          isSigner: false,
+          isWritable: false,
+        })),
+        ...overridden.map((delegate) => ({
+          pubkey: voteRecordAddress(proposalId, delegate),
+          isSigner: false,
+          isWritable: true,
+        })),
+        ...holdings.flatMap(({ mint, owner }) => [
+          { pubkey: ata(mint, owner), isSigner: false, isWritable: false },
+          { pubkey: metadataAddress(mint), isSigner: false, isWritable: false },
+          { pubkey: voteMarkerAddress(markersOf, mint), isSigner: false, isWritable: true },
+        ]),
+      ])
+      .signers([voter])
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function initializeProgram(admin: PublicKey) {
+    return program.methods
+      .initializeProgram(
+        900,
+        500,
+        new BN(600),
+        new BN(420_000_000),
+        null,
+        new BN(paramDelaySecs)
+      )
+      .accountsPartial({
+        admin,
+        config: configAddress,
+        program: program.programId,
+        programData,
+        systemProgram: SystemProgram.programId,
+      });
+  }
+
+  describe("initialize_program", () => {
+    it("rejects a signer that is not the upgrade authority", async () => {
+      const attacker = Keypair.generate();
+      const sig = await provider.connection.requestAirdrop(
+        attacker.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig, "confirmed");
+
+      const code = await errorCode(
+        initializeProgram(attacker.publicKey).signers([attacker]).rpc()
+      );
+      expect(code).to.equal("Unauthorized");
+
+      const config = await provider.connection.getAccountInfo(configAddress);
+      expect(config).to.be.null;
+    });
+
+    it("lets the upgrade authority initialize and become admin", async () => {
+      await initializeProgram(provider.wallet.publicKey).rpc();
+
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.admin.toBase58()).to.equal(
+        provider.wallet.publicKey.toBase58()
+      );
+      expect(config.version).to.equal(2);
+    });
+  });
+
+  describe("param updates", () => {
+    const [pendingAddress] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending-params")],
+      program.programId
+    );
+
+    const accounts = {
+      config: configAddress,
+      pending: pendingAddress,
+      proposer: provider.wallet.publicKey,
+    };
+
+    it("keeps the applied value until the queued update executes", async () => {
+      await program.methods
+        .queueParamUpdate(paramUpdate({ feeBps: 10_000 }))
+        .accountsPartial({ admin: provider.wallet.publicKey, ...accounts })
+        .rpc();
+
+      const pending = await program.account.pendingParamUpdate.fetch(pendingAddress);
+      expect(pending.update.feeBps).to.equal(10_000);
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.defaultFeeBps).to.equal(900);
+
+      const code = await errorCode(
+        program.methods.executeParamUpdate().accountsPartial(accounts).rpc()
+      );
+      expect(code).to.equal("ParamUpdateNotReady");
+    });
+
+    it("refuses to drop the timelock to zero", async () => {
+      const code = await errorCode(
+        program.methods
+          .queueParamUpdate(paramUpdate({ paramUpdateDelaySecs: new BN(0) }))
+          .accountsPartial({ admin: provider.wallet.publicKey, ...accounts })
+          .rpc()
+      );
+      expect(code).to.equal("InvalidParamUpdateDelay");
+    });
+
+    it("lets the admin cancel a queued update", async () => {
+      await program.methods
+        .cancelParamUpdate()
+        .accountsPartial({ admin: provider.wallet.publicKey, ...accounts })
+        .rpc();
+
+      const pending = await provider.connection.getAccountInfo(pendingAddress);
+      expect(pending).to.be.null;
+    });
+
+    it("applies the update once the delay has passed", async () => {
+      await program.methods
+        .queueParamUpdate(paramUpdate({ feeBps: 800 }))
+        .accountsPartial({ admin: provider.wallet.publicKey, ...accounts })
+        .rpc();
+      await sleep((paramDelaySecs + 1) * 1000);
+
+      await program.methods.executeParamUpdate().accountsPartial(accounts).rpc();
+
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.defaultFeeBps).to.equal(800);
+    });
+  });
+
+  describe("auto-relist", () => {
+    before(async () => {
+      // No anti-snipe extension, so short test auctions end on time.
+      await applyParams({ timeBufferSecs: new BN(0) });
+    });
+
+    /** An auto-relist auction that ended without bids. */
+    async function unsoldAuction(opts: ListingOptions = {}): Promise<TestAuction> {
+      const auction = await openAuction({ autoRelist: true, ...opts });
+      await waitUntil(auction.endTime);
+      return auction;
+    }
+
+    const relistedSlot = (auction: TestAuction, date: number) =>
+      program.account.slotRegistration.fetch(slotAddress(auction.mint, date));
+
+    it("rejects a reserve decay above 100%", async () => {
+      const seller = await funded();
+      const mint = await mintNft(seller.publicKey);
+      const date = nextMidnight(await chainTime()) + 86_400 * 300;
+
+      const code = await errorCode(
+        listSlot(seller, mint, date, { autoRelist: true, reserveDecayBps: 10_001 }).rpc()
+      );
+      expect(code).to.equal("InvalidReserveDecay");
+    });
+
+    it("relists an unsold NFT for the next day with a decayed reserve", async () => {
+      const auction = await unsoldAuction({ reserveDecayBps: 1_000, reserve: 1_000_000_000 });
+      const keeper = await funded();
+
+      // Only the seller may choose the date.
+      const code = await errorCode(
+        settle(auction, { payer: keeper, relistDate: auction.id + 86_400 * 30 })
+      );
+      expect(code).to.equal("RelistDateNotAllowed");
+
+      const relistDate = nextMidnight(await chainTime());
+      expect(await connection.getAccountInfo(dateAddress(relistDate))).to.be.null;
+      const keeperBefore = await balance(keeper.publicKey);
+      await settle(auction, { payer: keeper });
+
+      // The seller's old slot and escrow refund the keeper for the new ones;
+      // only the date reservation stays on it until the date is used up.
+      expect(await connection.getAccountInfo(auction.slot)).to.be.null;
+      const reservationRent = await connection.getMinimumBalanceForRentExemption(81);
+      expect(await balance(keeper.publicKey)).to.equal(keeperBefore - reservationRent);
+
+      const relistSlot = slotAddress(auction.mint, relistDate);
+      const relisted = await relistedSlot(auction, relistDate);
+      expect(relisted.owner.toBase58()).to.equal(auction.seller.publicKey.toBase58());
+      expect(relisted.reservePrice.toNumber()).to.equal(900_000_000);
+      expect(relisted.autoRelist).to.be.true;
+      expect(relisted.consumed).to.be.false;
+      expect(await tokenBalance(ata(auction.mint, relistSlot))).to.equal(1);
+      expect(await connection.getAccountInfo(auction.escrow)).to.be.null;
+
+      const state = await program.account.auctionState.fetch(auction.address);
+      expect(state.status).to.deep.equal({ settledUnsold: {} });
+    });
+
+    it("lets the seller pick the relist date", async () => {
+      const auction = await unsoldAuction();
+
+      // Far past any day the other tests list for.
+      const relistDate = auction.id + 86_400 * 1_000;
+      await settle(auction, { payer: auction.seller, relistDate });
+
+      const relisted = await relistedSlot(auction, relistDate);
+      expect(relisted.scheduledDate.toNumber()).to.equal(relistDate);
+      expect(relisted.reservePrice.toNumber()).to.equal(500_000_000);
+    });
+
+    it("refuses a relist date another NFT has booked", async () => {
+      const booked = await openAuction({ durationSecs: 60 });
+      const auction = await unsoldAuction();
+
+      const code = await errorCode(
+        settle(auction, { payer: auction.seller, relistDate: booked.id })
+      );
+      expect(code).to.equal("SlotTaken");
+    });
+
+    it("relists past booked dates to the first free one", async () => {
+      // Tomorrow was booked by the first relist above.
+      const tomorrow = nextMidnight(await chainTime());
+      expect(await connection.getAccountInfo(dateAddress(tomorrow))).to.not.be.null;
+
+      const auction = await unsoldAuction();
+      const { date, booked } = await firstFreeDate();
+      expect(booked.length).to.be.greaterThan(0);
+
+      // A free date cannot stand in for a booked one.
+      const code = await errorCode(settle(auction, { remaining: [dateAddress(date)] }));
+      expect(code).to.equal("BookedDateMismatch");
+
+      // The search gives up past the lookahead rather than the account limit.
+      const tooMany = Array(MAX_RELIST_LOOKAHEAD_DAYS + 1).fill(booked[0]);
+      const full = await errorCode(settle(auction, { remaining: tooMany }));
+      expect(full).to.equal("NoFreeRelistDate");
+
+      await settle(auction);
+      const relisted = await relistedSlot(auction, date);
+      expect(relisted.scheduledDate.toNumber()).to.equal(date);
+      const reservation = await program.account.dateReservation.fetch(dateAddress(date));
+      expect(reservation.nftMint.toBase58()).to.equal(auction.mint.toBase58());
+      expect(reservation.payer.toBase58()).to.equal(admin.toBase58());
+      expect(await connection.getAccountInfo(dateAddress(auction.id))).to.be.null;
+    });
+
+    it("returns the NFT once the seller switches auto-relist off", async () => {
+      const auction = await openAuction({ autoRelist: true });
+      const setAutoRelist = (owner: Keypair, enabled: boolean) =>
+        program.methods
+          .setAutoRelist(enabled)
+          .accountsPartial({ owner: owner.publicKey, slot: auction.slot })
+          .signers([owner])
+          .rpc();
+
+      const code = await errorCode(setAutoRelist(await funded(), false));
+      expect(code).to.equal("SellerMismatch");
+
+      await setAutoRelist(auction.seller, false);
+      await waitUntil(auction.endTime);
+      await settle(auction);
+
+      const sellerAta = ata(auction.mint, auction.seller.publicKey);
+      expect(await tokenBalance(sellerAta)).to.equal(1);
+      const { date } = await firstFreeDate();
+      expect(await connection.getAccountInfo(slotAddress(auction.mint, date))).to.be.null;
+    });
+  });
+
+  describe("migrate_slot", () => {
+    const legacyMint = seeded("legacy-slot-mint").publicKey;
+    const legacySlot = slotAddress(legacyMint, LEGACY_SLOT_DATE);
+
+    it("grows a slot listed before auto-relist, with auto-relist off", async () => {
+      const before = await connection.getAccountInfo(legacySlot);
+      expect(before!.data.length).to.equal(91);
+
+      await program.methods
+        .migrateSlot(legacyMint, new BN(LEGACY_SLOT_DATE))
+        .accountsPartial({ payer: admin, slot: legacySlot })
+        .rpc();
+
+      const after = await connection.getAccountInfo(legacySlot);
+      expect(after!.data.length).to.equal(94);
+      const slot = await program.account.slotRegistration.fetch(legacySlot);
+      expect(slot.reservePrice.toNumber()).to.equal(500_000_000);
+      expect(slot.escrowed).to.be.true;
+      expect(slot.autoRelist).to.be.false;
+      expect(slot.reserveDecayBps).to.equal(0);
+    });
+
+    it("rejects an address that holds no slot", async () => {
+      const code = await errorCode(
+        program.methods
+          .migrateSlot(Keypair.generate().publicKey, new BN(LEGACY_SLOT_DATE))
+          .accountsPartial({ payer: admin })
+          .rpc()
+      );
+      expect(code).to.equal("InvalidSlotAccount");
+    });
+  });
+
+  describe("offers", () => {
+    const offerAddress = (mint: PublicKey, buyer: PublicKey) =>
+      pda(Buffer.from("offer"), mint.toBuffer(), buyer.toBuffer());
+
+    function makeOffer(buyer: Keypair, mint: PublicKey, amount: number) {
+      return program.methods
+        .makeOffer(new BN(amount))
+        .accountsPartial({
+          buyer: buyer.publicKey,
+          config: configAddress,
+          nftMint: mint,
+          nftMetadata: metadataAddress(mint),
+          offer: offerAddress(mint, buyer.publicKey),
+        })
+        .signers([buyer])
+        .rpc();
+    }
+
+    function acceptOffer(holder: Keypair, buyer: PublicKey, mint: PublicKey, expected: number) {
+      return program.methods
+        .acceptOffer(new BN(expected))
+        .accountsPartial({
+          holder: holder.publicKey,
+          config: configAddress,
+          offer: offerAddress(mint, buyer),
+          nftMint: mint,
+          holderTokenAccount: ata(mint, holder.publicKey),
+          buyerTokenAccount: ata(mint, buyer),
+          buyer,
+          treasury,
+        })
+        .signers([holder])
+        .rpc();
+    }
+
+    /** A fresh buyer's offer of `amount` on a fresh NFT held by `holder`. */
+    async function openOffer(amount: number, holder: PublicKey = admin) {
+      const buyer = await funded();
+      const mint = await mintNft(holder);
+      await makeOffer(buyer, mint, amount);
+      return { buyer, mint, offer: offerAddress(mint, buyer.publicKey) };
+    }
+
+    it("rejects an offer below the minimum reserve", async () => {
+      const buyer = await funded();
+      const mint = await mintNft(admin);
+
+      const code = await errorCode(makeOffer(buyer, mint, 1_000));
+      expect(code).to.equal("OfferTooLow");
+    });
+
+    it("sells the NFT to the buyer at the offered amount", async () => {
+      const holder = await funded();
+      const amount = 2 * LAMPORTS_PER_SOL;
+      const { buyer, mint, offer } = await openOffer(amount, holder.publicKey);
+      expect((await program.account.offer.fetch(offer)).amount.toNumber()).to.equal(amount);
+
+      // The holder accepts the amount they saw, not whatever is escrowed now.
+      const code = await errorCode(acceptOffer(holder, buyer.publicKey, mint, amount + 1));
+      expect(code).to.equal("OfferAmountMismatch");
+
+      const config = await program.account.programConfig.fetch(configAddress);
+      const treasuryBefore = await balance(treasury);
+      await acceptOffer(holder, buyer.publicKey, mint, amount);
+
+      expect(await tokenBalance(ata(mint, buyer.publicKey))).to.equal(1);
+      expect(await tokenBalance(ata(mint, holder.publicKey))).to.equal(0);
+      expect(await balance(treasury)).to.equal(
+        treasuryBefore + (amount * config.defaultFeeBps) / 10_000
+      );
+      expect(await connection.getAccountInfo(offer)).to.be.null;
+    });
+
+    it("returns the escrowed amount when the buyer cancels", async () => {
+      const { buyer, offer } = await openOffer(LAMPORTS_PER_SOL);
+      const escrowed = await balance(offer);
+      const before = await balance(buyer.publicKey);
+      await program.methods
+        .cancelOffer()
+        .accountsPartial({ buyer: buyer.publicKey, offer })
+        .signers([buyer])
+        .rpc({ commitment: "confirmed" });
+
+      expect(await connection.getAccountInfo(offer)).to.be.null;
+      // Everything escrowed comes back, less the transaction fee.
+      expect(await balance(buyer.publicKey)).to.be.greaterThan(before + escrowed - 10_000);
+    });
+  });
+
+  describe("creator royalties", () => {
+    // Matches the royalty-metadata fixture: 5%, creators 60/30 verified and
+    // 10 unverified.
+    const creators = [0, 1, 2].map((i) => seeded(`royalty-creator-${i}`).publicKey);
+
+    withParams({ royaltiesEnabled: true }, { royaltiesEnabled: false });
+
+    it("pays the verified creators their shares out of the sale", async () => {
+      const seller = await funded();
+      const mint = await mintNft(seller.publicKey, seeded("royalty-mint"));
+      const auction = await openAuction({ seller, mint });
+      const bidder = await funded();
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, bidder, bid);
+      await waitUntil(auction.endTime);
+
+      // Verified creators must be passed in metadata order.
+      const code = await errorCode(settle(auction, { creators: [creators[1], creators[0]] }));
+      expect(code).to.equal("CreatorMismatch");
+
+      const sig = await settle(auction, { creators: creators.slice(0, 2) });
+
+      const pool = (bid * 500) / 10_000;
+      expect(await balance(creators[0])).to.equal((pool * 60) / 100);
+      expect(await balance(creators[1])).to.equal((pool * 30) / 100);
+      expect(await balance(creators[2])).to.equal(0);
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const settled = await eventOf(sig, "auctionSettled");
+      expect(settled.royalties.toNumber()).to.equal((pool * 90) / 100);
+      expect(settled.sellerProceeds.toNumber()).to.equal(bid - fee - (pool * 90) / 100);
+    });
+
+    it("leaves the shares of creators that can't be paid with the seller", async () => {
+      // royalty-unpayable fixture: an empty account at 1% (below rent
+      // exemption), the executable token program at 30%, creator 0 at 69%.
+      const dust = seeded("royalty-unpayable-creator").publicKey;
+      const seller = await funded();
+      const mint = await mintNft(seller.publicKey, seeded("royalty-unpayable-mint"));
+      const auction = await openAuction({ seller, mint });
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, await funded(), bid);
+      await waitUntil(auction.endTime);
+
+      const before = await balance(creators[0]);
+      const sig = await settle(auction, { creators: [dust, TOKEN_PROGRAM_ID, creators[0]] });
+
+      const pool = (bid * 500) / 10_000;
+      expect(await balance(dust)).to.equal(0);
+      expect(await balance(creators[0])).to.equal(before + (pool * 69) / 100);
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const settled = await eventOf(sig, "auctionSettled");
+      expect(settled.royalties.toNumber()).to.equal((pool * 69) / 100);
+      expect(settled.sellerProceeds.toNumber()).to.equal(bid - fee - (pool * 69) / 100);
+    });
+  });
+
+  describe("referrals", () => {
+    withParams({ referralBps: 2_000 }, { referralBps: 0 });
+
+    it("rejects a bidder naming themselves as referrer", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const bidder = await funded();
+
+      const code = await errorCode(placeBid(auction, bidder, LAMPORTS_PER_SOL, bidder.publicKey));
+      expect(code).to.equal("SelfReferral");
+    });
+
+    it("pays the winning bid's referrer their share of the fee", async () => {
+      const auction = await openAuction();
+      const bidder = await funded();
+      const referrer = (await funded(1)).publicKey;
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, bidder, bid, referrer);
+      await waitUntil(auction.endTime);
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const referrerBefore = await balance(referrer);
+      const treasuryBefore = await balance(treasury);
+      await settle(auction);
+
+      expect(await balance(referrer)).to.equal(referrerBefore + fee / 5);
+      expect(await balance(treasury)).to.equal(treasuryBefore + fee - fee / 5);
+    });
+  });
+
+  describe("fee recipients", () => {
+    const unused = { recipient: PublicKey.default, bps: 0 };
+    const table = (...used: { recipient: PublicKey; bps: number }[]) =>
+      [...used, unused, unused, unused, unused].slice(0, 4);
+    const ops = Keypair.generate().publicKey;
+    const dao = Keypair.generate().publicKey;
+
+    after(async () => {
+      await applyParams({ feeRecipients: table(), referralBps: 0 });
+    });
+
+    it("rejects a table naming the same wallet twice", async () => {
+      const code = await errorCode(
+        queueParams({
+          feeRecipients: table({ recipient: ops, bps: 5_000 }, { recipient: ops, bps: 5_000 }),
+        }).rpc()
+      );
+      expect(code).to.equal("InvalidFeeRecipient");
+    });
+
+    it("rejects shares that don't add up to 100%", async () => {
+      const code = await errorCode(
+        queueParams({
+          feeRecipients: table({ recipient: ops, bps: 6_000 }, { recipient: dao, bps: 3_000 }),
+        }).rpc()
+      );
+      expect(code).to.equal("InvalidFeeSplit");
+    });
+
+    it("splits the protocol fee across the configured recipients", async () => {
+      await applyParams({
+        feeRecipients: table({ recipient: ops, bps: 6_000 }, { recipient: dao, bps: 4_000 }),
+      });
+      const auction = await openAuction();
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, await funded(), bid);
+      await waitUntil(auction.endTime);
+
+      const code = await errorCode(settle(auction, { remaining: [] }));
+      expect(code).to.equal("FeeRecipientMismatch");
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const treasuryBefore = await balance(treasury);
+      await settle(auction);
+
+      expect(await balance(ops)).to.equal((fee * 6_000) / 10_000);
+      expect(await balance(dao)).to.equal((fee * 4_000) / 10_000);
+      expect(await balance(treasury)).to.equal(treasuryBefore);
+    });
+
+    it("splits a forfeited referral like the rest of the fee", async () => {
+      await applyParams({ referralBps: 2_000 });
+      const auction = await openAuction();
+      const bid = LAMPORTS_PER_SOL;
+      // The token program is executable, so it can't take its referral.
+      await placeBid(auction, await funded(), bid, TOKEN_PROGRAM_ID);
+      await waitUntil(auction.endTime);
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const [opsBefore, daoBefore, treasuryBefore] = await Promise.all(
+        [ops, dao, treasury].map(balance)
+      );
+      await settle(auction);
+
+      expect(await balance(ops)).to.equal(opsBefore + (fee * 6_000) / 10_000);
+      expect(await balance(dao)).to.equal(daoBefore + (fee * 4_000) / 10_000);
+      expect(await balance(treasury)).to.equal(treasuryBefore);
+    });
+  });
+
+  describe("settler and keeper rewards", () => {
+    const settlerReward = 5_000_000;
+
+    withParams(
+      { settlerRewardLamports: new BN(settlerReward) },
+      { settlerRewardLamports: new BN(0) }
+    );
+
+    it("rejects a settler reward above the cap", async () => {
+      const code = await errorCode(
+        queueParams({ settlerRewardLamports: new BN(10_000_001) }).rpc()
+      );
+      expect(code).to.equal("SettlerRewardTooHigh");
+    });
+
+    it("rejects a keeper reward above the cap", async () => {
+      const code = await errorCode(
+        queueParams({ keeperRewardLamports: new BN(1_000_001) }).rpc()
+      );
+      expect(code).to.equal("KeeperRewardTooHigh");
+    });
+
+    it("pays the settler out of the protocol fee", async () => {
+      const auction = await openAuction();
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, await funded(), bid);
+      await waitUntil(auction.endTime);
+
+      const { feeBps } = await program.account.auctionState.fetch(auction.address);
+      const fee = (bid * feeBps) / 10_000;
+      const treasuryBefore = await balance(treasury);
+      const sig = await settle(auction, { payer: await funded() });
+
+      const settled = await eventOf(sig, "auctionSettled");
+      expect(settled.settlerReward.toNumber()).to.equal(settlerReward);
+      expect(settled.protocolFee.toNumber()).to.equal(fee - settlerReward);
+      expect(await balance(treasury)).to.equal(treasuryBefore + fee - settlerReward);
+    });
+
+    it("pays no reward when nothing sold", async () => {
+      const auction = await openAuction({ autoRelist: true });
+      await waitUntil(auction.endTime);
+
+      const treasuryBefore = await balance(treasury);
+      const sig = await settle(auction, { payer: await funded() });
+
+      const settled = await eventOf(sig, "auctionSettled");
+      expect(settled.relistDate).to.not.be.null;
+      expect(settled.settlerReward.toNumber()).to.equal(0);
+      expect(await balance(treasury)).to.equal(treasuryBefore);
+    });
+  });
+
+  describe("admin handover", () => {
+    function proposeAdmin(signer: Keypair | null, newAdmin: PublicKey | null) {
+      const call = program.methods
+        .proposeAdmin(newAdmin)
+        .accountsPartial({ admin: signer?.publicKey ?? admin, config: configAddress });
+      return signer ? call.signers([signer]).rpc() : call.rpc();
+    }
+
+    function acceptAdmin(signer: Keypair | null) {
+      const call = program.methods
+        .acceptAdmin()
+        .accountsPartial({ newAdmin: signer?.publicKey ?? admin, config: configAddress });
+      return signer ? call.signers([signer]).rpc() : call.rpc();
+    }
+
+    it("only lets the admin propose a successor", async () => {
+      const stranger = await funded(1);
+      const code = await errorCode(proposeAdmin(stranger, stranger.publicKey));
+      expect(code).to.equal("Unauthorized");
+    });
+
+    it("hands over only once the proposed admin accepts", async () => {
+      const successor = await funded(1);
+      await proposeAdmin(null, successor.publicKey);
+
+      let config = await program.account.programConfig.fetch(configAddress);
+      expect(config.admin.toBase58()).to.equal(admin.toBase58());
+      expect(config.pendingAdmin!.toBase58()).to.equal(successor.publicKey.toBase58());
+
+      const code = await errorCode(acceptAdmin(await funded(1)));
+      expect(code).to.equal("Unauthorized");
+
+      await acceptAdmin(successor);
+      config = await program.account.programConfig.fetch(configAddress);
+      expect(config.admin.toBase58()).to.equal(successor.publicKey.toBase58());
+      expect(config.pendingAdmin).to.be.null;
+
+      // Hand it back for the rest of the suite.
+      await proposeAdmin(successor, admin);
+      await acceptAdmin(null);
+      config = await program.account.programConfig.fetch(configAddress);
+      expect(config.admin.toBase58()).to.equal(admin.toBase58());
+    });
+  });
+
+  describe("migrate_config", () => {
+    it("rejects anyone but the stored admin", async () => {
+      const stranger = await funded(1);
+      const code = await errorCode(
+        program.methods
+          .migrateConfig()
+          .accountsPartial({ admin: stranger.publicKey, config: configAddress })
+          .signers([stranger])
+          .rpc()
+      );
+      expect(code).to.equal("Unauthorized");
+    });
+
+    it("leaves a current config unchanged", async () => {
+      const before = await connection.getAccountInfo(configAddress);
+      await program.methods
+        .migrateConfig()
+        .accountsPartial({ admin, config: configAddress })
+        .rpc();
+
+      const after = await connection.getAccountInfo(configAddress);
+      expect(after!.data.equals(before!.data)).to.be.true;
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.version).to.equal(2);
+    });
+  });
+
+  describe("pause", () => {
+    const PAUSE_BIDDING = 1 << 1;
+
+    function setPause(paused: number, signer?: Keypair) {
+      const call = program.methods
+        .setPause(paused)
+        .accountsPartial({ admin: signer?.publicKey ?? admin, config: configAddress });
+      return signer ? call.signers([signer]).rpc() : call.rpc();
+    }
+
+    after(async () => {
+      await setPause(0);
+    });
+
+    it("only lets the admin pause", async () => {
+      const stranger = await funded(1);
+      const code = await errorCode(setPause(PAUSE_BIDDING, stranger));
+      expect(code).to.equal("Unauthorized");
+    });
+
+    it("rejects unknown pause bits", async () => {
+      const code = await errorCode(setPause(1 << 5));
+      expect(code).to.equal("InvalidPauseFlags");
+    });
+
+    it("halts bidding until resumed", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const bidder = await funded();
+      await setPause(PAUSE_BIDDING);
+
+      const code = await errorCode(placeBid(auction, bidder, LAMPORTS_PER_SOL));
+      expect(code).to.equal("ProgramPaused");
+
+      await setPause(0);
+      await placeBid(auction, bidder, LAMPORTS_PER_SOL);
+      const state = await program.account.auctionState.fetch(auction.address);
+      expect(state.currentBidder!.toBase58()).to.equal(bidder.publicKey.toBase58());
+    });
+  });
+
+  describe("operational roles", () => {
+    function setRoles(crank: PublicKey, manager: PublicKey, signer?: Keypair) {
+      const call = program.methods
+        .setRoles(crank, manager)
+        .accountsPartial({ admin: signer?.publicKey ?? admin, config: configAddress });
+      return signer ? call.signers([signer]).rpc() : call.rpc();
+    }
+
+    after(async () => {
+      await setRoles(admin, admin);
+    });
+
+    it("only lets the admin assign roles", async () => {
+      const stranger = await funded(1);
+      const code = await errorCode(setRoles(stranger.publicKey, stranger.publicKey, stranger));
+      expect(code).to.equal("Unauthorized");
+    });
+
+    it("moves auction creation to the new crank key", async () => {
+      const crank = await funded();
+      await setRoles(crank.publicKey, admin);
+
+      const seller = await funded();
+      const mint = await mintNft(seller.publicKey);
+      const id = await nextAuctionDate();
+      const slot = slotAddress(mint, id);
+      await listSlot(seller, mint, id).rpc();
+      const endTime = (await chainTime()) + 3_600;
+
+      const createAuction = (signer: Keypair | null) => {
+        const call = program.methods
+          .createAuction(new BN(id), new BN(endTime))
+          .accountsPartial({
+            crankAuthority: signer?.publicKey ?? admin,
+            config: configAddress,
+            nftMint: mint,
+            slot,
+            auction: auctionAddress(id),
+            bidLog: bidLogAddress(id),
+          });
+        return signer ? call.signers([signer]).rpc() : call.rpc();
+      };
+
+      const code = await errorCode(createAuction(null));
+      expect(code).to.equal("Unauthorized");
+
+      await createAuction(crank);
+      const state = await program.account.auctionState.fetch(auctionAddress(id));
+      expect(state.nftMint.toBase58()).to.equal(mint.toBase58());
+    });
+  });
+
+  describe("bidding", () => {
+    it("refunds the outbid bidder and reports the refund", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const first = await funded();
+      const second = await funded();
+      const bid = LAMPORTS_PER_SOL;
+      await placeBid(auction, first, bid);
+
+      const { bidIncrementBps } = await program.account.programConfig.fetch(configAddress);
+      const minNext = bid + (bid * bidIncrementBps) / 10_000;
+      const code = await errorCode(placeBid(auction, second, minNext - 1));
+      expect(code).to.equal("BidTooLow");
+
+      const firstBefore = await balance(first.publicKey);
+      const sig = await placeBid(auction, second, minNext);
+
+      expect(await balance(first.publicKey)).to.equal(firstBefore + bid);
+      const placed = await eventOf(sig, "bidPlaced");
+      expect(placed.bidder.toBase58()).to.equal(second.publicKey.toBase58());
+      expect(placed.amount.toNumber()).to.equal(minNext);
+      expect(placed.refundedBidder.toBase58()).to.equal(first.publicKey.toBase58());
+      expect(placed.refundAmount.toNumber()).to.equal(bid);
+    });
+
+    it("reports no refund for the opening bid", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const sig = await placeBid(auction, await funded(), LAMPORTS_PER_SOL);
+
+      const placed = await eventOf(sig, "bidPlaced");
+      expect(placed.refundedBidder).to.be.null;
+      expect(placed.refundAmount.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("auction lifecycle", () => {
+    async function cancelAuction(auction: TestAuction, dateReservationPayer?: PublicKey) {
+      const reservation = await reservationAccounts(auction.id, auction.seller.publicKey);
+      return program.methods
+        .cancelAuction()
+        .accountsPartial({
+          admin,
+          config: configAddress,
+          auction: auction.address,
+          slot: auction.slot,
+          ...reservation,
+          dateReservationPayer: dateReservationPayer ?? reservation.dateReservationPayer,
+          nftMint: auction.mint,
+          escrowTokenAccount: auction.escrow,
+          sellerTokenAccount: ata(auction.mint, auction.seller.publicKey),
+          seller: auction.seller.publicKey,
+        })
+        .rpc();
+    }
+
+    async function closeStale(auction: TestAuction, currentBidder: PublicKey) {
+      return program.methods
+        .closeStaleAuction()
+        .accountsPartial({
+          payer: admin,
+          config: configAddress,
+          auction: auction.address,
+          slot: auction.slot,
+          ...(await reservationAccounts(auction.id, auction.seller.publicKey)),
+          nftMint: auction.mint,
+          escrowTokenAccount: auction.escrow,
+          sellerTokenAccount: ata(auction.mint, auction.seller.publicKey),
+          seller: auction.seller.publicKey,
+          bidVault: auction.bidVault,
+          currentBidder,
+        })
+        .rpc({ commitment: "confirmed" });
+    }
+
+    it("only settles once the auction has ended, and only once", async () => {
+      const auction = await openAuction({ durationSecs: 15 });
+      const bidder = await funded();
+      await placeBid(auction, bidder, LAMPORTS_PER_SOL);
+
+      expect(await errorCode(settle(auction))).to.equal("AuctionNotEnded");
+      expect(await errorCode(cancelAuction(auction))).to.equal("AuctionHasBids");
+
+      await waitUntil(auction.endTime);
+      await settle(auction);
+      expect(await connection.getAccountInfo(dateAddress(auction.id))).to.be.null;
+      const state = await program.account.auctionState.fetch(auction.address);
+      expect(state.status).to.deep.equal({ settledSold: {} });
+      expect(state.settled).to.be.true;
+      expect(state.reserveMet).to.be.true;
+
+      const code = await errorCode(placeBid(auction, await funded(), 2 * LAMPORTS_PER_SOL));
+      expect(code).to.equal("AlreadySettled");
+    });
+
+    it("lets the admin cancel an auction without bids", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const code = await errorCode(cancelAuction(auction, admin));
+      expect(code).to.equal("ReservationPayerMismatch");
+
+      // The seller booked the date at list_slot and gets its rent back.
+      const reservationRent = await balance(dateAddress(auction.id));
+      const sellerBefore = await balance(auction.seller.publicKey);
+      await cancelAuction(auction);
+
+      expect(await connection.getAccountInfo(dateAddress(auction.id))).to.be.null;
+      expect(await balance(auction.seller.publicKey)).to.be.at.least(
+        sellerBefore + reservationRent
+      );
+
+      const state = await program.account.auctionState.fetch(auction.address);
+      expect(state.status).to.deep.equal({ cancelled: {} });
+      expect(await tokenBalance(ata(auction.mint, auction.seller.publicKey))).to.equal(1);
+    });
+
+    it("only closes an auction as stale days after it ended", async () => {
+      const auction = await openAuction({ durationSecs: 60 });
+      const code = await errorCode(closeStale(auction, admin));
+      expect(code).to.equal("AuctionNotStale");
+    });
+
+    it("returns a stale auction's NFT, refunds its standing bid and pays the keeper", async () => {
+      // Preloaded: an unsettled auction that ended in 2023 with a 0.6 SOL bid.
+      const date = STALE_AUCTION_DATE;
+      const auction = preloadedAuction("stale", date, date, date);
+      const { mint, seller } = auction;
+      const bidder = seeded("stale-bidder").publicKey;
+
+      const code = await errorCode(closeStale(auction, admin));
+      expect(code).to.equal("ConstraintRaw");
+
+      // The keeper reward comes out of the escrow's rent, not the treasury.
+      const keeperReward = 1_000_000;
+      const escrowRent = await balance(auction.escrow);
+      const sellerBefore = await balance(seller.publicKey);
+      const treasuryBefore = await balance(treasury);
+      await applyParams({ keeperRewardLamports: new BN(keeperReward) });
+      const sig = await closeStale(auction, bidder);
+      await applyParams({ keeperRewardLamports: new BN(0) });
+
+      const closed = await eventOf(sig, "staleClosed");
+      expect(closed.keeperReward.toNumber()).to.equal(keeperReward);
+      expect(await balance(seller.publicKey)).to.equal(sellerBefore + escrowRent - keeperReward);
+      expect(await balance(treasury)).to.equal(treasuryBefore);
+      expect(await balance(bidder)).to.equal(600_000_000);
+      expect(await tokenBalance(ata(mint, seller.publicKey))).to.equal(1);
+      expect(await connection.getAccountInfo(auction.escrow)).to.be.null;
+      const state = await program.account.auctionState.fetch(auction.address);
+      expect(state.status).to.deep.equal({ closedStale: {} });
+      expect(state.currentBidder).to.be.null;
+    });
+  });
+
+  describe("migrate_auction", () => {
+    const migrate = (id: number, currentBidder: PublicKey = admin) =>
+      program.methods
+        .migrateAuction(new BN(id))
+        .accountsPartial({
+          payer: admin,
+          auction: auctionAddress(id),
+          bidVault: bidVaultAddress(id),
+          currentBidder,
+        })
+        .rpc();
+
+    it("migrates the original layout, marking an unsettled past auction Ended", async () => {
+      const address = auctionAddress(BASELINE_AUCTION_ID);
+      expect((await connection.getAccountInfo(address))!.data.length).to.equal(150);
+
+      await migrate(BASELINE_AUCTION_ID);
+
+      expect((await connection.getAccountInfo(address))!.data.length).to.equal(184);
+      const state = await program.account.auctionState.fetch(address);
+      expect(state.status).to.deep.equal({ ended: {} });
+      expect(state.currentReferrer).to.be.null;
+      expect(state.currentBid.toNumber()).to.equal(600_000_000);
+      expect(state.currentBidder!.toBase58()).to.equal(
+        seeded("baseline-auction-bidder").publicKey.toBase58()
+      );
+    });
+
+    it("marks an unsold auction that had a bidder as closed stale and refunds the bid", async () => {
+      const address = auctionAddress(STALE_CLOSED_AUCTION_ID);
+      const bidder = seeded("stale-closed-auction-bidder").publicKey;
+      expect((await connection.getAccountInfo(address))!.data.length).to.equal(150);
+
+      const code = await errorCode(migrate(STALE_CLOSED_AUCTION_ID));
+      expect(code).to.equal("BidderMismatch");
+
+      await migrate(STALE_CLOSED_AUCTION_ID, bidder);
+
+      expect(await balance(bidder)).to.equal(300_000_000);
+      const state = await program.account.auctionState.fetch(address);
+      expect(state.status).to.deep.equal({ closedStale: {} });
+      expect(state.settled).to.be.true;
+      expect(state.currentBidder).to.be.null;
+      expect(state.currentBid.toNumber()).to.equal(0);
+    });
+
+    it("rejects an address that holds no auction", async () => {
+      const code = await errorCode(migrate(9_000_999));
+      expect(code).to.equal("InvalidAuctionAccount");
+    });
+  });
+
+  describe("bid log and close_auction", () => {
+    const closeAuction = async (
+      auction: TestAuction,
+      currentBidder: PublicKey = admin,
+      bidLogPayer?: PublicKey
+    ) => {
+      const log = await program.account.bidLog.fetchNullable(auction.bidLog);
+      return program.methods
+        .closeAuction()
+        .accountsPartial({
+          crankAuthority: admin,
+          config: configAddress,
+          auction: auction.address,
+          bidLog: auction.bidLog,
+          bidLogPayer: bidLogPayer ?? log?.payer ?? admin,
+          bidVault: auction.bidVault,
+          currentBidder,
+        })
+        .rpc({ commitment: "confirmed" });
+    };
+
+    it("logs every bid and closes the auction once settled", async () => {
+      const auction = await openAuction({ durationSecs: 15 });
+      const first = await funded();
+      const second = await funded();
+      await placeBid(auction, first, LAMPORTS_PER_SOL);
+      await placeBid(auction, second, 2 * LAMPORTS_PER_SOL);
+
+      const log = await program.account.bidLog.fetch(auction.bidLog);
+      expect(log.payer.toBase58()).to.equal(admin.toBase58());
+      expect(log.totalBids.toNumber()).to.equal(2);
+      expect(log.entries[0].bidder.toBase58()).to.equal(first.publicKey.toBase58());
+      expect(log.entries[0].amount.toNumber()).to.equal(LAMPORTS_PER_SOL);
+      expect(log.entries[1].bidder.toBase58()).to.equal(second.publicKey.toBase58());
+      expect(log.entries[1].amount.toNumber()).to.equal(2 * LAMPORTS_PER_SOL);
+
+      // Still live: the record has to stay.
+      expect(await errorCode(closeAuction(auction))).to.equal("InvalidStatusTransition");
+
+      await waitUntil(auction.endTime);
+      await settle(auction);
+      // The log's rent goes back to whoever paid it, here the crank.
+      const code = await errorCode(closeAuction(auction, second.publicKey, second.publicKey));
+      expect(code).to.equal("BidLogPayerMismatch");
+      await closeAuction(auction, second.publicKey);
+
+      expect(await connection.getAccountInfo(auction.address)).to.be.null;
+      expect(await connection.getAccountInfo(auction.bidLog)).to.be.null;
+    });
+
+    it("sweeps lamports sent to the bid vault instead of refusing to close", async () => {
+      const auction = await openAuction({ durationSecs: 4 });
+      await waitUntil(auction.endTime);
+      await settle(auction);
+
+      await airdrop(auction.bidVault, 1);
+      const sig = await closeAuction(auction);
+
+      expect(await balance(auction.bidVault)).to.equal(0);
+      const closed = await eventOf(sig, "auctionClosed");
+      expect(closed.swept.toNumber()).to.equal(LAMPORTS_PER_SOL);
+      expect(await connection.getAccountInfo(auction.address)).to.be.null;
+    });
+
+    it("refunds the standing bid a stale close left in the vault", async () => {
+      // Preloaded: a ClosedStale auction whose 0.4 SOL bid was never refunded.
+      const auction = preloadedAuction(
+        "unrefunded-auction",
+        UNREFUNDED_AUCTION_ID,
+        1_700_000_000
+      );
+      const bidder = seeded("unrefunded-auction-bidder").publicKey;
+
+      expect(await errorCode(closeAuction(auction))).to.equal("ConstraintRaw");
+      const sig = await closeAuction(auction, bidder);
+
+      expect(await balance(bidder)).to.equal(400_000_000);
+      const closed = await eventOf(sig, "auctionClosed");
+      expect(closed.refund.toNumber()).to.equal(400_000_000);
+      expect(closed.swept.toNumber()).to.equal(
+        await connection.getMinimumBalanceForRentExemption(0)
+      );
+      expect(await connection.getAccountInfo(auction.address)).to.be.null;
+    });
+
+    it("creates the bid log of an auction opened before bid logs", async () => {
+      // Preloaded: a Live auction without a BidLog, running until 2100.
+      const auction = preloadedAuction(
+        "unlogged-auction",
+        UNLOGGED_AUCTION_ID,
+        4_102_444_800
+      );
+      expect(await connection.getAccountInfo(auction.bidLog)).to.be.null;
+
+      const bidder = await funded();
+      await placeBid(auction, bidder, LAMPORTS_PER_SOL);
+
+      const log = await program.account.bidLog.fetch(auction.bidLog);
+      expect(log.auctionId.toNumber()).to.equal(UNLOGGED_AUCTION_ID);
+      expect(log.payer.toBase58()).to.equal(bidder.publicKey.toBase58());
+      expect(log.totalBids.toNumber()).to.equal(1);
+      expect(log.entries[0].bidder.toBase58()).to.equal(bidder.publicKey.toBase58());
+    });
+  });
+
+  describe("cast_vote", () => {
+    before(async () => {
+      await applyParams({ commonerCollection: COMMONER_COLLECTION });
+    });
+
+    it("counts one vote per Commoner the voter holds", async () => {
+      const id = await openProposal();
+      const voter = await funded();
+      const mints = [await mintCommoner(voter.publicKey), await mintCommoner(voter.publicKey)];
+      const holdings = mints.map((mint) => ({ mint, owner: voter.publicKey }));
+
+      await castVote(id, voter, [1, 0, 1], holdings);
+
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(1);
+      expect(proposal.abstain.toNumber()).to.equal(1);
+      const record = await program.account.voteRecord.fetch(
+        voteRecordAddress(id, voter.publicKey)
+      );
+      expect(record.weight.toNumber()).to.equal(2);
+      expect(record.delegatedWeight.toNumber()).to.equal(0);
+      for (const mint of mints) {
+        expect(await connection.getAccountInfo(voteMarkerAddress(id, mint))).to.not.be.null;
+      }
+    });
+
+    it("adds Commoners to a vote across transactions", async () => {
+      const id = await openProposal();
+      const voter = await funded();
+      const holdings: Holding[] = [];
+      for (let i = 0; i < 12; i++) {
+        holdings.push({ mint: await mintCommoner(voter.publicKey), owner: voter.publicKey });
+      }
+
+      // Twelve (token account, metadata, marker) triples don't fit in one
+      // transaction.
+      expect(await errorCode(castVote(id, voter, [12, 0, 0], holdings))).to.equal(
+        "UnknownError"
+      );
+
+      await castVote(id, voter, [6, 0, 0], holdings.slice(0, 6));
+      await castVote(id, voter, [4, 2, 0], holdings.slice(6));
+
+      // NFTs already counted can't be added again.
+      const code = await errorCode(castVote(id, voter, [1, 0, 0], holdings.slice(0, 1)));
+      expect(code).to.equal("NftAlreadyVoted");
+
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(10);
+      expect(proposal.no.toNumber()).to.equal(2);
+      const record = await program.account.voteRecord.fetch(
+        voteRecordAddress(id, voter.publicKey)
+      );
+      expect(record.weight.toNumber()).to.equal(12);
+      expect(record.yes.toNumber()).to.equal(10);
+      expect(record.no.toNumber()).to.equal(2);
+    });
+
+    it("rejects a Commoner held by another wallet", async () => {
+      const id = await openProposal();
+      const voter = await funded();
+      const holder = await funded(1);
+      const mint = await mintCommoner(holder.publicKey);
+
+      const code = await errorCode(castVote(id, voter, [1, 0, 0], [{ mint, owner: holder.publicKey }]));
+      expect(code).to.equal("InvalidCommonerTokenAccount");
+    });
+
+    it("rejects an NFT whose collection membership is unverified", async () => {
+      const id = await openProposal();
+      const voter = await funded();
+      const mint = await mintNft(voter.publicKey, seeded("commoner-unverified"));
+
+      const code = await errorCode(castVote(id, voter, [1, 0, 0], [{ mint, owner: voter.publicKey }]));
+      expect(code).to.equal("CollectionNotVerified");
+    });
+  });
+
+  describe("vote markers", () => {
+    it("stops a Commoner voting again from another wallet", async () => {
+      const id = await openProposal();
+      const first = await funded();
+      const second = await funded();
+      const mint = await mintCommoner(first.publicKey);
+      await castVote(id, first, [1, 0, 0], [{ mint, owner: first.publicKey }]);
+
+      await moveNft(mint, first, second.publicKey);
+      const code = await errorCode(
+        castVote(id, second, [1, 0, 0], [{ mint, owner: second.publicKey }])
+      );
+      expect(code).to.equal("NftAlreadyVoted");
+
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(1);
+    });
+
+    it("records who the Commoner voted for", async () => {
+      const id = await openProposal();
+      const voter = await funded();
+      const mint = await mintCommoner(voter.publicKey);
+      await castVote(id, voter, [0, 1, 0], [{ mint, owner: voter.publicKey }]);
+
+      // NftVoteMarker isn't in the IDL: discriminator, proposal_id, nft_mint,
+      // voter, bump, holder.
+      const marker = (await connection.getAccountInfo(voteMarkerAddress(id, mint)))!.data;
+      expect(new PublicKey(marker.subarray(48, 80)).toBase58()).to.equal(
+        voter.publicKey.toBase58()
+      );
+      expect(new PublicKey(marker.subarray(81, 113)).toBase58()).to.equal(
+        voter.publicKey.toBase58()
+      );
+    });
+  });
+
+  describe("finalize_proposal", () => {
+    // GovernanceProposal::STATUS_*
+    const PASSED = 1;
+    const FAILED = 2;
+
+    it("refuses to finalize while voting is open", async () => {
+      const id = await openProposal();
+      expect(await errorCode(finalizeProposal(id))).to.equal("VotingStillActive");
+    });
+
+    it("passes a vote with quorum and a yes majority", async () => {
+      // Preloaded: 30 yes, 5 no of 120 Commoners, voting closed in 2023.
+      const sig = await finalizeProposal(PASSING_PROPOSAL_ID);
+
+      const proposal = await fetchProposal(PASSING_PROPOSAL_ID);
+      expect(proposal.status).to.equal(PASSED);
+      const finalized = await eventOf(sig, "proposalFinalized");
+      expect(finalized.status).to.equal(PASSED);
+
+      const code = await errorCode(finalizeProposal(PASSING_PROPOSAL_ID));
+      expect(code).to.equal("ProposalNotActive");
+    });
+
+    it("fails a vote short of quorum, however one-sided", async () => {
+      // Preloaded: 1 yes of 120 Commoners against a 20% quorum.
+      await finalizeProposal(NO_QUORUM_PROPOSAL_ID);
+
+      const proposal = await fetchProposal(NO_QUORUM_PROPOSAL_ID);
+      expect(proposal.status).to.equal(FAILED);
+    });
+  });
+
+  describe("execute_proposal", () => {
+    // GovernanceProposal::STATUS_EXECUTED
+    const EXECUTED = 5;
+    const recipient = seeded("treasury-recipient").publicKey;
+
+    before(async () => {
+      await airdrop(treasury, 1);
+    });
+
+    it("waits out the execution delay", async () => {
+      const code = await errorCode(executeProposal(DELAYED_TREASURY_PROPOSAL_ID));
+      expect(code).to.equal("ExecutionDelayNotElapsed");
+    });
+
+    it("pays a passed treasury proposal exactly once", async () => {
+      // Preloaded: passed in 2023, paying 0.1 SOL to the recipient.
+      const treasuryBefore = await balance(treasury);
+      await executeProposal(TREASURY_PROPOSAL_ID);
+
+      expect(await balance(recipient)).to.equal(100_000_000);
+      expect(await balance(treasury)).to.equal(treasuryBefore - 100_000_000);
+      const proposal = await fetchProposal(TREASURY_PROPOSAL_ID);
+      expect(proposal.status).to.equal(EXECUTED);
+      expect(proposal.executedAt.toNumber()).to.be.greaterThan(0);
+
+      const code = await errorCode(executeProposal(TREASURY_PROPOSAL_ID));
+      expect(code).to.equal("ProposalNotPassed");
+    });
+  });
+
+  describe("parameter proposals", () => {
+    it("rejects an out-of-range update when the proposal is created", async () => {
+      const { call } = await createProposal({
+        paramUpdate: proposalParamUpdate({ feeBps: 10_001 }),
+      });
+      expect(await errorCode(call.rpc())).to.equal("FeeTooHigh");
+    });
+
+    it("rejects a zero max vote weight when the proposal is created", async () => {
+      const { call } = await createProposal({
+        paramUpdate: proposalParamUpdate({ maxVoteWeight: new BN(0) }),
+      });
+      expect(await errorCode(call.rpc())).to.equal("InvalidMaxVoteWeight");
+    });
+
+    it("applies a passed proposal's update to the config", async () => {
+      // Preloaded: passed in 2023, setting the bid increment to 10%.
+      const before = await program.account.programConfig.fetch(configAddress);
+      const sig = await executeProposal(PARAM_PROPOSAL_ID);
+
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.bidIncrementBps).to.equal(1_000);
+      const names = (await eventsOf(sig)).map((e) => e.name);
+      expect(names).to.include("paramsUpdated");
+      expect(names).to.include("proposalExecuted");
+
+      await applyParams({ bidIncrementBps: before.bidIncrementBps });
+    });
+  });
+
+  describe("vote delegation", () => {
+    function delegateVotes(holder: Keypair, delegate: PublicKey) {
+      return program.methods
+        .delegateVotes(delegate)
+        .accountsPartial({
+          holder: holder.publicKey,
+          config: configAddress,
+          delegation: delegationAddress(holder.publicKey),
+        })
+        .signers([holder])
+        .rpc();
+    }
+
+    it("rejects delegating to yourself", async () => {
+      const holder = await funded(1);
+      const code = await errorCode(delegateVotes(holder, holder.publicKey));
+      expect(code).to.equal("SelfDelegation");
+    });
+
+    it("keeps a delegate from counting NFTs their holder already voted", async () => {
+      const holder = await funded();
+      const delegate = await funded();
+      const held = await mintCommoner(holder.publicKey);
+      const own = await mintCommoner(delegate.publicKey);
+      await delegateVotes(holder, delegate.publicKey);
+      const id = await openProposal();
+
+      await castVote(id, holder, [1, 0, 0], [{ mint: held, owner: holder.publicKey }]);
+
+      const both = [
+        { mint: own, owner: delegate.publicKey },
+        { mint: held, owner: holder.publicKey },
+      ];
+      const code = await errorCode(
+        castVote(id, delegate, [0, 2, 0], both, { delegations: [holder.publicKey] })
+      );
+      expect(code).to.equal("NftAlreadyVoted");
+
+      await castVote(id, delegate, [0, 1, 0], [both[0]]);
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(1);
+      expect(proposal.no.toNumber()).to.equal(1);
+    });
+
+    it("lets a holder override the vote their delegate cast for them", async () => {
+      const holder = await funded();
+      const delegate = await funded();
+      const held = await mintCommoner(holder.publicKey);
+      const holding = { mint: held, owner: holder.publicKey };
+      await delegateVotes(holder, delegate.publicKey);
+      const id = await openProposal();
+
+      await castVote(id, delegate, [1, 0, 0], [holding], { delegations: [holder.publicKey] });
+      let record = await program.account.voteRecord.fetch(
+        voteRecordAddress(id, delegate.publicKey)
+      );
+      expect(record.delegatedWeight.toNumber()).to.equal(1);
+
+      // The delegate's record has to be passed so it can be shrunk.
+      const code = await errorCode(castVote(id, holder, [0, 1, 0], [holding]));
+      expect(code).to.equal("OverriddenVoteRecordMismatch");
+
+      const sig = await castVote(id, holder, [0, 1, 0], [holding], {
+        overridden: [delegate.publicKey],
+      });
+
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(0);
+      expect(proposal.no.toNumber()).to.equal(1);
+      record = await program.account.voteRecord.fetch(voteRecordAddress(id, delegate.publicKey));
+      expect(record.weight.toNumber()).to.equal(0);
+      expect(record.yes.toNumber()).to.equal(0);
+      const overridden = await eventOf(sig, "delegateVoteOverridden");
+      expect(overridden.delegate.toBase58()).to.equal(delegate.publicKey.toBase58());
+      expect(overridden.yes.toNumber()).to.equal(1);
+    });
+
+    it("won't take over a marker from another proposal", async () => {
+      const holder = await funded();
+      const delegate = await funded();
+      const holding = { mint: await mintCommoner(holder.publicKey), owner: holder.publicKey };
+      await delegateVotes(holder, delegate.publicKey);
+      const earlier = await openProposal();
+      const id = await openProposal();
+      await castVote(earlier, delegate, [1, 0, 0], [holding], {
+        delegations: [holder.publicKey],
+      });
+
+      const code = await errorCode(
+        castVote(id, holder, [0, 1, 0], [holding], {
+          overridden: [delegate.publicKey],
+          markersOf: earlier,
+        })
+      );
+      expect(code).to.equal("VoteMarkerMismatch");
+
+      const marker = (await connection.getAccountInfo(voteMarkerAddress(earlier, holding.mint)))!;
+      expect(new PublicKey(marker.data.subarray(48, 80)).toBase58()).to.equal(
+        delegate.publicKey.toBase58()
+      );
+    });
+
+    it("lets a delegate add their holders' Commoners across transactions", async () => {
+      const delegate = await funded();
+      const holders = [await funded(), await funded()];
+      const holdings: Holding[] = [];
+      for (const holder of holders) {
+        await delegateVotes(holder, delegate.publicKey);
+        for (let i = 0; i < 5; i++) {
+          holdings.push({ mint: await mintCommoner(holder.publicKey), owner: holder.publicKey });
+        }
+      }
+      const delegations = holders.map((h) => h.publicKey);
+      const id = await openProposal();
+
+      // Ten triples plus the delegations don't fit in one transaction.
+      expect(
+        await errorCode(castVote(id, delegate, [10, 0, 0], holdings, { delegations }))
+      ).to.equal("UnknownError");
+
+      await castVote(id, delegate, [5, 0, 0], holdings.slice(0, 5), {
+        delegations: [delegations[0]],
+      });
+      await castVote(id, delegate, [0, 5, 0], holdings.slice(5), {
+        delegations: [delegations[1]],
+      });
+
+      const record = await program.account.voteRecord.fetch(
+        voteRecordAddress(id, delegate.publicKey)
+      );
+      expect(record.weight.toNumber()).to.equal(10);
+      expect(record.delegatedWeight.toNumber()).to.equal(10);
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(5);
+      expect(proposal.no.toNumber()).to.equal(5);
+    });
+
+    it("closes every marker of a delegate's withdrawn vote", async () => {
+      const holder = await funded();
+      const delegate = await funded();
+      const holding = { mint: await mintCommoner(holder.publicKey), owner: holder.publicKey };
+      const own = { mint: await mintCommoner(delegate.publicKey), owner: delegate.publicKey };
+      await delegateVotes(holder, delegate.publicKey);
+      const id = await openProposal();
+      await castVote(id, delegate, [2, 0, 0], [own, holding], {
+        delegations: [holder.publicKey],
+      });
+
+      const withdraw = (markers: Holding[]) =>
+        program.methods
+          .withdrawVote(new BN(id))
+          .accountsPartial({
+            voter: delegate.publicKey,
+            config: configAddress,
+            proposal: proposalAddress(id),
+            voteRecord: voteRecordAddress(id, delegate.publicKey),
+          })
+          .remainingAccounts(
+            markers.map(({ mint }) => ({
+              pubkey: voteMarkerAddress(id, mint),
+              isSigner: false,
+              isWritable: true,
+            }))
+          )
+          .signers([delegate])
+          .rpc();
+
+      // Leaving the holder's marker open would keep it naming the delegate.
+      expect(await errorCode(withdraw([own]))).to.equal("VoteMarkerMismatch");
+      expect(await errorCode(withdraw([own, own]))).to.equal("VoteMarkerMismatch");
+      await withdraw([own, holding]);
+
+      expect(await connection.getAccountInfo(voteMarkerAddress(id, holding.mint))).to.be.null;
+      await castVote(id, holder, [0, 1, 0], [holding]);
+      const proposal = await fetchProposal(id);
+      expect(proposal.yes.toNumber()).to.equal(0);
+      expect(proposal.no.toNumber()).to.equal(1);
+    });
+
+    it("stops counting a holder's NFTs once they revoke", async () => {
+      const holder = await funded();
+      const delegate = await funded();
+      const held = await mintCommoner(holder.publicKey);
+      await delegateVotes(holder, delegate.publicKey);
+
+      await program.methods
+        .revokeDelegation()
+        .accountsPartial({
+          holder: holder.publicKey,
+          delegation: delegationAddress(holder.publicKey),
+        })
+        .signers([holder])
+        .rpc();
+      expect(await connection.getAccountInfo(delegationAddress(holder.publicKey))).to.be.null;
+
+      const id = await openProposal();
+      const code = await errorCode(
+        castVote(id, delegate, [1, 0, 0], [{ mint: held, owner: holder.publicKey }], {
+          delegations: [holder.publicKey],
+        })
+      );
+      expect(code).to.equal("InvalidDelegation");
+    });
+  });
+
+  describe("changing a vote", () => {
+    const updateVote = (id: number, voter: Keypair, [yes, no, abstain]: number[]) =>
+      program.methods
+        .updateVote(new BN(id), new BN(yes), new BN(no), new BN(abstain))
+        .accountsPartial({
+          voter: voter.publicKey,
+          config: configAddress,
+          proposal: proposalAddress(id),
+          voteRecord: voteRecordAddress(id, voter.publicKey),
+        })
+        .signers([voter])
+        .rpc();
+
+    const tallies = async (id: number) => {
+      const p = await fetchProposal(id);
+      return [p.yes.toNumber(), p.no.toNumber(), p.abstain.toNumber()];
+    };
+
+    it("replaces the allocation within the proven weight", async () => {
+      const voter = await funded();
+      const holdings = [
+        { mint: await mintCommoner(voter.publicKey), owner: voter.publicKey },
+        { mint: await mintCommoner(voter.publicKey), owner: voter.publicKey },
+      ];
+      const id = await openProposal();
+      await castVote(id, voter, [2, 0, 0], holdings);
+
+      await updateVote(id, voter, [0, 1, 1]);
+      expect(await tallies(id)).to.deep.equal([0, 1, 1]);
+
+      const code = await errorCode(updateVote(id, voter, [2, 1, 0]));
+      expect(code).to.equal("AllocationExceedsWeight");
+
+      // Withdrawing with the markers frees the Commoners to vote again.
+      await program.methods
+        .withdrawVote(new BN(id))
+        .accountsPartial({
+          voter: voter.publicKey,
+          config: configAddress,
+          proposal: proposalAddress(id),
+          voteRecord: voteRecordAddress(id, voter.publicKey),
+        })
+        .remainingAccounts(
+          holdings.map(({ mint }) => ({
+            pubkey: voteMarkerAddress(id, mint),
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+        .signers([voter])
+        .rpc();
+
+      expect(await tallies(id)).to.deep.equal([0, 0, 0]);
+      expect(await connection.getAccountInfo(voteRecordAddress(id, voter.publicKey))).to.be.null;
+
+      await castVote(id, voter, [0, 0, 2], holdings);
+      expect(await tallies(id)).to.deep.equal([0, 0, 2]);
+    });
+  });
+
+  describe("submitted proposals", () => {
+    // GovernanceProposal::STATUS_*
+    const ACTIVE = 0;
+    const VETOED = 4;
+    const PENDING_APPROVAL = 6;
+    const REJECTED = 7;
+
+    let holder: Keypair;
+    let mint: PublicKey;
+
+    before(async () => {
+      holder = await funded();
+      mint = await mintCommoner(holder.publicKey);
+    });
+
+    /** Submit as `proposer`, proving the Commoner in `owner`'s wallet. */
+    async function submitProposal(proposer: Keypair, owner = proposer.publicKey) {
+      const id = await nextProposalId();
+      await program.methods
+        .submitProposal(
+          "Holder proposal",
+          { inline: { description: "Submitted by a Commoner holder." } },
+          "community-initiative",
+          new BN(0),
+          proposer.publicKey,
+          null,
+          new BN(3_600)
+        )
+        .accountsPartial({
+          proposer: proposer.publicKey,
+          config: configAddress,
+          proposerTokenAccount: ata(mint, owner),
+          proposerMetadata: metadataAddress(mint),
+          proposal: proposalAddress(id),
+        })
+        .signers([proposer])
+        .rpc();
+      return id;
+    }
+
+    const approveProposal = (id: number, manager?: Keypair) => {
+      const call = program.methods.approveProposal(new BN(id)).accountsPartial({
+        proposalManager: manager?.publicKey ?? admin,
+        config: configAddress,
+        proposal: proposalAddress(id),
+      });
+      return (manager ? call.signers([manager]) : call).rpc();
+    };
+
+    const rejectProposal = async (id: number, caller?: Keypair) => {
+      const proposal = await fetchProposal(id);
+      const call = program.methods.rejectProposal(new BN(id)).accountsPartial({
+        caller: caller?.publicKey ?? admin,
+        config: configAddress,
+        proposal: proposalAddress(id),
+        proposer: proposal.proposer,
+      });
+      return (caller ? call.signers([caller]) : call).rpc({ commitment: "confirmed" });
+    };
+
+    /** Lamports a proposal holds on top of its rent. */
+    async function heldDeposit(id: number): Promise<number> {
+      const info = (await connection.getAccountInfo(proposalAddress(id)))!;
+      return info.lamports - (await connection.getMinimumBalanceForRentExemption(info.data.length));
+    }
+
+    it("numbers a holder's proposal from the counter and holds the deposit", async () => {
+      const config = await program.account.programConfig.fetch(configAddress);
+      const id = await submitProposal(holder);
+
+      expect(id).to.equal(config.nextProposalId.toNumber());
+      const after = await program.account.programConfig.fetch(configAddress);
+      expect(after.nextProposalId.toNumber()).to.equal(id + 1);
+
+      const proposal = await fetchProposal(id);
+      expect(proposal.proposer.toBase58()).to.equal(holder.publicKey.toBase58());
+      expect(proposal.status).to.equal(ACTIVE);
+      expect(proposal.depositLamports.toNumber()).to.equal(
+        config.proposalDepositLamports.toNumber()
+      );
+      expect(await heldDeposit(id)).to.equal(config.proposalDepositLamports.toNumber());
+
+      // A veto forfeits the deposit to the treasury.
+      const treasuryBefore = await balance(treasury);
+      await program.methods
+        .vetoProposal(new BN(id))
+        .accountsPartial({ admin, config: configAddress, proposal: proposalAddress(id), treasury })
+        .rpc();
+      expect(await balance(treasury)).to.equal(
+        treasuryBefore + config.proposalDepositLamports.toNumber()
+      );
+      const vetoed = await fetchProposal(id);
+      expect(vetoed.status).to.equal(VETOED);
+      expect(vetoed.depositLamports.toNumber()).to.equal(0);
+    });
+
+    it("numbers created and submitted proposals from the same counter", async () => {
+      const created = await openProposal();
+      const submitted = await submitProposal(holder);
+      expect(submitted).to.equal(created + 1);
+      expect(await nextProposalId()).to.equal(submitted + 1);
+    });
+
+    it("rejects a submission without a Commoner", async () => {
+      const outsider = await funded();
+      const code = await errorCode(submitProposal(outsider, holder.publicKey));
+      expect(code).to.equal("InvalidCommonerTokenAccount");
+    });
+
+    describe("with approval required", () => {
+      withParams({ proposalApprovalRequired: true }, { proposalApprovalRequired: false });
+
+      it("opens voting only once the proposal manager approves", async () => {
+        const id = await submitProposal(holder);
+        const pending = await fetchProposal(id);
+        expect(pending.status).to.equal(PENDING_APPROVAL);
+        expect(pending.endsAt.toNumber()).to.equal(0);
+
+        expect(await errorCode(approveProposal(id, holder))).to.equal("Unauthorized");
+
+        await approveProposal(id);
+        const approved = await fetchProposal(id);
+        expect(approved.status).to.equal(ACTIVE);
+        expect(approved.endsAt.toNumber()).to.be.greaterThan(await chainTime());
+      });
+
+      it("refunds the deposit when the proposal manager rejects", async () => {
+        const id = await submitProposal(holder);
+        const deposit = await heldDeposit(id);
+
+        const code = await errorCode(rejectProposal(id, holder));
+        expect(code).to.equal("ApprovalWindowOpen");
+
+        const before = await balance(holder.publicKey);
+        await rejectProposal(id);
+        expect(await balance(holder.publicKey)).to.equal(before + deposit);
+        const rejected = await fetchProposal(id);
+        expect(rejected.status).to.equal(REJECTED);
+      });
+    });
+
+    it("lets anyone expire a proposal left pending past the approval window", async () => {
+      // Preloaded: submitted in 2023 and never approved.
+      const proposer = seeded("deposit-proposer").publicKey;
+      const deposit = await heldDeposit(EXPIRED_PENDING_PROPOSAL_ID);
+      const before = await balance(proposer);
+
+      const sig = await rejectProposal(EXPIRED_PENDING_PROPOSAL_ID, await funded(1));
+
+      expect(await balance(proposer)).to.equal(before + deposit);
+      const rejected = await eventOf(sig, "proposalRejected");
+      expect(rejected.expired).to.be.true;
+    });
+
+    it("refunds the deposit at finalization only if the vote reached quorum", async () => {
+      // Preloaded: voting closed in 2023 with 30 and 1 of 120 Commoners.
+      const proposer = seeded("deposit-proposer").publicKey;
+      const deposit = await heldDeposit(DEPOSIT_QUORUM_PROPOSAL_ID);
+
+      const proposerBefore = await balance(proposer);
+      await finalizeProposal(DEPOSIT_QUORUM_PROPOSAL_ID);
+      expect(await balance(proposer)).to.equal(proposerBefore + deposit);
+
+      const treasuryBefore = await balance(treasury);
+      const sig = await finalizeProposal(DEPOSIT_NO_QUORUM_PROPOSAL_ID);
+      expect(await balance(treasury)).to.equal(treasuryBefore + deposit);
+      const settled = await eventOf(sig, "proposalDepositSettled");
+      expect(settled.refunded).to.be.false;
+    });
+  });
+
+  describe("max vote weight", () => {
+    after(async () => {
+      await applyParams({ maxVoteWeight: new BN(120) });
+    });
+
+    it("refuses a zero maximum", async () => {
+      const code = await errorCode(queueParams({ maxVoteWeight: new BN(0) }).rpc());
+      expect(code).to.equal("InvalidMaxVoteWeight");
+    });
+
+    it("caps the combined tally at the configured weight", async () => {
+      await applyParams({ maxVoteWeight: new BN(2) });
+      const config = await program.account.programConfig.fetch(configAddress);
+      expect(config.maxVoteWeight.toNumber()).to.equal(2);
+
+      const first = await funded();
+      const second = await funded();
+      const id = await openProposal();
+      await castVote(id, first, [2, 0, 0], [
+        { mint: await mintCommoner(first.publicKey), owner: first.publicKey },
+        { mint: await mintCommoner(first.publicKey), owner: first.publicKey },
+      ]);
+
+      const code = await errorCode(
+        castVote(id, second, [0, 1, 0], [
+          { mint: await mintCommoner(second.publicKey), owner: second.publicKey },
+        ])
+      );
+      expect(code).to.equal("TallyOverflow");
+    });
+  });
+
+  describe("hosted proposal content", () => {
+    const uri = "https://commoners.example/proposals/hosted.md";
+    const document = "# Hosted proposal\n\nThe full text lives off-chain.";
+
+    it("stores a URI and hash in a smaller account than an inline proposal", async () => {
+      const contentHash = [...createHash("sha256").update(document).digest()];
+      const hosted = await openProposal({ content: { hosted: { uri, contentHash } } });
+      const inline = await openProposal();
+
+      const proposal = await fetchProposal(hosted);
+      expect(proposal.contentUri).to.equal(uri);
+      expect(proposal.contentHash).to.deep.equal(contentHash);
+      expect(proposal.description).to.equal("");
+
+      const hostedSize = (await connection.getAccountInfo(proposalAddress(hosted)))!.data.length;
+      const inlineSize = (await connection.getAccountInfo(proposalAddress(inline)))!.data.length;
+      expect(hostedSize).to.be.lessThan(inlineSize);
+    });
+
+    it("rejects hosted content without a hash", async () => {
+      const { call } = await createProposal({
+        content: { hosted: { uri, contentHash: new Array(32).fill(0) } },
+      });
+      expect(await errorCode(call.rpc())).to.equal("InvalidProposalContent");
+    });
+  });
+
+  describe("migrate_proposal", () => {
+    const migrateProposal = (id: number) =>
+      program.methods
+        .migrateProposal(new BN(id))
+        .accountsPartial({ payer: admin, proposal: proposalAddress(id) })
+        .rpc();
+
+    it("grows a full legacy proposal to the inline layout", async () => {
+      // Preloaded: every string and the parameter update at full length,
+      // written before content_uri and content_hash existed.
+      const address = proposalAddress(LEGACY_PROPOSAL_ID);
+      const before = (await connection.getAccountInfo(address))!;
+      expect(before.data.length).to.equal(1_257);
+
+      await migrateProposal(LEGACY_PROPOSAL_ID);
+
+      const after = (await connection.getAccountInfo(address))!;
+      expect(after.data.length).to.equal(1_293);
+      expect(after.lamports).to.equal(
+        await connection.getMinimumBalanceForRentExemption(after.data.length)
+      );
+      const proposal = await fetchProposal(LEGACY_PROPOSAL_ID);
+      expect(proposal.description).to.equal("d".repeat(800));
+      expect(proposal.contentUri).to.equal("");
+
+      // Already current: left unchanged.
+      await migrateProposal(LEGACY_PROPOSAL_ID);
+      expect((await connection.getAccountInfo(address))!.data.length).to.equal(1_293);
+    });
+
+    it("refuses an address holding no proposal", async () => {
+      const code = await errorCode(migrateProposal(await nextProposalId()));
+      expect(code).to.equal("InvalidProposalAccount");
+    });
   });
 });